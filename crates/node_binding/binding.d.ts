@@ -276,12 +276,14 @@ export declare enum BuiltinPluginName {
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
   NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -293,6 +295,7 @@ export declare enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -523,10 +526,16 @@ export interface JsChunkGroupOrigin {
  *
  * This matches with:
  * - keep:
- *   - If a string, keep the files under this path
+ *   - If a string, keep the files under this path, or the files matched by it if it's a glob
+ *   - If a RegExp, keep the files matched by it
+ *   - If a function, keep the files when it returns true
+ * - dry: Only log the files that would be removed
+ * - emittedOnly: Only remove the files emitted by this compiler before
  */
 export interface JsCleanOptions {
-  keep?: string
+  keep?: string | RegExp | ((path: string) => boolean)
+  dry?: boolean
+  emittedOnly?: boolean
 }
 
 export interface JsCodegenerationResult {
@@ -570,7 +579,7 @@ export interface JsDiagnostic {
   sourceCode?: string
   location?: JsDiagnosticLocation
   file?: string
-  severity: "error" | "warning"
+  severity: "error" | "warning" | "advice"
   moduleIdentifier?: string
 }
 
@@ -787,6 +796,8 @@ export interface JsRspackDiagnostic {
 export interface JsRspackError {
   name: string
   message: string
+  /** The stable code of the diagnostic, such as `RSPACK_E0001` */
+  code?: string
   moduleIdentifier?: string
   loc?: string
   file?: string
@@ -923,11 +934,13 @@ export interface JsStatsCompilation {
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
   warnings: Array<JsStatsWarning>
+  advices: Array<JsStatsWarning>
 }
 
 export interface JsStatsError {
   moduleDescriptor?: JsModuleDescriptor
   message: string
+  code?: string
   chunkName?: string
   chunkEntry?: boolean
   chunkInitial?: boolean
@@ -1058,6 +1071,7 @@ export interface JsStatsSize {
 export interface JsStatsWarning {
   moduleDescriptor?: JsModuleDescriptor
   message: string
+  code?: string
   chunkName?: string
   chunkEntry?: boolean
   chunkInitial?: boolean
@@ -1115,8 +1129,14 @@ export interface RawAssetInlineGeneratorOptions {
 }
 
 export interface RawAssetParserDataUrl {
-  type: "options"
+  type: "options" | "function"
   options?: RawAssetParserDataUrlOptions
+  function?: (source: Buffer, context: RawAssetParserDataUrlFnCtx) => boolean
+}
+
+export interface RawAssetParserDataUrlFnCtx {
+  filename: string
+  context: string
 }
 
 export interface RawAssetParserDataUrlOptions {
@@ -1203,7 +1223,7 @@ export interface RawConsumeOptions {
   import?: string
   importResolved?: string
   shareKey: string
-  shareScope: string
+  shareScope: string | string[]
   requiredVersion?: string | false | undefined
   packageName?: string
   strictVersion: boolean
@@ -1218,7 +1238,7 @@ export interface RawConsumeSharedPluginOptions {
 
 export interface RawContainerPluginOptions {
   name: string
-  shareScope: string
+  shareScope: string | string[]
   library: JsLibraryOptions
   runtime?: false | string
   filename?: string
@@ -1229,7 +1249,7 @@ export interface RawContainerPluginOptions {
 export interface RawContainerReferencePluginOptions {
   remoteType: string
   remotes: Array<RawRemoteOptions>
-  shareScope?: string
+  shareScope?: string | string[]
   enhanced: boolean
 }
 
@@ -1279,6 +1299,7 @@ export interface RawCssAutoGeneratorOptions {
 
 export interface RawCssAutoParserOptions {
   namedExports?: boolean
+  ignoreOrder?: boolean
 }
 
 export interface RawCssExtractPluginOption {
@@ -1306,10 +1327,12 @@ export interface RawCssModuleGeneratorOptions {
 
 export interface RawCssModuleParserOptions {
   namedExports?: boolean
+  ignoreOrder?: boolean
 }
 
 export interface RawCssParserOptions {
   namedExports?: boolean
+  ignoreOrder?: boolean
 }
 
 export interface RawDllEntryPluginOptions {
@@ -1389,6 +1412,7 @@ export interface RawExperimentSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  hashPaths?: Array<string|RegExp>
 }
 
 export interface RawExposeOptions {
@@ -1476,7 +1500,7 @@ export interface RawHtmlRspackPluginOptions {
   /** path or `auto` */
   publicPath?: string
   /** `blocking`, `defer`, `module` or `systemjs-module` */
-  scriptLoading: "blocking" | "defer" | "module" | "systemjs-module"
+  scriptLoading?: "blocking" | "defer" | "module" | "systemjs-module"
   /** entry_chunk_name (only entry chunks are supported) */
   chunks?: Array<string>
   excludeChunks?: Array<string>
@@ -1567,6 +1591,10 @@ export interface RawJavascriptParserOptions {
   importDynamic?: boolean
 }
 
+export interface RawJsonParserOptions {
+  parse?: "json" | "json5" | "toml" | "yaml" | ((source: string) => string)
+}
+
 export interface RawLazyCompilationOption {
   module: ((err: Error | null, arg: RawModuleArg) => RawModuleInfo)
   test?: RawLazyCompilationTest
@@ -1635,6 +1663,13 @@ export interface RawModuleArg {
   path: string
 }
 
+export interface RawModuleFederationManifestPluginOptions {
+  name: string
+  globalName?: string
+  filename: string
+  statsFilename: string
+}
+
 export interface RawModuleFilenameTemplateFnCtx {
   identifier: string
   shortIdentifier: string
@@ -1751,6 +1786,9 @@ export interface RawOptions {
   profile: boolean
   amd?: string
   bail: boolean
+  diagnostics?: Record<string, 'error' | 'warning' | 'advice' | 'off'>
+  recordsInputPath?: string
+  recordsOutputPath?: string
   __references: Record<string, any>
 }
 
@@ -1801,12 +1839,13 @@ export interface RawOutputOptions {
 }
 
 export interface RawParserOptions {
-  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm"
+  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json"
   asset?: RawAssetParserOptions
   css?: RawCssParserOptions
   cssAuto?: RawCssAutoParserOptions
   cssModule?: RawCssModuleParserOptions
   javascript?: RawJavascriptParserOptions
+  json?: RawJsonParserOptions
 }
 
 export interface RawPathData {
@@ -1827,7 +1866,7 @@ export interface RawProgressPluginOptions {
 export interface RawProvideOptions {
   key: string
   shareKey: string
-  shareScope: string
+  shareScope: string | string[]
   version?: string | false | undefined
   eager: boolean
   singleton?: boolean
@@ -1842,7 +1881,7 @@ export interface RawRelated {
 export interface RawRemoteOptions {
   key: string
   external: Array<string>
-  shareScope: string
+  shareScope: string | string[]
 }
 
 export interface RawResolveOptions {
@@ -2031,7 +2070,7 @@ export interface RawTrustedTypes {
  * Author Donny/강동윤
  * Copyright (c)
  */
-export declare function registerGlobalTrace(filter: string, layer: "chrome" | "logger"| "console", output: string): void
+export declare function registerGlobalTrace(filter: string, layer: "chrome" | "logger" | "console" | "profile", output: string): void
 
 export declare enum RegisterJsTapKind {
   CompilerThisCompilation = 0,
@@ -1,5 +1,6 @@
 use napi::bindgen_prelude::*;
 use rspack_error::{
+  code::{MODULE_ERROR, MODULE_WARNING},
   miette::{self, LabeledSpan, MietteDiagnostic, Severity},
   Diagnostic,
 };
@@ -26,7 +27,7 @@ pub struct JsDiagnostic {
   pub location: Option<JsDiagnosticLocation>,
   pub file: Option<String>,
 
-  #[napi(ts_type = "\"error\" | \"warning\"")]
+  #[napi(ts_type = "\"error\" | \"warning\" | \"advice\"")]
   pub severity: String,
   pub module_identifier: Option<String>,
 }
@@ -42,10 +43,18 @@ pub fn format_diagnostic(diagnostic: JsDiagnostic) -> Result<External<Diagnostic
     module_identifier,
     file,
   } = diagnostic;
-  let mut d = MietteDiagnostic::new(message).with_severity(match severity.as_str() {
+  let severity = match severity.as_str() {
     "warning" => Severity::Warning,
+    "advice" => Severity::Advice,
     _ => Severity::Error,
-  });
+  };
+  // Diagnostics of loaders are reported as module errors or warnings
+  let code = if severity == Severity::Error {
+    &MODULE_ERROR
+  } else {
+    &MODULE_WARNING
+  };
+  let mut d = MietteDiagnostic::new(message).with_severity(severity);
   if let Some(help) = help {
     d = d.with_help(help);
   }
@@ -96,6 +105,7 @@ pub fn format_diagnostic(diagnostic: JsDiagnostic) -> Result<External<Diagnostic
     Diagnostic::from(error)
       .with_file(file.map(Into::into))
       .with_loc(loc.map(|l| l.to_string()))
-      .with_module_identifier(module_identifier.map(Into::into)),
+      .with_module_identifier(module_identifier.map(Into::into))
+      .with_diagnostic_code(code),
   ))
 }
@@ -26,7 +26,7 @@ use plugins::*;
 use resolver_factory::*;
 use rspack_binding_options::*;
 use rspack_binding_values::*;
-use rspack_tracing::chrome::FlushGuard;
+use rspack_tracing::{chrome::FlushGuard, profile::ProfileGuard};
 
 #[napi]
 pub struct Rspack {
@@ -195,11 +195,28 @@ fn concurrent_compiler_error() -> Error {
 
 #[derive(Default)]
 enum TraceState {
-  On(Option<FlushGuard>),
+  On(Option<TraceGuard>),
   #[default]
   Off,
 }
 
+enum TraceGuard {
+  Chrome(FlushGuard),
+  Profile(ProfileGuard),
+}
+
+impl TraceGuard {
+  fn flush(&self) -> std::io::Result<()> {
+    match self {
+      TraceGuard::Chrome(guard) => {
+        guard.flush();
+        Ok(())
+      }
+      TraceGuard::Profile(guard) => guard.flush(),
+    }
+  }
+}
+
 #[ctor]
 fn init() {
   panic::install_panic_handler();
@@ -223,7 +240,7 @@ static GLOBAL_TRACE_STATE: Mutex<TraceState> = Mutex::new(TraceState::Off);
 #[napi]
 pub fn register_global_trace(
   filter: String,
-  #[napi(ts_arg_type = "\"chrome\" | \"logger\"| \"console\"")] layer: String,
+  #[napi(ts_arg_type = "\"chrome\" | \"logger\" | \"console\" | \"profile\"")] layer: String,
   output: String,
 ) {
   let mut state = GLOBAL_TRACE_STATE
@@ -231,7 +248,10 @@ pub fn register_global_trace(
     .expect("Failed to lock GLOBAL_TRACE_STATE");
   if matches!(&*state, TraceState::Off) {
     let guard = match layer.as_str() {
-      "chrome" => rspack_tracing::enable_tracing_by_env_with_chrome_layer(&filter, &output),
+      "chrome" => rspack_tracing::enable_tracing_by_env_with_chrome_layer(&filter, &output)
+        .map(TraceGuard::Chrome),
+      "profile" => rspack_tracing::enable_tracing_by_env_with_profile_layer(&filter, &output)
+        .map(TraceGuard::Profile),
       "console" => {
         rspack_tracing::enable_tracing_by_env_with_tokio_console();
         None
@@ -248,16 +268,18 @@ pub fn register_global_trace(
 }
 
 #[napi]
-pub fn cleanup_global_trace() {
+pub fn cleanup_global_trace() -> Result<()> {
   let mut state = GLOBAL_TRACE_STATE
     .lock()
     .expect("Failed to lock GLOBAL_TRACE_STATE");
   if let TraceState::On(guard) = &mut *state
     && let Some(g) = guard.take()
   {
-    g.flush();
+    let flushed = g.flush();
     drop(g);
     let new_state = TraceState::Off;
     *state = new_state;
+    flushed.map_err(|e| napi::Error::from_reason(e.to_string()))?;
   }
+  Ok(())
 }
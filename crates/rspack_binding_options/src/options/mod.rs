@@ -1,8 +1,10 @@
 use napi_derive::napi;
 use rspack_core::{
-  incremental::IncrementalPasses, CacheOptions, CompilerOptions, Context, Experiments,
-  ModuleOptions, OutputOptions, References,
+  incremental::IncrementalPasses, CacheOptions, CompilerOptions, Context, DiagnosticLevel,
+  DiagnosticsOptions, Experiments, ModuleOptions, OutputOptions, References,
 };
+use rspack_error::{error, DiagnosticCode};
+use rustc_hash::FxHashMap as HashMap;
 
 mod raw_builtins;
 mod raw_cache;
@@ -56,6 +58,10 @@ pub struct RawOptions {
   pub profile: bool,
   pub amd: Option<String>,
   pub bail: bool,
+  #[napi(ts_type = "Record<string, 'error' | 'warning' | 'advice' | 'off'>")]
+  pub diagnostics: Option<HashMap<String, String>>,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
 }
@@ -79,6 +85,11 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let stats = value.stats.into();
     let snapshot = value.snapshot.into();
     let node = value.node.map(|n| n.into());
+    let diagnostics = value
+      .diagnostics
+      .map(get_diagnostics_options)
+      .transpose()?
+      .unwrap_or_default();
 
     Ok(CompilerOptions {
       context,
@@ -96,7 +107,36 @@ impl TryFrom<RawOptions> for CompilerOptions {
       profile: value.profile,
       amd: value.amd,
       bail: value.bail,
+      diagnostics,
+      records_input_path: value.records_input_path.map(Into::into),
+      records_output_path: value.records_output_path.map(Into::into),
       __references: value.__references,
     })
   }
 }
+
+fn get_diagnostics_options(
+  levels: HashMap<String, String>,
+) -> rspack_error::Result<DiagnosticsOptions> {
+  let levels = levels
+    .into_iter()
+    .map(|(code, level)| {
+      let Some(diagnostic_code) = DiagnosticCode::from_code(&code) else {
+        return Err(error!("Unknown diagnostic code `{code}` in `diagnostics`"));
+      };
+      let level = match level.as_str() {
+        "error" => DiagnosticLevel::Error,
+        "warning" => DiagnosticLevel::Warning,
+        "advice" => DiagnosticLevel::Advice,
+        "off" => DiagnosticLevel::Off,
+        _ => {
+          return Err(error!(
+            "Invalid level `{level}` of diagnostic code `{code}`, expected \"error\", \"warning\", \"advice\" or \"off\""
+          ))
+        }
+      };
+      Ok((diagnostic_code, level))
+    })
+    .collect::<rspack_error::Result<Vec<_>>>()?;
+  Ok(DiagnosticsOptions::new(levels))
+}
@@ -23,7 +23,7 @@ use rspack_core::{BoxPlugin, Plugin, PluginExt};
 use rspack_error::Result;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin, RecordIdsPlugin,
 };
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
@@ -59,8 +59,8 @@ use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
-  ProvideSharedPlugin, ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationManifestPlugin,
+  ModuleFederationRuntimePlugin, ProvideSharedPlugin, ShareRuntimePlugin,
 };
 use rspack_plugin_no_emit_on_errors::NoEmitOnErrorsPlugin;
 use rspack_plugin_progress::ProgressPlugin;
@@ -77,7 +77,7 @@ use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
 use rspack_plugin_wasm::{
-  enable_wasm_loading_plugin, AsyncWasmPlugin, FetchCompileAsyncWasmPlugin,
+  enable_wasm_loading_plugin, AsyncWasmPlugin, FetchCompileAsyncWasmPlugin, SyncWasmPlugin,
 };
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
@@ -97,7 +97,10 @@ use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
-  raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_mf::{
+    RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions,
+    RawModuleFederationManifestPluginOptions, RawProvideOptions,
+  },
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
 };
@@ -143,12 +146,14 @@ pub enum BuiltinPluginName {
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
   ModuleFederationRuntimePlugin,
+  ModuleFederationManifestPlugin,
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
+  RecordIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -160,6 +165,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -268,13 +274,11 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableLibraryPlugin => {
         let library_type = downcast_into::<String>(self.options)?;
-        enable_library_plugin(library_type, plugins);
+        enable_library_plugin(library_type, plugins)?;
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::FetchCompileAsyncWasmPlugin => {
         plugins.push(FetchCompileAsyncWasmPlugin::default().boxed())
@@ -352,6 +356,12 @@ impl BuiltinPlugin {
       BuiltinPluginName::ModuleFederationRuntimePlugin => {
         plugins.push(ModuleFederationRuntimePlugin::default().boxed())
       }
+      BuiltinPluginName::ModuleFederationManifestPlugin => plugins.push(
+        ModuleFederationManifestPlugin::new(
+          downcast_into::<RawModuleFederationManifestPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -370,6 +380,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::DeterministicChunkIdsPlugin => {
         plugins.push(DeterministicChunkIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::RecordIdsPlugin => plugins.push(RecordIdsPlugin::default().boxed()),
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -393,6 +404,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed())
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
@@ -23,7 +23,7 @@ type RawChunkSortMode = String;
 type RawTemplateRenderFn = ThreadsafeFunction<String, String>;
 
 type RawTemplateParameter =
-  Either3<HashMap<String, String>, bool, ThreadsafeFunction<String, String>>;
+  Either3<HashMap<String, serde_json::Value>, bool, ThreadsafeFunction<String, String>>;
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -45,7 +45,7 @@ pub struct RawHtmlRspackPluginOptions {
   pub public_path: Option<String>,
   /// `blocking`, `defer`, `module` or `systemjs-module`
   #[napi(ts_type = "\"blocking\" | \"defer\" | \"module\" | \"systemjs-module\"")]
-  pub script_loading: RawHtmlScriptLoading,
+  pub script_loading: Option<RawHtmlScriptLoading>,
 
   /// entry_chunk_name (only entry chunks are supported)
   pub chunks: Option<Vec<String>>,
@@ -67,8 +67,9 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
   fn from(value: RawHtmlRspackPluginOptions) -> Self {
     let inject = HtmlInject::from_str(&value.inject).expect("Invalid inject value");
 
-    let script_loading =
-      HtmlScriptLoading::from_str(&value.script_loading).expect("Invalid script_loading value");
+    let script_loading = value.script_loading.as_ref().map(|s| {
+      HtmlScriptLoading::from_str(s).unwrap_or_else(|_| panic!("Invalid script_loading value: {s}"))
+    });
 
     let chunks_sort_mode =
       HtmlChunkSortMode::from_str(&value.chunks_sort_mode).expect("Invalid chunks_sort_mode value");
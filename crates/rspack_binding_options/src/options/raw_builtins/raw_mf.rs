@@ -8,14 +8,16 @@ use rspack_binding_values::{
 };
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ModuleFederationManifestPluginOptions,
+  ProvideOptions, ProvideVersion, RemoteOptions, ShareScope,
 };
 
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawContainerPluginOptions {
   pub name: String,
-  pub share_scope: String,
+  #[napi(ts_type = "string | string[]")]
+  pub share_scope: RawShareScope,
   pub library: JsLibraryOptions,
   #[napi(ts_type = "false | string")]
   pub runtime: Option<JsEntryRuntime>,
@@ -28,7 +30,7 @@ impl From<RawContainerPluginOptions> for ContainerPluginOptions {
   fn from(value: RawContainerPluginOptions) -> Self {
     Self {
       name: value.name,
-      share_scope: value.share_scope,
+      share_scope: RawShareScopeWrapper(value.share_scope).into(),
       library: value.library.into(),
       runtime: value.runtime.map(|r| JsEntryRuntimeWrapper(r).into()),
       filename: value.filename.map(|f| f.into()),
@@ -63,7 +65,8 @@ impl From<RawExposeOptions> for (String, ExposeOptions) {
 pub struct RawContainerReferencePluginOptions {
   pub remote_type: String,
   pub remotes: Vec<RawRemoteOptions>,
-  pub share_scope: Option<String>,
+  #[napi(ts_type = "string | string[]")]
+  pub share_scope: Option<RawShareScope>,
   pub enhanced: bool,
 }
 
@@ -72,7 +75,7 @@ impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOption
     Self {
       remote_type: value.remote_type,
       remotes: value.remotes.into_iter().map(|e| e.into()).collect(),
-      share_scope: value.share_scope,
+      share_scope: value.share_scope.map(|s| RawShareScopeWrapper(s).into()),
       enhanced: value.enhanced,
     }
   }
@@ -83,7 +86,8 @@ impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOption
 pub struct RawRemoteOptions {
   pub key: String,
   pub external: Vec<String>,
-  pub share_scope: String,
+  #[napi(ts_type = "string | string[]")]
+  pub share_scope: RawShareScope,
 }
 
 impl From<RawRemoteOptions> for (String, RemoteOptions) {
@@ -92,7 +96,7 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
       value.key,
       RemoteOptions {
         external: value.external,
-        share_scope: value.share_scope,
+        share_scope: RawShareScopeWrapper(value.share_scope).into(),
       },
     )
   }
@@ -103,7 +107,8 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
 pub struct RawProvideOptions {
   pub key: String,
   pub share_key: String,
-  pub share_scope: String,
+  #[napi(ts_type = "string | string[]")]
+  pub share_scope: RawShareScope,
   #[napi(ts_type = "string | false | undefined")]
   pub version: Option<RawVersion>,
   pub eager: bool,
@@ -119,7 +124,7 @@ impl From<RawProvideOptions> for (String, ProvideOptions) {
       value.key,
       ProvideOptions {
         share_key: value.share_key,
-        share_scope: value.share_scope,
+        share_scope: RawShareScopeWrapper(value.share_scope).into(),
         version: value.version.map(|v| RawVersionWrapper(v).into()),
         eager: value.eager,
         singleton: value.singleton,
@@ -158,7 +163,8 @@ pub struct RawConsumeOptions {
   pub import: Option<String>,
   pub import_resolved: Option<String>,
   pub share_key: String,
-  pub share_scope: String,
+  #[napi(ts_type = "string | string[]")]
+  pub share_scope: RawShareScope,
   #[napi(ts_type = "string | false | undefined")]
   pub required_version: Option<RawVersion>,
   pub package_name: Option<String>,
@@ -175,7 +181,7 @@ impl From<RawConsumeOptions> for (String, ConsumeOptions) {
         import: value.import,
         import_resolved: value.import_resolved,
         share_key: value.share_key,
-        share_scope: value.share_scope,
+        share_scope: RawShareScopeWrapper(value.share_scope).into(),
         required_version: value.required_version.map(|v| RawVersionWrapper(v).into()),
         package_name: value.package_name,
         strict_version: value.strict_version,
@@ -186,6 +192,26 @@ impl From<RawConsumeOptions> for (String, ConsumeOptions) {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub global_name: Option<String>,
+  pub filename: String,
+  pub stats_filename: String,
+}
+
+impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
+  fn from(value: RawModuleFederationManifestPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      global_name: value.global_name,
+      filename: value.filename,
+      stats_filename: value.stats_filename,
+    }
+  }
+}
+
 pub type RawVersion = Either<String, bool>;
 
 struct RawVersionWrapper(RawVersion);
@@ -207,3 +233,16 @@ impl From<RawVersionWrapper> for ConsumeVersion {
     }
   }
 }
+
+pub type RawShareScope = Either<String, Vec<String>>;
+
+struct RawShareScopeWrapper(RawShareScope);
+
+impl From<RawShareScopeWrapper> for ShareScope {
+  fn from(value: RawShareScopeWrapper) -> Self {
+    match value.0 {
+      Either::A(s) => ShareScope::from(s),
+      Either::B(v) => ShareScope::from(v),
+    }
+  }
+}
@@ -12,6 +12,8 @@ pub struct RawExperimentSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  #[napi(ts_type = r#"Array<string|RegExp>"#)]
+  pub hash_paths: Option<Vec<RawPathMatcher>>,
 }
 
 type RawPathMatcher = Either<String, RspackRegex>;
@@ -34,6 +36,12 @@ impl From<RawExperimentSnapshotOptions> for SnapshotOptions {
         .into_iter()
         .map(normalize_raw_path_matcher)
         .collect(),
+      value
+        .hash_paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(normalize_raw_path_matcher)
+        .collect(),
     )
   }
 }
@@ -10,7 +10,9 @@ pub struct RawStorageOptions {
 }
 
 impl From<RawStorageOptions> for StorageOptions {
-  fn from(_value: RawStorageOptions) -> Self {
-    StorageOptions::FileSystem
+  fn from(value: RawStorageOptions) -> Self {
+    StorageOptions::FileSystem {
+      directory: value.directory.into(),
+    }
   }
 }
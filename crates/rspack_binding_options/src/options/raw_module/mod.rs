@@ -2,21 +2,21 @@ use std::fmt::Formatter;
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use derivative::Derivative;
-use napi::bindgen_prelude::Either3;
+use napi::bindgen_prelude::{Buffer, Either3};
 use napi::Either;
 use napi_derive::napi;
 use rspack_binding_values::JsFilename;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetGeneratorDataUrlOptions,
-  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
+  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlFnCtx,
   AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
   CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
   CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportFetchPriority,
   DynamicImportMode, ExportPresenceMode, FuncUseCtx, GeneratorOptions, GeneratorOptionsMap,
-  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, ModuleNoParseRule,
-  ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions, ModuleRule, ModuleRuleEffect,
-  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, OverrideStrict, ParserOptions,
-  ParserOptionsMap,
+  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonParse,
+  JsonParserOptions, ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions,
+  ModuleRule, ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader,
+  OverrideStrict, ParserOptions, ParserOptionsMap,
 };
 use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
@@ -180,10 +180,10 @@ pub struct RawModuleRule {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawParserOptions {
   #[napi(
-    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm""#
+    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json""#
   )]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
@@ -191,11 +191,14 @@ pub struct RawParserOptions {
   pub css_auto: Option<RawCssAutoParserOptions>,
   pub css_module: Option<RawCssModuleParserOptions>,
   pub javascript: Option<RawJavascriptParserOptions>,
+  pub json: Option<RawJsonParserOptions>,
 }
 
-impl From<RawParserOptions> for ParserOptions {
-  fn from(value: RawParserOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawParserOptions> for ParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawParserOptions) -> rspack_error::Result<Self> {
+    let options = match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
@@ -246,11 +249,20 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"css_module\" when RawParserOptions.type is \"css/module\"")
           .into(),
       ),
-      _ => panic!(
-        "Failed to resolve the RawParserOptions.type {}.",
-        value.r#type
+      "json" => Self::Json(
+        value
+          .json
+          .expect("should have an \"json\" when RawParserOptions.type is \"json\"")
+          .try_into()?,
       ),
-    }
+      _ => {
+        return Err(error!(
+          "Failed to resolve the RawParserOptions.type {}.",
+          value.r#type
+        ))
+      }
+    };
+    Ok(options)
   }
 }
 
@@ -330,7 +342,7 @@ impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
 }
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserOptions {
   pub data_url_condition: Option<RawAssetParserDataUrl>,
 }
@@ -343,17 +355,37 @@ impl From<RawAssetParserOptions> for AssetParserOptions {
   }
 }
 
+type RawAssetParserDataUrlFn = ThreadsafeFunction<(Buffer, RawAssetParserDataUrlFnCtx), bool>;
+
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawAssetParserDataUrl {
-  #[napi(ts_type = r#""options""#)]
+  #[napi(ts_type = r#""options" | "function""#)]
   pub r#type: String,
   pub options: Option<RawAssetParserDataUrlOptions>,
-  // TODO: pub function
+  #[napi(ts_type = "(source: Buffer, context: RawAssetParserDataUrlFnCtx) => boolean")]
+  pub function: Option<RawAssetParserDataUrlFn>,
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawAssetParserDataUrlFnCtx {
+  pub filename: String,
+  pub context: String,
+}
+
+impl From<AssetParserDataUrlFnCtx> for RawAssetParserDataUrlFnCtx {
+  fn from(value: AssetParserDataUrlFnCtx) -> Self {
+    Self {
+      filename: value.filename,
+      context: value.context,
+    }
+  }
 }
 
 impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
   fn from(value: RawAssetParserDataUrl) -> Self {
+    use pollster::block_on;
     match value.r#type.as_str() {
       "options" => Self::Options(
         value
@@ -361,8 +393,16 @@ impl From<RawAssetParserDataUrl> for AssetParserDataUrl {
           .expect("should have an \"options\" when RawAssetParserDataUrl.type is \"options\"")
           .into(),
       ),
+      "function" => {
+        let func = value
+          .function
+          .expect("should have a \"function\" when RawAssetParserDataUrl.type is \"function\"");
+        Self::Func(Arc::new(move |source, ctx| {
+          block_on(func.call((source.into(), ctx.into())))
+        }))
+      }
       _ => panic!(
-        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options`.",
+        "Failed to resolve the RawAssetParserDataUrl.type {}. Expected type is `options` or `function`.",
         value.r#type
       ),
     }
@@ -387,12 +427,14 @@ impl From<RawAssetParserDataUrlOptions> for AssetParserDataUrlOptions {
 #[napi(object)]
 pub struct RawCssParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
 }
 
 impl From<RawCssParserOptions> for CssParserOptions {
   fn from(value: RawCssParserOptions) -> Self {
     Self {
       named_exports: value.named_exports,
+      ignore_order: value.ignore_order,
     }
   }
 }
@@ -401,12 +443,14 @@ impl From<RawCssParserOptions> for CssParserOptions {
 #[napi(object)]
 pub struct RawCssAutoParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
 }
 
 impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
   fn from(value: RawCssAutoParserOptions) -> Self {
     Self {
       named_exports: value.named_exports,
+      ignore_order: value.ignore_order,
     }
   }
 }
@@ -415,16 +459,52 @@ impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
 #[napi(object)]
 pub struct RawCssModuleParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
 }
 
 impl From<RawCssModuleParserOptions> for CssModuleParserOptions {
   fn from(value: RawCssModuleParserOptions) -> Self {
     Self {
       named_exports: value.named_exports,
+      ignore_order: value.ignore_order,
     }
   }
 }
 
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawJsonParserOptions {
+  #[napi(ts_type = r#""json" | "json5" | "toml" | "yaml" | ((source: string) => string)"#)]
+  pub parse: Option<Either<String, ThreadsafeFunction<String, String>>>,
+}
+
+impl TryFrom<RawJsonParserOptions> for JsonParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawJsonParserOptions) -> rspack_error::Result<Self> {
+    use pollster::block_on;
+    let parse = match value.parse {
+      None => None,
+      Some(Either::A(s)) => Some(match s.as_str() {
+        "json" => JsonParse::Json,
+        "json5" => JsonParse::Json5,
+        "toml" => JsonParse::Toml,
+        "yaml" => JsonParse::Yaml,
+        _ => {
+          return Err(error!(
+            "Failed to resolve the RawJsonParserOptions.parse {s}. Expected parse is `json`, `json5`, `toml`, `yaml` or a function."
+          ))
+        }
+      }),
+      Some(Either::B(func)) => Some(JsonParse::Func(Arc::new(move |source| {
+        let result = block_on(func.call(source))?;
+        json::parse(&result).map_err(|e| error!("Failed to parse the result of parser.parse: {e}"))
+      }))),
+    };
+    Ok(Self { parse })
+  }
+}
+
 #[derive(Debug, Default)]
 #[napi(object, object_to_js = false)]
 pub struct RawGeneratorOptions {
@@ -822,7 +902,7 @@ impl TryFrom<RawModuleRule> for ModuleRule {
         r#use: uses.transpose()?.unwrap_or_default(),
         r#type: module_type,
         layer: value.layer,
-        parser: value.parser.map(|raw| raw.into()),
+        parser: value.parser.map(|raw| raw.try_into()).transpose()?,
         generator: value.generator.map(|raw| raw.into()),
         resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
         side_effects: value.side_effects,
@@ -847,7 +927,7 @@ impl TryFrom<RawModuleOptions> for ModuleOptions {
         .parser
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((k, v.into())))
+            .map(|(k, v)| Ok((k, v.try_into()?)))
             .collect::<std::result::Result<ParserOptionsMap, rspack_error::Error>>()
         })
         .transpose()?,
@@ -1,31 +1,47 @@
+use napi::bindgen_prelude::Either3;
 use napi_derive::napi;
-use rspack_core::CleanOptions;
-use rspack_napi::napi;
+use rspack_core::{CleanKeep, CleanKeepFn, CleanOptions};
+use rspack_napi::{napi, threadsafe_function::ThreadsafeFunction};
+use rspack_regex::RspackRegex;
+
+pub type RawCleanKeep = Either3<String, RspackRegex, ThreadsafeFunction<String, Option<bool>>>;
 
 /// File clean options
 ///
 /// This matches with:
 /// - keep:
-///   - If a string, keep the files under this path
+///   - If a string, keep the files under this path, or the files matched by it if it's a glob
+///   - If a RegExp, keep the files matched by it
+///   - If a function, keep the files when it returns true
+/// - dry: Only log the files that would be removed
+/// - emittedOnly: Only remove the files emitted by this compiler before
 #[napi(object, object_to_js = false)]
 #[derive(Debug)]
 pub struct JsCleanOptions {
-  pub keep: Option<String>,
-  // todo:
-  // - support RegExp type
-  //   if path match the RegExp, keep the file
-  // - support function type
-  //    if the fn returns true on path str, keep the file
+  #[napi(ts_type = "string | RegExp | ((path: string) => boolean)")]
+  pub keep: Option<RawCleanKeep>,
+  pub dry: Option<bool>,
+  pub emitted_only: Option<bool>,
+}
+
+fn js_func_to_clean_keep_func(v: ThreadsafeFunction<String, Option<bool>>) -> CleanKeepFn {
+  Box::new(move |s| {
+    let v = v.clone();
+    Box::pin(async move { v.call(s).await.map(|v| v.unwrap_or_default()) })
+  })
 }
 
 impl JsCleanOptions {
   pub fn to_clean_options(&self) -> CleanOptions {
-    let keep = self.keep.as_ref();
-    if let Some(path) = keep {
-      let p = path.as_str();
-      CleanOptions::from(p)
-    } else {
-      CleanOptions::CleanAll(false)
+    let keep = self.keep.as_ref().map(|keep| match keep {
+      Either3::A(path) => CleanKeep::from(path.as_str()),
+      Either3::B(regex) => CleanKeep::Regexp(regex.clone()),
+      Either3::C(func) => CleanKeep::Fn(js_func_to_clean_keep_func(func.clone())),
+    });
+    CleanOptions::Detailed {
+      keep,
+      dry: self.dry.unwrap_or(false),
+      emitted_only: self.emitted_only.unwrap_or(false),
     }
   }
 }
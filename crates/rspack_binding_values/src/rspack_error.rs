@@ -1,5 +1,5 @@
 use napi_derive::napi;
-use rspack_error::{miette, Diagnostic, Result, RspackSeverity};
+use rspack_error::{miette, Diagnostic, DiagnosticCode, Result, RspackSeverity};
 
 #[napi(object)]
 pub struct JsRspackDiagnostic {
@@ -42,6 +42,8 @@ impl From<JsRspackSeverity> for miette::Severity {
 pub struct JsRspackError {
   pub name: String,
   pub message: String,
+  /// The stable code of the diagnostic, such as `RSPACK_E0001`
+  pub code: Option<String>,
   pub module_identifier: Option<String>,
   pub loc: Option<String>,
   pub file: Option<String>,
@@ -56,9 +58,13 @@ impl JsRspackError {
         match diagnostic.severity() {
           rspack_error::RspackSeverity::Error => "Error".to_string(),
           rspack_error::RspackSeverity::Warn => "Warn".to_string(),
+          rspack_error::RspackSeverity::Advice => "Advice".to_string(),
         }
       }),
       message: diagnostic.render_report(colored)?,
+      code: diagnostic
+        .diagnostic_code()
+        .map(|code| code.code().to_string()),
       module_identifier: diagnostic.module_identifier().map(|d| d.to_string()),
       loc: diagnostic.loc(),
       file: diagnostic.file().map(|f| f.as_str().to_string()),
@@ -68,13 +74,20 @@ impl JsRspackError {
   }
 
   pub fn into_diagnostic(self, severity: RspackSeverity) -> Diagnostic {
-    (match severity {
-      RspackSeverity::Error => Diagnostic::error,
-      RspackSeverity::Warn => Diagnostic::warn,
-    })(self.name, self.message)
-    .with_file(self.file.map(Into::into))
-    .with_module_identifier(self.module_identifier.map(Into::into))
-    .with_stack(self.stack)
-    .with_hide_stack(self.hide_stack)
+    let mut diagnostic = match severity {
+      RspackSeverity::Error => Diagnostic::error(self.name, self.message),
+      RspackSeverity::Warn => Diagnostic::warn(self.name, self.message),
+      RspackSeverity::Advice => {
+        Diagnostic::warn(self.name, self.message).with_severity(RspackSeverity::Advice)
+      }
+    };
+    if let Some(code) = self.code.as_deref().and_then(DiagnosticCode::from_code) {
+      diagnostic = diagnostic.with_diagnostic_code(code);
+    }
+    diagnostic
+      .with_file(self.file.map(Into::into))
+      .with_module_identifier(self.module_identifier.map(Into::into))
+      .with_stack(self.stack)
+      .with_hide_stack(self.hide_stack)
   }
 }
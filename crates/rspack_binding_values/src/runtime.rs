@@ -54,6 +54,7 @@ static RUNTIME_GLOBAL_MAP: LazyLock<(
   declare_runtime_global!(GLOBAL);
   declare_runtime_global!(RETURN_EXPORTS_FROM_RUNTIME);
   declare_runtime_global!(INSTANTIATE_WASM);
+  declare_runtime_global!(WASM_INSTANCES);
   declare_runtime_global!(ASYNC_MODULE);
   declare_runtime_global!(BASE_URI);
   declare_runtime_global!(MODULE_LOADED);
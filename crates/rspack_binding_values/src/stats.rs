@@ -70,6 +70,7 @@ pub struct JsStatsError {
   #[napi(ts_type = "JsModuleDescriptor")]
   pub module_descriptor: Option<JsModuleDescriptorWrapper>,
   pub message: String,
+  pub code: Option<String>,
   pub chunk_name: Option<String>,
   pub chunk_entry: Option<bool>,
   pub chunk_initial: Option<bool>,
@@ -93,6 +94,7 @@ impl From<rspack_core::StatsError<'_>> for JsStatsError {
         .into()
       }),
       message: stats.message,
+      code: stats.code.map(ToOwned::to_owned),
       loc: stats.loc,
       file: stats.file.map(|f| f.as_str().to_string()),
       chunk_name: stats.chunk_name,
@@ -115,6 +117,7 @@ pub struct JsStatsWarning {
   #[napi(ts_type = "JsModuleDescriptor")]
   pub module_descriptor: Option<JsModuleDescriptorWrapper>,
   pub message: String,
+  pub code: Option<String>,
   pub chunk_name: Option<String>,
   pub chunk_entry: Option<bool>,
   pub chunk_initial: Option<bool>,
@@ -137,6 +140,7 @@ impl From<rspack_core::StatsWarning<'_>> for JsStatsWarning {
         .into()
       }),
       message: stats.message,
+      code: stats.code.map(ToOwned::to_owned),
       file: stats.file.map(|f| f.as_str().to_string()),
       chunk_name: stats.chunk_name,
       chunk_entry: stats.chunk_entry,
@@ -983,6 +987,7 @@ pub struct JsStatsCompilation {
   pub modules: Option<Vec<JsStatsModule>>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup>>,
   pub warnings: Vec<JsStatsWarning>,
+  pub advices: Vec<JsStatsWarning>,
 }
 
 pub struct JsStatsCompilationWrapper(JsStatsCompilation);
@@ -1060,6 +1065,8 @@ impl JsStats {
 
     let warnings = self.warnings();
 
+    let advices = self.advices();
+
     Ok(JsStatsCompilationWrapper(JsStatsCompilation {
       assets,
       assets_by_chunk_name,
@@ -1070,6 +1077,7 @@ impl JsStats {
       modules,
       named_chunk_groups,
       warnings,
+      advices,
     }))
   }
 
@@ -1145,6 +1153,15 @@ impl JsStats {
       .collect()
   }
 
+  fn advices(&self) -> Vec<JsStatsWarning> {
+    self
+      .inner
+      .get_advices()
+      .into_iter()
+      .map(Into::into)
+      .collect()
+  }
+
   #[napi]
   pub fn has_warnings(&self) -> bool {
     !self.inner.get_warnings().is_empty()
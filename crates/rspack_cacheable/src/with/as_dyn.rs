@@ -0,0 +1,71 @@
+use rkyv::{
+  boxed::{ArchivedBox, BoxResolver},
+  with::{ArchiveWith, DeserializeWith, SerializeWith},
+  ArchiveUnsized, Deserialize, Place, SerializeUnsized,
+};
+
+use crate::{DeserializeError, Deserializer, SerializeError, Serializer};
+
+/// Serialize a trait object through its `#[cacheable_dyn]` counterpart trait.
+///
+/// Useful when only part of the implementors of a trait are cacheable,
+/// serializing a value whose implementor is not cacheable will return
+/// `SerializeError::UnsupportedField`.
+pub struct AsDyn;
+
+pub trait AsDynConverter {
+  type Cacheable: ?Sized + ArchiveUnsized;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable>;
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self
+  where
+    Self: Sized;
+}
+
+impl<T> ArchiveWith<T> for AsDyn
+where
+  T: AsDynConverter,
+{
+  type Archived = ArchivedBox<<T::Cacheable as ArchiveUnsized>::Archived>;
+  type Resolver = BoxResolver;
+
+  #[inline]
+  fn resolve_with(field: &T, resolver: Self::Resolver, out: Place<Self::Archived>) {
+    let cacheable = field
+      .to_cacheable()
+      .expect("should be cacheable when resolve");
+    ArchivedBox::resolve_from_ref(cacheable, resolver, out);
+  }
+}
+
+impl<'a, T> SerializeWith<T, Serializer<'a>> for AsDyn
+where
+  T: AsDynConverter,
+  T::Cacheable: SerializeUnsized<Serializer<'a>>,
+{
+  #[inline]
+  fn serialize_with(
+    field: &T,
+    serializer: &mut Serializer<'a>,
+  ) -> Result<Self::Resolver, SerializeError> {
+    let cacheable = field
+      .to_cacheable()
+      .ok_or(SerializeError::UnsupportedField)?;
+    ArchivedBox::serialize_from_ref(cacheable, serializer)
+  }
+}
+
+impl<T> DeserializeWith<ArchivedBox<<T::Cacheable as ArchiveUnsized>::Archived>, T, Deserializer>
+  for AsDyn
+where
+  T: AsDynConverter,
+  ArchivedBox<<T::Cacheable as ArchiveUnsized>::Archived>:
+    Deserialize<Box<T::Cacheable>, Deserializer>,
+{
+  #[inline]
+  fn deserialize_with(
+    field: &ArchivedBox<<T::Cacheable as ArchiveUnsized>::Archived>,
+    deserializer: &mut Deserializer,
+  ) -> Result<T, DeserializeError> {
+    Ok(T::from_cacheable(field.deserialize(deserializer)?))
+  }
+}
@@ -1,5 +1,6 @@
 mod r#as;
 mod as_cacheable;
+mod as_dyn;
 mod as_inner;
 mod as_map;
 mod as_owned;
@@ -13,6 +14,7 @@ mod inline;
 mod unsupported;
 
 pub use as_cacheable::AsCacheable;
+pub use as_dyn::{AsDyn, AsDynConverter};
 pub use as_inner::{AsInner, AsInnerConverter};
 pub use as_map::{AsMap, AsMapConverter};
 pub use as_owned::AsOwned;
@@ -0,0 +1,81 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn, from_bytes, to_bytes,
+  with::{AsDyn, AsDynConverter},
+  SerializeError,
+};
+
+trait Animal {
+  fn name(&self) -> &str;
+  fn as_cacheable(&self) -> Option<&(dyn CacheableAnimal + 'static)> {
+    None
+  }
+}
+
+#[cacheable_dyn]
+trait CacheableAnimal: Animal {
+  fn into_animal(self: Box<Self>) -> Box<dyn Animal>;
+}
+
+impl AsDynConverter for Box<dyn Animal> {
+  type Cacheable = dyn CacheableAnimal;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable> {
+    self.as_ref().as_cacheable()
+  }
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self {
+    data.into_animal()
+  }
+}
+
+#[cacheable]
+struct Dog {
+  name: String,
+}
+impl Animal for Dog {
+  fn name(&self) -> &str {
+    &self.name
+  }
+  fn as_cacheable(&self) -> Option<&(dyn CacheableAnimal + 'static)> {
+    Some(self)
+  }
+}
+#[cacheable_dyn]
+impl CacheableAnimal for Dog {
+  fn into_animal(self: Box<Self>) -> Box<dyn Animal> {
+    self
+  }
+}
+
+struct Cat;
+impl Animal for Cat {
+  fn name(&self) -> &str {
+    "cat"
+  }
+}
+
+#[cacheable]
+struct Data {
+  #[cacheable(with=AsDyn)]
+  animal: Box<dyn Animal>,
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_as_dyn() {
+  let data = Data {
+    animal: Box::new(Dog {
+      name: String::from("dog"),
+    }),
+  };
+  let bytes = to_bytes(&data, &()).unwrap();
+  let new_data: Data = from_bytes(&bytes, &()).unwrap();
+  assert_eq!(new_data.animal.name(), "dog");
+
+  let data = Data {
+    animal: Box::new(Cat),
+  };
+  assert_eq!(data.animal.name(), "cat");
+  assert!(matches!(
+    to_bytes(&data, &()),
+    Err(SerializeError::UnsupportedField)
+  ));
+}
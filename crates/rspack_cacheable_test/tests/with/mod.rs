@@ -1,5 +1,6 @@
 mod r#as;
 mod as_cacheable;
+mod as_dyn;
 mod as_inner;
 mod as_map;
 mod as_owned;
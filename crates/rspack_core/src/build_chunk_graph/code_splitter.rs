@@ -11,7 +11,7 @@ use rspack_collections::{
   UkeyMap, UkeySet,
 };
 use rspack_collections::{IdentifierIndexSet, IdentifierMap};
-use rspack_error::{error, Diagnostic, Error, Result};
+use rspack_error::{code::ASYNC_DEPENDENCY_TO_INITIAL_CHUNK, error, Diagnostic, Error, Result};
 use rspack_util::itoa;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
@@ -1431,7 +1431,10 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
             .is_initial()
           {
             let error = AsyncDependenciesToInitialChunkError(chunk_name.to_string(), loc.clone());
-            compilation.push_diagnostic(Error::from(error).into());
+            compilation.push_diagnostic(
+              Diagnostic::from(Error::from(error))
+                .with_diagnostic_code(&ASYNC_DEPENDENCY_TO_INITIAL_CHUNK),
+            );
             cgi = item_chunk_group_info;
           }
 
@@ -1561,9 +1564,8 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .block_modules_runtime_map
       .entry(runtime.cloned().into())
       .or_default();
-    let block = module.into();
-    map.insert(block, Vec::new());
-    for b in block.get_blocks(compilation) {
+    map.insert(module.into(), Vec::new());
+    for b in module_graph.get_module_all_blocks(&module) {
       map.insert(b.into(), Vec::new());
     }
 
@@ -6,4 +6,5 @@ use super::Cache;
 #[derive(Debug)]
 pub struct DisableCache;
 
+#[async_trait::async_trait]
 impl Cache for DisableCache {}
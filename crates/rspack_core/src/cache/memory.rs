@@ -7,4 +7,5 @@ use super::Cache;
 #[derive(Debug)]
 pub struct MemoryCache;
 
+#[async_trait::async_trait]
 impl Cache for MemoryCache {}
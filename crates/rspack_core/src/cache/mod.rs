@@ -4,10 +4,11 @@ pub mod persistent;
 
 use std::{fmt::Debug, sync::Arc};
 
+use rspack_error::Result;
 use rspack_fs::FileSystem;
 
 use self::{disable::DisableCache, memory::MemoryCache, persistent::PersistentCache};
-use crate::{Compilation, CompilerOptions, ExperimentCacheOptions};
+use crate::{Compilation, CompilerOptions, ExperimentCacheOptions, MakeArtifact};
 
 /// Cache trait
 ///
@@ -21,15 +22,31 @@ use crate::{Compilation, CompilerOptions, ExperimentCacheOptions};
 /// * This API does not need to cooperate with the js side.
 ///
 /// We can consider change to Hook when we need to open the API to js side.
+#[async_trait::async_trait]
 pub trait Cache: Debug + Send + Sync {
-  fn before_compile(&self, _compilation: &mut Compilation) {}
-  fn after_compile(&self, _compilation: &Compilation) {}
+  async fn before_compile(&self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+  async fn after_compile(&self, _compilation: &Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  async fn before_make(&self, _make_artifact: &mut MakeArtifact) -> Result<()> {
+    Ok(())
+  }
+  async fn after_make(&self, _make_artifact: &MakeArtifact) -> Result<()> {
+    Ok(())
+  }
 }
 
 pub fn new_cache(compiler_option: Arc<CompilerOptions>, fs: Arc<dyn FileSystem>) -> Arc<dyn Cache> {
   match &compiler_option.experiments.cache {
     ExperimentCacheOptions::Disabled => Arc::new(DisableCache),
     ExperimentCacheOptions::Memory => Arc::new(MemoryCache),
-    ExperimentCacheOptions::Persistent(option) => Arc::new(PersistentCache::new(option, fs)),
+    ExperimentCacheOptions::Persistent(option) => Arc::new(PersistentCache::new(
+      option,
+      compiler_option.context.as_path(),
+      fs,
+    )),
   }
 }
@@ -1,18 +1,21 @@
+mod occasion;
 pub mod snapshot;
 pub mod storage;
 
 use std::sync::Arc;
 
+use rspack_error::Result;
 use rspack_fs::FileSystem;
-use rspack_paths::ArcPath;
+use rspack_paths::{ArcPath, Utf8Path};
 use rustc_hash::FxHashSet as HashSet;
 
 use self::{
+  occasion::MakeOccasion,
   snapshot::{Snapshot, SnapshotOptions},
-  storage::{MemoryStorage, Storage, StorageOptions},
+  storage::{new_storage, Storage, StorageOptions},
 };
 use super::Cache;
-use crate::Compilation;
+use crate::{Compilation, MakeArtifact};
 
 #[derive(Debug, Clone)]
 pub struct PersistentCacheOptions {
@@ -25,29 +28,35 @@ pub struct PersistentCacheOptions {
 pub struct PersistentCache {
   storage: Arc<dyn Storage>,
   snapshot: Snapshot,
+  make_occasion: MakeOccasion,
 }
 
 impl PersistentCache {
-  pub fn new(option: &PersistentCacheOptions, fs: Arc<dyn FileSystem>) -> Self {
-    let storage = Arc::new(MemoryStorage::default());
+  pub fn new(option: &PersistentCacheOptions, context: &Utf8Path, fs: Arc<dyn FileSystem>) -> Self {
+    let storage = new_storage(&option.storage, context, fs.clone());
     Self {
       snapshot: Snapshot::new(option.snapshot.clone(), fs, storage.clone()),
+      make_occasion: MakeOccasion::new(storage.clone()),
       storage,
     }
   }
 }
 
+#[async_trait::async_trait]
 impl Cache for PersistentCache {
-  fn before_compile(&self, compilation: &mut Compilation) {
+  async fn before_compile(&self, compilation: &mut Compilation) -> Result<()> {
+    // the hooks of this compilation may create dependencies before the module graph is recovered
+    self.make_occasion.reserve_dependency_ids();
     if compilation.modified_files.is_empty() && compilation.removed_files.is_empty() {
       // inject modified_files and removed_files
       let (modified_paths, removed_paths) = self.snapshot.calc_modified_paths();
       compilation.modified_files = modified_paths;
       compilation.removed_files = removed_paths;
     }
+    Ok(())
   }
 
-  fn after_compile(&self, compilation: &Compilation) {
+  async fn after_compile(&self, compilation: &Compilation) -> Result<()> {
     // TODO add a all_dependencies to collect dependencies
     let (_, file_added, file_removed) = compilation.file_dependencies();
     let (_, context_added, context_removed) = compilation.context_dependencies();
@@ -78,6 +87,19 @@ impl Cache for PersistentCache {
       .snapshot
       .add(modified_paths.iter().map(|item| item.as_ref()));
 
-    self.storage.idle();
+    self.storage.idle().await
+  }
+
+  async fn before_make(&self, make_artifact: &mut MakeArtifact) -> Result<()> {
+    // only recover on cold start, a rebuild reuses the module graph of last compilation
+    if make_artifact.get_module_graph_partial().modules.is_empty() {
+      self.make_occasion.recovery(make_artifact);
+    }
+    Ok(())
+  }
+
+  async fn after_make(&self, make_artifact: &MakeArtifact) -> Result<()> {
+    self.make_occasion.save(make_artifact);
+    Ok(())
   }
 }
@@ -0,0 +1,570 @@
+use std::sync::{atomic::Ordering::Relaxed, Arc};
+
+use rspack_cacheable::{
+  cacheable, from_bytes, to_bytes,
+  with::{AsCacheable, AsDyn, AsTuple2, AsVec, Inline},
+};
+use rustc_hash::FxHashSet as HashSet;
+
+use super::super::storage::Storage;
+use crate::{
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BoxModule, DependencyId,
+  DependencyParents, ExportInfoData, ExportsInfoData, MakeArtifact, ModuleGraph, ModuleGraphModule,
+  ModuleIdentifier, ModuleIssuer, DEPENDENCY_ID,
+};
+
+const SCOPE: &str = "occasion_make";
+/// Stores the value of [DEPENDENCY_ID] when the module graph is saved,
+/// which is greater than the ids of all saved dependencies.
+const META_SCOPE: &str = "occasion_make_meta";
+const DEPENDENCY_ID_KEY: &[u8] = b"dependency_id";
+
+/// A module with the dependencies, blocks and outgoing connections it owns in the module graph.
+#[cacheable]
+struct Node {
+  #[cacheable(with=AsDyn)]
+  module: BoxModule,
+  issuer: ModuleIssuer,
+  #[cacheable(with=AsVec<AsTuple2<AsDyn, AsCacheable>>)]
+  dependencies: Vec<(BoxDependency, Option<AsyncDependenciesBlockIdentifier>)>,
+  blocks: Vec<AsyncDependenciesBlock>,
+  connections: Vec<(DependencyId, ModuleIdentifier)>,
+}
+
+/// The borrowed version of [Node], modules are saved without being cloned out of the module graph.
+#[cacheable(as=Node)]
+struct NodeRef<'a> {
+  #[allow(clippy::borrowed_box)]
+  #[cacheable(with=Inline<AsDyn>)]
+  module: &'a BoxModule,
+  #[cacheable(with=Inline)]
+  issuer: &'a ModuleIssuer,
+  #[cacheable(with=AsVec<AsTuple2<Inline<AsDyn>, AsCacheable>>)]
+  dependencies: Vec<(&'a BoxDependency, Option<AsyncDependenciesBlockIdentifier>)>,
+  #[cacheable(with=AsVec<Inline>)]
+  blocks: Vec<&'a AsyncDependenciesBlock>,
+  connections: Vec<(DependencyId, ModuleIdentifier)>,
+}
+
+/// Make Occasion is used to save and recover the module graph of the make phase.
+///
+/// Each module is stored as a [Node] keyed by its identifier. Modules which can not
+/// be serialized are left out, and the dependencies pointing to them will be
+/// factorized again after recovery.
+#[derive(Debug)]
+pub struct MakeOccasion {
+  storage: Arc<dyn Storage>,
+}
+
+impl MakeOccasion {
+  pub fn new(storage: Arc<dyn Storage>) -> Self {
+    Self { storage }
+  }
+
+  /// Raise [DEPENDENCY_ID] above the ids of the saved dependencies.
+  ///
+  /// It should be called before any dependency is created by the compilation,
+  /// so that the new dependencies will not reuse the ids of the recovered ones.
+  pub fn reserve_dependency_ids(&self) {
+    let saved_id = self
+      .storage
+      .get_all(META_SCOPE)
+      .into_iter()
+      .find(|(key, _)| key == DEPENDENCY_ID_KEY)
+      .and_then(|(_, value)| Some(u32::from_le_bytes(value.try_into().ok()?)));
+    if let Some(saved_id) = saved_id {
+      DEPENDENCY_ID.fetch_max(saved_id, Relaxed);
+    }
+  }
+
+  pub fn save(&self, artifact: &MakeArtifact) {
+    let module_graph = artifact.get_module_graph();
+
+    for module_identifier in &artifact.revoked_modules {
+      if module_graph
+        .module_by_identifier(module_identifier)
+        .is_none()
+      {
+        self.storage.remove(SCOPE, module_identifier.as_bytes());
+      }
+    }
+
+    // the outgoing connections of the origin modules point to the rebuilt modules,
+    // so the origin modules should be saved again
+    let mut need_save_modules = artifact.built_modules.clone();
+    for module_identifier in &artifact.built_modules {
+      need_save_modules.extend(
+        module_graph
+          .get_incoming_connections(module_identifier)
+          .filter_map(|connection| connection.original_module_identifier),
+      );
+    }
+
+    for module_identifier in need_save_modules {
+      let key = module_identifier.as_bytes();
+      match Self::serialize_node(&module_graph, &module_identifier) {
+        Some(value) => self.storage.set(SCOPE, key.to_vec(), value),
+        None => self.storage.remove(SCOPE, key),
+      }
+    }
+
+    self.storage.set(
+      META_SCOPE,
+      DEPENDENCY_ID_KEY.to_vec(),
+      DEPENDENCY_ID.load(Relaxed).to_le_bytes().to_vec(),
+    );
+  }
+
+  fn serialize_node(
+    module_graph: &ModuleGraph,
+    module_identifier: &ModuleIdentifier,
+  ) -> Option<Vec<u8>> {
+    let module = module_graph.module_by_identifier(module_identifier)?;
+    let mgm = module_graph.module_graph_module_by_identifier(module_identifier)?;
+    let node = NodeRef {
+      module,
+      issuer: mgm.issuer(),
+      dependencies: mgm
+        .all_dependencies
+        .iter()
+        .filter_map(|dep_id| {
+          let dep = module_graph.dependency_by_id(dep_id)?;
+          Some((dep, module_graph.get_parent_block(dep_id).copied()))
+        })
+        .collect(),
+      blocks: module_graph
+        .get_module_all_blocks(module_identifier)
+        .iter()
+        .filter_map(|block_id| module_graph.block_by_id(block_id))
+        .collect(),
+      connections: module_graph
+        .get_outgoing_connections(module_identifier)
+        .map(|connection| (connection.dependency_id, *connection.module_identifier()))
+        .collect(),
+    };
+    // the module or one of its dependencies is not cacheable
+    to_bytes::<_, ()>(&node, &()).ok()
+  }
+
+  pub fn recovery(&self, artifact: &mut MakeArtifact) {
+    let mut nodes = vec![];
+    for (key, value) in self.storage.get_all(SCOPE) {
+      match from_bytes::<Node, ()>(&value, &()) {
+        Ok(node) => nodes.push(node),
+        // saved by an incompatible version
+        Err(_) => self.storage.remove(SCOPE, &key),
+      }
+    }
+    if nodes.is_empty() {
+      return;
+    }
+    // the dependencies created before the compilation, e.g. the entry dependencies created by
+    // plugins, may reuse the saved ids, a cold build is needed in this case
+    let existing_module_graph = artifact.get_module_graph();
+    if nodes.iter().any(|node| {
+      node
+        .dependencies
+        .iter()
+        .any(|(dep, _)| existing_module_graph.dependency_by_id(dep.id()).is_some())
+    }) {
+      tracing::warn!("make occasion recovery skipped: dependency ids conflict with existing ones");
+      return;
+    }
+
+    let mut module_graph = ModuleGraph::new(vec![], Some(&mut artifact.module_graph_partial));
+    let mut module_dependencies = vec![];
+    let mut connections = vec![];
+    for node in nodes {
+      let Node {
+        module,
+        issuer,
+        dependencies,
+        blocks,
+        connections: outgoing_connections,
+      } = node;
+      let module_identifier = module.identifier();
+
+      let other_exports_info = ExportInfoData::new(None, None);
+      let side_effects_only_info = ExportInfoData::new(Some("*side effects only*".into()), None);
+      let exports_info = ExportsInfoData::new(other_exports_info.id(), side_effects_only_info.id());
+      let mut mgm = ModuleGraphModule::new(module_identifier, exports_info.id());
+      mgm.set_issuer(issuer);
+      mgm.all_dependencies = dependencies.iter().map(|(dep, _)| *dep.id()).collect();
+      module_graph.add_module_graph_module(mgm);
+      module_graph.set_exports_info(exports_info.id(), exports_info);
+      module_graph.set_export_info(side_effects_only_info.id(), side_effects_only_info);
+      module_graph.set_export_info(other_exports_info.id(), other_exports_info);
+
+      for (dep, block) in dependencies {
+        let dep_id = *dep.id();
+        if dep.as_module_dependency().is_some() || dep.as_context_dependency().is_some() {
+          module_dependencies.push((dep_id, module_identifier));
+        }
+        module_graph.set_parents(
+          dep_id,
+          DependencyParents {
+            block,
+            module: module_identifier,
+          },
+        );
+        module_graph.add_dependency(dep);
+      }
+      for block in blocks {
+        module_graph.add_block(Box::new(block));
+      }
+
+      if let Some(build_info) = module.build_info() {
+        artifact
+          .file_dependencies
+          .add_batch_file(&build_info.file_dependencies);
+        artifact
+          .context_dependencies
+          .add_batch_file(&build_info.context_dependencies);
+        artifact
+          .missing_dependencies
+          .add_batch_file(&build_info.missing_dependencies);
+        artifact
+          .build_dependencies
+          .add_batch_file(&build_info.build_dependencies);
+      }
+      module_graph.add_module(module);
+      artifact.built_modules.insert(module_identifier);
+      connections.extend(
+        outgoing_connections
+          .into_iter()
+          .map(|(dep_id, target)| (module_identifier, dep_id, target)),
+      );
+    }
+
+    let mut resolved_dependencies = HashSet::default();
+    for (origin, dep_id, target) in connections {
+      if module_graph
+        .module_graph_module_by_identifier(&target)
+        .is_some()
+      {
+        module_graph
+          .set_resolved_module(Some(origin), dep_id, target)
+          .expect("should set resolved module success");
+        resolved_dependencies.insert(dep_id);
+      }
+    }
+    // the target modules of these dependencies were not saved, factorize them again
+    artifact.make_failed_dependencies.extend(
+      module_dependencies
+        .into_iter()
+        .filter(|(dep_id, _)| !resolved_dependencies.contains(dep_id))
+        .map(|(dep_id, origin)| (dep_id, Some(origin))),
+    );
+
+    artifact.has_module_graph_change = true;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    borrow::Cow,
+    sync::{atomic::Ordering::Relaxed, Arc},
+  };
+
+  use rspack_cacheable::{cacheable, cacheable_dyn};
+  use rspack_collections::Identifiable;
+  use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+  use rspack_macros::impl_source_map_config;
+  use rspack_sources::Source;
+  use rspack_util::source_map::SourceMapKind;
+
+  use super::super::super::storage::{MemoryStorage, Storage};
+  use super::{MakeOccasion, DEPENDENCY_ID_KEY, META_SCOPE, SCOPE};
+  use crate::{
+    AffectType, AsContextDependency, AsDependencyTemplate, BuildInfo, BuildMeta,
+    CacheableDependency, CacheableModule, CodeGenerationResult, Compilation, ConcatenationScope,
+    Context, DependenciesBlock, Dependency, DependencyId, ExportsInfo, FactoryMeta, MakeArtifact,
+    Module, ModuleDependency, ModuleGraphModule, ModuleIdentifier, ModuleIssuer, ModuleType,
+    RuntimeSpec, SourceType, DEPENDENCY_ID,
+  };
+
+  #[cacheable]
+  #[derive(Debug, Clone)]
+  struct TestDep {
+    request: String,
+    id: DependencyId,
+  }
+
+  impl AsContextDependency for TestDep {}
+  impl AsDependencyTemplate for TestDep {}
+
+  impl Dependency for TestDep {
+    fn id(&self) -> &DependencyId {
+      &self.id
+    }
+
+    fn could_affect_referencing_module(&self) -> AffectType {
+      AffectType::True
+    }
+
+    fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+      Some(self)
+    }
+  }
+
+  impl ModuleDependency for TestDep {
+    fn request(&self) -> &str {
+      &self.request
+    }
+  }
+
+  #[cacheable_dyn]
+  impl CacheableDependency for TestDep {}
+
+  #[impl_source_map_config]
+  #[cacheable]
+  #[derive(Debug)]
+  struct TestModule {
+    id: ModuleIdentifier,
+    deps: Vec<DependencyId>,
+    build_info: BuildInfo,
+  }
+
+  impl DependenciesBlock for TestModule {
+    fn add_block_id(&mut self, _block: crate::AsyncDependenciesBlockIdentifier) {
+      todo!()
+    }
+
+    fn get_blocks(&self) -> &[crate::AsyncDependenciesBlockIdentifier] {
+      &[]
+    }
+
+    fn add_dependency_id(&mut self, dependency: DependencyId) {
+      self.deps.push(dependency);
+    }
+
+    fn remove_dependency_id(&mut self, dependency: DependencyId) {
+      self.deps.retain(|dep| dep != &dependency);
+    }
+
+    fn get_dependencies(&self) -> &[DependencyId] {
+      &self.deps
+    }
+  }
+
+  impl Identifiable for TestModule {
+    fn identifier(&self) -> rspack_collections::Identifier {
+      self.id
+    }
+  }
+
+  impl_empty_diagnosable_trait!(TestModule);
+
+  impl Module for TestModule {
+    fn module_type(&self) -> &ModuleType {
+      todo!()
+    }
+
+    fn source_types(&self) -> &[SourceType] {
+      todo!()
+    }
+
+    fn get_diagnostics(&self) -> Vec<Diagnostic> {
+      todo!()
+    }
+
+    fn original_source(&self) -> Option<&dyn Source> {
+      todo!()
+    }
+
+    fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+      todo!()
+    }
+
+    fn size(&self, _source_type: Option<&SourceType>, _compilation: Option<&Compilation>) -> f64 {
+      todo!()
+    }
+
+    fn factory_meta(&self) -> Option<&FactoryMeta> {
+      todo!()
+    }
+
+    fn set_factory_meta(&mut self, _factory_meta: FactoryMeta) {
+      todo!()
+    }
+
+    fn build_info(&self) -> Option<&BuildInfo> {
+      Some(&self.build_info)
+    }
+
+    fn set_build_info(&mut self, _build_info: BuildInfo) {
+      todo!()
+    }
+
+    fn build_meta(&self) -> Option<&BuildMeta> {
+      todo!()
+    }
+
+    fn set_build_meta(&mut self, _build_meta: BuildMeta) {
+      todo!()
+    }
+
+    fn code_generation(
+      &self,
+      _compilation: &Compilation,
+      _runtime: Option<&RuntimeSpec>,
+      _concatenation_scope: Option<ConcatenationScope>,
+    ) -> Result<CodeGenerationResult> {
+      todo!()
+    }
+
+    fn update_hash(
+      &self,
+      _hasher: &mut dyn std::hash::Hasher,
+      _compilation: &Compilation,
+      _runtime: Option<&RuntimeSpec>,
+    ) -> Result<()> {
+      todo!()
+    }
+
+    fn as_cacheable_module(&self) -> Option<&(dyn CacheableModule + 'static)> {
+      Some(self)
+    }
+  }
+
+  #[cacheable_dyn]
+  impl CacheableModule for TestModule {}
+
+  fn add_module(artifact: &mut MakeArtifact, id: &str, deps: Vec<TestDep>) -> ModuleIdentifier {
+    let module_identifier: ModuleIdentifier = id.into();
+    let mut build_info = BuildInfo::default();
+    build_info
+      .file_dependencies
+      .insert(std::path::PathBuf::from(format!("/{id}.js")).into());
+    let mut mg = artifact.get_module_graph_mut();
+    let mut mgm = ModuleGraphModule::new(module_identifier, ExportsInfo::new());
+    mgm.set_issuer(ModuleIssuer::None);
+    mgm.all_dependencies = deps.iter().map(|dep| dep.id).collect();
+    mg.add_module_graph_module(mgm);
+    mg.add_module(Box::new(TestModule {
+      id: module_identifier,
+      deps: deps.iter().map(|dep| dep.id).collect(),
+      build_info,
+      source_map_kind: SourceMapKind::empty(),
+    }));
+    for dep in deps {
+      mg.set_parents(
+        dep.id,
+        crate::DependencyParents {
+          block: None,
+          module: module_identifier,
+        },
+      );
+      mg.add_dependency(Box::new(dep));
+    }
+    artifact.built_modules.insert(module_identifier);
+    module_identifier
+  }
+
+  #[test]
+  fn should_make_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let occasion = MakeOccasion::new(storage.clone());
+
+    let mut artifact = MakeArtifact::default();
+    let dep = TestDep {
+      request: "./lib".into(),
+      id: DependencyId::new(),
+    };
+    let dep_id = dep.id;
+    let app = add_module(&mut artifact, "app", vec![dep]);
+    let lib = add_module(&mut artifact, "lib", vec![]);
+    artifact
+      .get_module_graph_mut()
+      .set_resolved_module(Some(app), dep_id, lib)
+      .expect("should set resolved module success");
+    occasion.save(&artifact);
+    assert_eq!(storage.get_all(SCOPE).len(), 2);
+
+    let mut new_artifact = MakeArtifact::default();
+    occasion.recovery(&mut new_artifact);
+    let mg = new_artifact.get_module_graph();
+    assert!(new_artifact.has_module_graph_change);
+    assert!(new_artifact.make_failed_dependencies.is_empty());
+    assert!(new_artifact.built_modules.contains(&app));
+    assert!(new_artifact.built_modules.contains(&lib));
+    assert_eq!(mg.module_identifier_by_dependency_id(&dep_id), Some(&lib));
+    assert_eq!(mg.get_parent_module(&dep_id), Some(&app));
+    assert_eq!(new_artifact.file_dependencies.files().count(), 2);
+
+    // the dependency should be factorized again when its module is not saved
+    storage.remove(SCOPE, lib.as_bytes());
+    let mut new_artifact = MakeArtifact::default();
+    occasion.recovery(&mut new_artifact);
+    assert!(new_artifact
+      .make_failed_dependencies
+      .contains(&(dep_id, Some(app))));
+  }
+
+  #[test]
+  fn should_not_reuse_dependency_ids_on_warm_start() {
+    let storage = Arc::new(MemoryStorage::default());
+    let occasion = MakeOccasion::new(storage.clone());
+
+    let mut artifact = MakeArtifact::default();
+    let dep = TestDep {
+      request: "./lib".into(),
+      id: DependencyId::new(),
+    };
+    let dep_id = dep.id;
+    let app = add_module(&mut artifact, "app", vec![dep]);
+    occasion.save(&artifact);
+
+    // the entry dependency is created after the saved ids are reserved
+    let reserved = DEPENDENCY_ID.load(Relaxed) + 1000;
+    storage.set(
+      META_SCOPE,
+      DEPENDENCY_ID_KEY.to_vec(),
+      reserved.to_le_bytes().to_vec(),
+    );
+    occasion.reserve_dependency_ids();
+    let entry_id = DependencyId::new();
+    assert!(*entry_id >= reserved);
+    let mut new_artifact = MakeArtifact::default();
+    new_artifact
+      .get_module_graph_mut()
+      .add_dependency(Box::new(TestDep {
+        request: "./app".into(),
+        id: entry_id,
+      }));
+    occasion.recovery(&mut new_artifact);
+    assert!(new_artifact.built_modules.contains(&app));
+    let mg = new_artifact.get_module_graph();
+    assert_eq!(mg.get_parent_module(&dep_id), Some(&app));
+    assert_eq!(mg.get_parent_module(&entry_id), None);
+    let request = |id: &DependencyId| {
+      mg.dependency_by_id(id)
+        .and_then(|dep| dep.as_module_dependency())
+        .map(|dep| dep.request().to_string())
+    };
+    assert_eq!(request(&entry_id).as_deref(), Some("./app"));
+    assert_eq!(request(&dep_id).as_deref(), Some("./lib"));
+
+    // the dependency created before the reservation conflicts with a saved one
+    let mut new_artifact = MakeArtifact::default();
+    new_artifact
+      .get_module_graph_mut()
+      .add_dependency(Box::new(TestDep {
+        request: "./app".into(),
+        id: dep_id,
+      }));
+    occasion.recovery(&mut new_artifact);
+    assert!(new_artifact.built_modules.is_empty());
+    assert!(!new_artifact.has_module_graph_change);
+    assert_eq!(
+      new_artifact
+        .get_module_graph()
+        .dependency_by_id(&dep_id)
+        .and_then(|dep| dep.as_module_dependency())
+        .map(|dep| dep.request().to_string())
+        .as_deref(),
+      Some("./app")
+    );
+  }
+}
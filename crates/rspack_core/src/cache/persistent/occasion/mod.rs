@@ -0,0 +1,3 @@
+mod make;
+
+pub use make::MakeOccasion;
@@ -3,6 +3,7 @@ mod strategy;
 
 use std::{path::Path, sync::Arc};
 
+use rayon::prelude::*;
 use rspack_cacheable::{from_bytes, to_bytes};
 use rspack_fs::FileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
@@ -39,34 +40,35 @@ impl Snapshot {
   }
 
   pub fn add(&self, paths: impl Iterator<Item = &Path>) {
-    let default_strategy = StrategyHelper::compile_time();
-    let mut helper = StrategyHelper::new(self.fs.clone());
-    // TODO use multi thread
     // TODO merge package version file
-    for path in paths {
-      // TODO check path exists
-      // TODO directory check all sub file
+    let default_strategy = StrategyHelper::compile_time();
+    let helper = StrategyHelper::new(self.fs.clone());
+    let paths = paths.collect::<Vec<_>>();
+    paths.into_par_iter().for_each(|path| {
       let path_str = path.assert_utf8().as_str();
       if self.options.is_immutable_path(path_str) {
-        continue;
-      }
-      if self.options.is_managed_path(path_str) {
-        if let Some(v) = helper.package_version(path) {
-          self.storage.set(
-            SCOPE,
-            path.as_os_str().as_encoded_bytes().to_vec(),
-            to_bytes::<_, ()>(&v, &()).expect("should to bytes success"),
-          );
-          continue;
-        }
+        return;
       }
-      // compiler time
+      let strategy = if !helper.exists(path) {
+        Strategy::Missing
+      } else if self.options.is_managed_path(path_str)
+        && let Some(v) = helper.package_version(path)
+      {
+        v
+      } else if self.options.is_hash_path(path_str)
+        && let Some(v) = helper.content_hash_strategy(path)
+      {
+        v
+      } else {
+        // compiler time
+        default_strategy.clone()
+      };
       self.storage.set(
         SCOPE,
         path.as_os_str().as_encoded_bytes().to_vec(),
-        to_bytes::<_, ()>(&default_strategy, &()).expect("should to bytes success"),
+        to_bytes::<_, ()>(&strategy, &()).expect("should to bytes success"),
       );
-    }
+    });
   }
 
   pub fn remove(&self, paths: impl Iterator<Item = &Path>) {
@@ -78,16 +80,26 @@ impl Snapshot {
   }
 
   pub fn calc_modified_paths(&self) -> (HashSet<ArcPath>, HashSet<ArcPath>) {
-    let mut helper = StrategyHelper::new(self.fs.clone());
+    let helper = StrategyHelper::new(self.fs.clone());
+    let changed_paths = self
+      .storage
+      .get_all(SCOPE)
+      .into_par_iter()
+      .filter_map(|(key, value)| {
+        let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
+        let strategy: Strategy =
+          from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
+        match helper.validate(&path, &strategy) {
+          ValidateResult::NoChanged => None,
+          result => Some((path, result)),
+        }
+      })
+      .collect::<Vec<_>>();
+
     let mut modified_path = HashSet::default();
     let mut deleted_path = HashSet::default();
-
-    // TODO use multi thread
-    for (key, value) in self.storage.get_all(SCOPE) {
-      let path: ArcPath = Path::new(&*String::from_utf8_lossy(&key)).into();
-      let strategy: Strategy =
-        from_bytes::<Strategy, ()>(&value, &()).expect("should from bytes success");
-      match helper.validate(&path, &strategy) {
+    for (path, result) in changed_paths {
+      match result {
         ValidateResult::Modified => {
           modified_path.insert(path);
         }
@@ -107,8 +119,8 @@ mod tests {
 
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
 
-  use super::super::MemoryStorage;
-  use super::{PathMatcher, Snapshot, SnapshotOptions};
+  use super::super::storage::MemoryStorage;
+  use super::{PathMatcher, Snapshot, SnapshotOptions, Strategy, StrategyHelper};
 
   macro_rules! p {
     ($tt:tt) => {
@@ -116,6 +128,14 @@ mod tests {
     };
   }
 
+  /// A modified time later than the compile time of the snapshot added before
+  fn later_mtime() -> u64 {
+    let Strategy::CompileTime(now) = StrategyHelper::compile_time() else {
+      unreachable!()
+    };
+    now + 1000
+  }
+
   #[tokio::test]
   async fn should_snapshot_work() {
     let fs = Arc::new(MemoryFileSystem::default());
@@ -124,6 +144,7 @@ mod tests {
       vec![PathMatcher::String("constant".into())],
       vec![PathMatcher::String("node_modules/project".into())],
       vec![PathMatcher::String("node_modules".into())],
+      vec![],
     );
 
     fs.create_dir_all("/node_modules/project".into())
@@ -164,7 +185,6 @@ mod tests {
       ]
       .into_iter(),
     );
-    std::thread::sleep(std::time::Duration::from_millis(100));
     fs.write("/file1".into(), "abcd".as_bytes()).await.unwrap();
     fs.write("/constant".into(), "abcd".as_bytes())
       .await
@@ -175,6 +195,15 @@ mod tests {
     fs.write("/node_modules/lib/file1".into(), "abcd".as_bytes())
       .await
       .unwrap();
+    let mtime = later_mtime();
+    for path in [
+      "/file1",
+      "/constant",
+      "/node_modules/project/file1",
+      "/node_modules/lib/file1",
+    ] {
+      fs.set_mtime(path.into(), mtime).unwrap();
+    }
 
     let (modified_paths, deleted_paths) = snapshot.calc_modified_paths();
     assert!(deleted_paths.is_empty());
@@ -189,6 +218,8 @@ mod tests {
     )
     .await
     .unwrap();
+    // the file is not changed after it's added again
+    fs.set_mtime("/file1".into(), 0).unwrap();
     snapshot.add([p!("/file1")].into_iter());
     let (modified_paths, deleted_paths) = snapshot.calc_modified_paths();
     assert!(deleted_paths.is_empty());
@@ -197,4 +228,58 @@ mod tests {
     assert!(modified_paths.contains(p!("/node_modules/project/file1")));
     assert!(modified_paths.contains(p!("/node_modules/lib/file1")));
   }
+
+  #[tokio::test]
+  async fn should_snapshot_content_hash_and_missing_work() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = Arc::new(MemoryStorage::default());
+    let options = SnapshotOptions::new(
+      vec![],
+      vec![],
+      vec![],
+      vec![PathMatcher::String("/hash/".into())],
+    );
+
+    fs.create_dir_all("/hash/dir".into()).await.unwrap();
+    fs.write("/hash/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/hash/dir/a".into(), "a".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/hash/dir/b".into(), "b".as_bytes())
+      .await
+      .unwrap();
+
+    let snapshot = Snapshot::new(options, fs.clone(), storage);
+    snapshot.add([p!("/hash/file1"), p!("/hash/dir"), p!("/missing")].into_iter());
+    // same content with a newer modified time
+    fs.write("/hash/file1".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/hash/dir/b".into(), "b".as_bytes())
+      .await
+      .unwrap();
+    let mtime = later_mtime();
+    fs.set_mtime("/hash/file1".into(), mtime).unwrap();
+    fs.set_mtime("/hash/dir/b".into(), mtime).unwrap();
+
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths();
+    assert!(deleted_paths.is_empty());
+    assert!(modified_paths.is_empty());
+
+    fs.write("/hash/dir/c".into(), "c".as_bytes())
+      .await
+      .unwrap();
+    fs.write("/missing".into(), "abc".as_bytes()).await.unwrap();
+    let (modified_paths, deleted_paths) = snapshot.calc_modified_paths();
+    assert!(deleted_paths.is_empty());
+    assert!(!modified_paths.contains(p!("/hash/file1")));
+    assert!(modified_paths.contains(p!("/hash/dir")));
+    assert!(modified_paths.contains(p!("/missing")));
+
+    fs.remove_file("/hash/file1".into()).await.unwrap();
+    let (_, deleted_paths) = snapshot.calc_modified_paths();
+    assert!(deleted_paths.contains(p!("/hash/file1")));
+  }
 }
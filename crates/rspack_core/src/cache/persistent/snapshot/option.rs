@@ -26,6 +26,9 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// hash_paths, snapshot will use content hash strategy instead of
+  /// compile time strategy for them
+  hash_paths: Vec<PathMatcher>,
 }
 
 impl SnapshotOptions {
@@ -33,11 +36,13 @@ impl SnapshotOptions {
     immutable_paths: Vec<PathMatcher>,
     unmanaged_paths: Vec<PathMatcher>,
     managed_paths: Vec<PathMatcher>,
+    hash_paths: Vec<PathMatcher>,
   ) -> Self {
     Self {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      hash_paths,
     }
   }
 
@@ -64,6 +69,15 @@ impl SnapshotOptions {
     }
     false
   }
+
+  pub fn is_hash_path(&self, path_str: &str) -> bool {
+    for item in &self.hash_paths {
+      if item.try_match(path_str) {
+        return true;
+      }
+    }
+    false
+  }
 }
 
 #[cfg(test)]
@@ -101,6 +115,7 @@ mod tests {
         PathMatcher::String("node_modules".into()),
         PathMatcher::Regexp(RspackRegex::new("test_modules/.+").unwrap()),
       ],
+      vec![PathMatcher::String("/src/".into())],
     );
 
     assert!(options.is_immutable_path("/root/project/constant/var.js"));
@@ -116,5 +131,8 @@ mod tests {
 
     assert!(options.is_managed_path("/root/project/test_modules/var.js"));
     assert!(!options.is_managed_path("/root/project/test_modules/test1/var.js"));
+
+    assert!(options.is_hash_path("/root/project/src/index.js"));
+    assert!(!options.is_hash_path("/root/project/lib/index.js"));
   }
 }
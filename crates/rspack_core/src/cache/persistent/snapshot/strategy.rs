@@ -1,17 +1,33 @@
 use std::{
+  hash::{BuildHasherDefault, Hasher},
   path::Path,
   sync::Arc,
   time::{SystemTime, UNIX_EPOCH},
 };
 
+use dashmap::DashMap;
 use rspack_cacheable::cacheable;
-use rspack_fs::FileSystem;
-use rspack_paths::{ArcPath, AssertUtf8};
-use rustc_hash::FxHashMap as HashMap;
+use rspack_fs::{FileMetadata, FileSystem};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_paths::{ArcPath, AssertUtf8, Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+
+/// The max number of entries to visit when walking a directory,
+/// a larger directory is compared shallowly, see [Walk::Truncated].
+const MAX_WALK_ENTRIES: usize = 10_000;
+
+/// The result of walking a directory
+enum Walk {
+  /// All the child entries are visited
+  Complete,
+  /// The directory has more than [MAX_WALK_ENTRIES] entries, so the walk is stopped
+  /// and only the directory itself and its direct child entries should be compared
+  Truncated,
+}
 
 /// Snapshot check strategy
 #[cacheable]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Strategy {
   /// Check by package version
   ///
@@ -21,8 +37,20 @@ pub enum Strategy {
 
   /// Check by compile time
   ///
-  /// This strategy will compare the compile time and the file update time
+  /// This strategy will compare the compile time and the file update time,
+  /// for a directory, the update time of all the child entries are compared.
   CompileTime(u64),
+
+  /// Check by content hash
+  ///
+  /// This strategy will compare the hash of the file content, for a directory,
+  /// the hash covers the names and contents of all the child entries.
+  ContentHash(String),
+
+  /// Check by existence
+  ///
+  /// This strategy is used for the missing path, which is modified once it's created.
+  Missing,
 }
 
 /// Validate Result
@@ -36,9 +64,10 @@ pub enum ValidateResult {
   NoChanged,
 }
 
+/// The helper to generate and validate [Strategy], which can be shared between threads.
 pub struct StrategyHelper {
   fs: Arc<dyn FileSystem>,
-  package_version_cache: HashMap<ArcPath, Option<String>>,
+  package_version_cache: DashMap<ArcPath, Option<String>, BuildHasherDefault<FxHasher>>,
 }
 
 impl StrategyHelper {
@@ -49,17 +78,114 @@ impl StrategyHelper {
     }
   }
 
-  /// get path file modified time
+  /// check whether the path exists
+  pub fn exists(&self, path: &Path) -> bool {
+    self.fs.metadata(path.assert_utf8()).is_ok()
+  }
+
+  /// get path file modified time, the latest modified time of the child entries
+  /// is used for a directory
   fn modified_time(&self, path: &Path) -> Option<u64> {
-    if let Ok(info) = self.fs.metadata(path.assert_utf8()) {
-      Some(info.mtime_ms)
+    let utf8_path = path.assert_utf8();
+    let info = self.fs.metadata(utf8_path).ok()?;
+    let mut mtime = info.mtime_ms;
+    if info.is_directory {
+      let walk = self.walk_dir(utf8_path, &mut |_, child_info| {
+        mtime = mtime.max(child_info.mtime_ms);
+        Some(())
+      })?;
+      if let Walk::Truncated = walk {
+        // the modified time of a directory changes when a direct child entry is added or removed
+        mtime = info.mtime_ms;
+      }
+    }
+    Some(mtime)
+  }
+
+  /// get path file content hash, the hash covers the names and contents of
+  /// the child entries for a directory
+  fn content_hash(&self, path: &Path) -> Option<String> {
+    let utf8_path = path.assert_utf8();
+    let info = self.fs.metadata(utf8_path).ok()?;
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    if info.is_directory {
+      let walk = self.walk_dir(utf8_path, &mut |child, child_info| {
+        hasher.write(child.as_str().as_bytes());
+        if !child_info.is_directory {
+          hasher.write(&self.fs.read(child).ok()?);
+        }
+        Some(())
+      })?;
+      if let Walk::Truncated = walk {
+        // only hash the names of the direct child entries
+        hasher = RspackHash::new(&HashFunction::Xxhash64);
+        let mut children = self.fs.read_dir_sync(utf8_path).ok()?;
+        children.sort_unstable();
+        for name in children {
+          hasher.write(name.as_bytes());
+        }
+      }
     } else {
-      None
+      hasher.write(&self.fs.read(utf8_path).ok()?);
     }
+    Some(hasher.digest(&HashDigest::Hex).encoded().to_string())
+  }
+
+  /// Visit the child entries of a directory recursively in a stable order.
+  ///
+  /// Symlinks are followed, and the directories already visited by their canonical
+  /// paths are skipped, so a symlink cycle will not be walked forever.
+  /// Return `None` when a child entry can't be read, and [Walk::Truncated] when the
+  /// directory has more than [MAX_WALK_ENTRIES] entries.
+  fn walk_dir(
+    &self,
+    dir: &Utf8Path,
+    f: &mut dyn FnMut(&Utf8Path, &FileMetadata) -> Option<()>,
+  ) -> Option<Walk> {
+    let mut visited = HashSet::default();
+    let mut budget = MAX_WALK_ENTRIES;
+    self.walk_dir_impl(dir, &mut visited, &mut budget, f)
+  }
+
+  fn walk_dir_impl(
+    &self,
+    dir: &Utf8Path,
+    visited: &mut HashSet<Utf8PathBuf>,
+    budget: &mut usize,
+    f: &mut dyn FnMut(&Utf8Path, &FileMetadata) -> Option<()>,
+  ) -> Option<Walk> {
+    let canonical = self
+      .fs
+      .canonicalize(dir)
+      .unwrap_or_else(|_| dir.to_path_buf());
+    if !visited.insert(canonical) {
+      return Some(Walk::Complete);
+    }
+    let mut children = self.fs.read_dir_sync(dir).ok()?;
+    children.sort_unstable();
+    for name in children {
+      let Some(rest) = budget.checked_sub(1) else {
+        return Some(Walk::Truncated);
+      };
+      *budget = rest;
+      let child = dir.join(name);
+      let mut info = self.fs.symlink_metadata(&child).ok()?;
+      if info.is_symlink {
+        // keep the info of the link itself for a dangling symlink
+        info = self.fs.metadata(&child).unwrap_or(info);
+      }
+      f(&child, &info)?;
+      if info.is_directory
+        && let Walk::Truncated = self.walk_dir_impl(&child, visited, budget, f)?
+      {
+        return Some(Walk::Truncated);
+      }
+    }
+    Some(Walk::Complete)
   }
 
   /// get path file version in package.json
-  fn package_version_with_cache(&mut self, path: &Path) -> Option<String> {
+  fn package_version_with_cache(&self, path: &Path) -> Option<String> {
     if let Some(version) = self.package_version_cache.get(path) {
       return version.clone();
     }
@@ -94,14 +220,29 @@ impl StrategyHelper {
     Strategy::CompileTime(now)
   }
   /// get path file package version strategy
-  pub fn package_version(&mut self, path: &Path) -> Option<Strategy> {
+  pub fn package_version(&self, path: &Path) -> Option<Strategy> {
     self
       .package_version_with_cache(path)
       .map(Strategy::PackageVersion)
   }
 
+  /// get path file content hash strategy
+  pub fn content_hash_strategy(&self, path: &Path) -> Option<Strategy> {
+    self.content_hash(path).map(Strategy::ContentHash)
+  }
+
+  /// the result when the path can't be checked, the path which still exists may
+  /// have an unreadable child entry
+  fn modified_or_deleted(&self, path: &Path) -> ValidateResult {
+    if self.exists(path) {
+      ValidateResult::Modified
+    } else {
+      ValidateResult::Deleted
+    }
+  }
+
   /// validate path file by target strategy
-  pub fn validate(&mut self, path: &Path, strategy: &Strategy) -> ValidateResult {
+  pub fn validate(&self, path: &Path, strategy: &Strategy) -> ValidateResult {
     match strategy {
       Strategy::PackageVersion(version) => {
         if let Some(ref cur_version) = self.package_version_with_cache(path) {
@@ -122,7 +263,25 @@ impl StrategyHelper {
             ValidateResult::NoChanged
           }
         } else {
-          ValidateResult::Deleted
+          self.modified_or_deleted(path)
+        }
+      }
+      Strategy::ContentHash(hash) => {
+        if let Some(ref cur_hash) = self.content_hash(path) {
+          if cur_hash == hash {
+            ValidateResult::NoChanged
+          } else {
+            ValidateResult::Modified
+          }
+        } else {
+          self.modified_or_deleted(path)
+        }
+      }
+      Strategy::Missing => {
+        if self.exists(path) {
+          ValidateResult::Modified
+        } else {
+          ValidateResult::NoChanged
         }
       }
     }
@@ -133,9 +292,9 @@ impl StrategyHelper {
 mod tests {
   use std::{path::Path, sync::Arc};
 
-  use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem, ReadableFileSystem, WritableFileSystem};
 
-  use super::{Strategy, StrategyHelper, ValidateResult};
+  use super::{Strategy, StrategyHelper, ValidateResult, MAX_WALK_ENTRIES};
 
   #[tokio::test]
   async fn should_strategy_works() {
@@ -166,7 +325,7 @@ mod tests {
     };
     assert!(time1 < time2);
 
-    let mut helper = StrategyHelper::new(fs.clone());
+    let helper = StrategyHelper::new(fs.clone());
     // modified_time
     assert_eq!(
       helper.modified_time(Path::new("/file1")),
@@ -228,8 +387,11 @@ mod tests {
       helper.validate(Path::new("/file1"), &now),
       ValidateResult::NoChanged
     ));
-    std::thread::sleep(std::time::Duration::from_millis(100));
     fs.write("/file1".into(), "abcd".as_bytes()).await.unwrap();
+    let Strategy::CompileTime(compile_time) = now else {
+      unreachable!()
+    };
+    fs.set_mtime("/file1".into(), compile_time + 1).unwrap();
     assert!(matches!(
       helper.validate(Path::new("/file1"), &now),
       ValidateResult::Modified
@@ -253,4 +415,75 @@ mod tests {
       ValidateResult::Deleted
     ));
   }
+
+  #[tokio::test]
+  async fn should_compare_large_directory_shallowly() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/large/sub".into()).await.unwrap();
+    for i in 0..=MAX_WALK_ENTRIES {
+      fs.write(format!("/large/sub/{i}").as_str().into(), b"a")
+        .await
+        .unwrap();
+    }
+    fs.set_mtime("/large".into(), 1000).unwrap();
+    let helper = StrategyHelper::new(fs.clone());
+    assert_eq!(helper.modified_time(Path::new("/large")), Some(1000));
+
+    // an unchanged large directory is not modified
+    let time = Strategy::CompileTime(1000);
+    let hash = helper.content_hash_strategy(Path::new("/large")).unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/large"), &time),
+      ValidateResult::NoChanged
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/large"), &hash),
+      ValidateResult::NoChanged
+    ));
+
+    // the entries deeper than the direct children are not compared
+    fs.write("/large/sub/0".into(), b"b").await.unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/large"), &hash),
+      ValidateResult::NoChanged
+    ));
+
+    // a direct child entry is added
+    fs.write("/large/file".into(), b"a").await.unwrap();
+    fs.set_mtime("/large".into(), 2000).unwrap();
+    assert!(matches!(
+      helper.validate(Path::new("/large"), &time),
+      ValidateResult::Modified
+    ));
+    assert!(matches!(
+      helper.validate(Path::new("/large"), &hash),
+      ValidateResult::Modified
+    ));
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn should_not_walk_symlink_cycle() {
+    let dir = std::env::temp_dir().join(format!("rspack_snapshot_strategy_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("sub")).unwrap();
+    std::fs::write(dir.join("sub/file"), "abc").unwrap();
+    std::os::unix::fs::symlink(&dir, dir.join("sub/loop")).unwrap();
+
+    let helper = StrategyHelper::new(Arc::new(NativeFileSystem));
+    assert!(helper.modified_time(&dir).is_some());
+    let hash = helper.content_hash_strategy(&dir).unwrap();
+    assert!(matches!(
+      helper.validate(&dir, &hash),
+      ValidateResult::NoChanged
+    ));
+
+    std::fs::write(dir.join("sub/file"), "abcd").unwrap();
+    assert!(matches!(
+      helper.validate(&dir, &hash),
+      ValidateResult::Modified
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
 }
@@ -0,0 +1,471 @@
+use std::{
+  hash::Hasher,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+};
+
+use rspack_error::Result;
+use rspack_fs::FileSystem;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use super::Storage;
+
+/// Bump it when the layout of meta or pack files changes.
+const STORAGE_FORMAT: &str = "1";
+const VERSION_DIR_PREFIX: &str = "pack-";
+const META_FILE: &str = "meta";
+const PACK_EXTENSION: &str = ".pack";
+const TMP_EXTENSION: &str = ".tmp";
+/// Each scope is split into a fixed number of packs by key hash,
+/// so that a small change only rewrites a small pack.
+const PACK_COUNT: usize = 16;
+
+type Entries = HashMap<Vec<u8>, Vec<u8>>;
+
+#[derive(Debug, Default)]
+struct Pack {
+  /// file name of the pack on disk, `None` means the pack is not written yet
+  name: Option<String>,
+  entries: Entries,
+  dirty: bool,
+}
+
+#[derive(Debug)]
+struct ScopeState {
+  packs: Vec<Pack>,
+}
+
+impl ScopeState {
+  fn new() -> Self {
+    Self {
+      packs: (0..PACK_COUNT).map(|_| Pack::default()).collect(),
+    }
+  }
+
+  fn is_dirty(&self) -> bool {
+    self.packs.iter().any(|pack| pack.dirty)
+  }
+}
+
+/// A pack which should be flushed to disk
+#[derive(Debug)]
+struct PackWriteTask {
+  name: String,
+  content: Vec<u8>,
+}
+
+/// All of the file operations needed to persist a scope
+#[derive(Debug)]
+struct ScopeWriteTask {
+  scope: String,
+  packs: Vec<PackWriteTask>,
+  meta: Vec<u8>,
+  referenced: HashSet<String>,
+}
+
+/// FileSystem Storage
+///
+/// Entries are kept in memory and flushed to `<directory>/pack-<version>/<scope>` when idle.
+///
+/// Each scope contains several packs and a meta file. Packs are named by their content hash
+/// and never overwritten, the meta file is written after all of the packs and carries a checksum.
+/// Every file is written to a `.tmp` file first and renamed to its name, so an interrupted write
+/// leaves the previous meta and packs untouched. Leftover `.tmp` files, packs not referenced by
+/// the meta and directories of other versions are removed.
+#[derive(Debug)]
+pub struct FileSystemStorage {
+  directory: Utf8PathBuf,
+  version: String,
+  fs: Arc<dyn FileSystem>,
+  scopes: Mutex<HashMap<String, ScopeState>>,
+  cleaned: AtomicBool,
+}
+
+impl FileSystemStorage {
+  pub fn new(directory: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self {
+      directory,
+      version: format!("{}-{}", STORAGE_FORMAT, env!("CARGO_PKG_VERSION")),
+      fs,
+      scopes: Default::default(),
+      cleaned: AtomicBool::new(false),
+    }
+  }
+
+  fn root(&self) -> Utf8PathBuf {
+    self
+      .directory
+      .join(format!("{VERSION_DIR_PREFIX}{}", self.version))
+  }
+
+  fn load_scope(&self, scope: &str) -> ScopeState {
+    let mut state = ScopeState::new();
+    let scope_dir = self.root().join(scope);
+    let Ok(meta) = self.fs.read(&scope_dir.join(META_FILE)) else {
+      return state;
+    };
+    let Some(packs) = decode_meta(&meta, &self.version) else {
+      tracing::warn!("invalid persistent cache meta in {scope_dir}, ignored");
+      return state;
+    };
+    for (index, name) in packs {
+      let Ok(content) = self.fs.read(&scope_dir.join(&name)) else {
+        continue;
+      };
+      if let Some(entries) = decode_pack(&content, &name) {
+        state.packs[index] = Pack {
+          name: Some(name),
+          entries,
+          dirty: false,
+        };
+      } else {
+        tracing::warn!("invalid persistent cache pack {name} in {scope_dir}, ignored");
+      }
+    }
+    state
+  }
+
+  fn with_scope<T>(&self, scope: &str, f: impl FnOnce(&mut ScopeState) -> T) -> T {
+    let mut scopes = self.scopes.lock().expect("should get lock");
+    if !scopes.contains_key(scope) {
+      let state = self.load_scope(scope);
+      scopes.insert(scope.to_string(), state);
+    }
+    f(scopes.get_mut(scope).expect("should have scope"))
+  }
+
+  fn collect_write_tasks(&self) -> Vec<ScopeWriteTask> {
+    let mut scopes = self.scopes.lock().expect("should get lock");
+    scopes
+      .iter_mut()
+      .filter(|(_, state)| state.is_dirty())
+      .map(|(scope, state)| {
+        let mut packs = vec![];
+        for (index, pack) in state.packs.iter_mut().enumerate() {
+          if !pack.dirty {
+            continue;
+          }
+          pack.dirty = false;
+          if pack.entries.is_empty() {
+            pack.name = None;
+            continue;
+          }
+          let content = encode_pack(&pack.entries);
+          let name = pack_name(index, &content);
+          if pack.name.as_ref() != Some(&name) {
+            pack.name = Some(name.clone());
+            packs.push(PackWriteTask { name, content });
+          }
+        }
+        let referenced: Vec<(usize, String)> = state
+          .packs
+          .iter()
+          .enumerate()
+          .filter_map(|(index, pack)| pack.name.clone().map(|name| (index, name)))
+          .collect();
+        ScopeWriteTask {
+          scope: scope.clone(),
+          packs,
+          meta: encode_meta(&referenced, &self.version),
+          referenced: referenced.into_iter().map(|(_, name)| name).collect(),
+        }
+      })
+      .collect()
+  }
+
+  async fn write_scope(&self, task: ScopeWriteTask) -> Result<()> {
+    let scope_dir = self.root().join(&task.scope);
+    self.fs.create_dir_all(&scope_dir).await?;
+    for pack in task.packs {
+      self
+        .write_atomically(&scope_dir.join(&pack.name), &pack.content)
+        .await?;
+    }
+    self
+      .write_atomically(&scope_dir.join(META_FILE), &task.meta)
+      .await?;
+
+    // remove the packs replaced by this write and the files left by an interrupted one
+    for name in self.fs.read_dir(&scope_dir).await? {
+      if name.ends_with(TMP_EXTENSION)
+        || (name.ends_with(PACK_EXTENSION) && !task.referenced.contains(&name))
+      {
+        self.fs.remove_file(&scope_dir.join(&name)).await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Write to a temporary file and rename it to the target,
+  /// so that a crash in the middle of writing never leaves a truncated target.
+  async fn write_atomically(&self, path: &Utf8Path, content: &[u8]) -> Result<()> {
+    let tmp_path = Utf8PathBuf::from(format!("{path}{TMP_EXTENSION}"));
+    self.fs.write(&tmp_path, content).await?;
+    self.fs.rename(&tmp_path, path).await?;
+    Ok(())
+  }
+
+  /// Remove the cache directories created by other versions of storage
+  async fn clean_stale_versions(&self) -> Result<()> {
+    if self.cleaned.swap(true, Ordering::Relaxed) {
+      return Ok(());
+    }
+    let Ok(names) = self.fs.read_dir(&self.directory).await else {
+      // nothing has been written yet
+      return Ok(());
+    };
+    let current = format!("{VERSION_DIR_PREFIX}{}", self.version);
+    for name in names {
+      if name.starts_with(VERSION_DIR_PREFIX) && name != current {
+        let path = self.directory.join(&name);
+        if self.fs.stat(&path).await?.is_directory {
+          self.fs.remove_dir_all(&path).await?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait]
+impl Storage for FileSystemStorage {
+  fn get_all(&self, scope: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
+    self.with_scope(scope, |state| {
+      state
+        .packs
+        .iter()
+        .flat_map(|pack| pack.entries.iter())
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+    })
+  }
+  fn set(&self, scope: &str, key: Vec<u8>, value: Vec<u8>) {
+    self.with_scope(scope, |state| {
+      let pack = &mut state.packs[pack_index(&key)];
+      if pack.entries.get(&key) != Some(&value) {
+        pack.entries.insert(key, value);
+        pack.dirty = true;
+      }
+    })
+  }
+  fn remove(&self, scope: &str, key: &[u8]) {
+    self.with_scope(scope, |state| {
+      let pack = &mut state.packs[pack_index(key)];
+      if pack.entries.remove(key).is_some() {
+        pack.dirty = true;
+      }
+    })
+  }
+  async fn idle(&self) -> Result<()> {
+    for task in self.collect_write_tasks() {
+      self.write_scope(task).await?;
+    }
+    self.clean_stale_versions().await
+  }
+}
+
+fn hash_bytes(data: &[u8]) -> RspackHash {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(data);
+  hasher
+}
+
+fn pack_index(key: &[u8]) -> usize {
+  (hash_bytes(key).finish() % PACK_COUNT as u64) as usize
+}
+
+fn pack_name(index: usize, content: &[u8]) -> String {
+  format!(
+    "{index}_{}{PACK_EXTENSION}",
+    hash_bytes(content).digest(&HashDigest::Hex).encoded()
+  )
+}
+
+/// Pack layout: repeated `[key_len: u32 le][key][value_len: u32 le][value]`,
+/// entries are sorted by key to make the content hash stable.
+fn encode_pack(entries: &Entries) -> Vec<u8> {
+  let mut sorted: Vec<_> = entries.iter().collect();
+  sorted.sort_unstable_by(|a, b| a.0.cmp(b.0));
+  let mut buf = vec![];
+  for (key, value) in sorted {
+    buf.extend((key.len() as u32).to_le_bytes());
+    buf.extend(key);
+    buf.extend((value.len() as u32).to_le_bytes());
+    buf.extend(value);
+  }
+  buf
+}
+
+fn decode_pack(content: &[u8], name: &str) -> Option<Entries> {
+  let index = name.split('_').next()?;
+  if pack_name(index.parse().ok()?, content) != name {
+    return None;
+  }
+
+  fn read_chunk<'a>(content: &'a [u8], offset: &mut usize) -> Option<&'a [u8]> {
+    let len_end = offset.checked_add(4)?;
+    let len = u32::from_le_bytes(content.get(*offset..len_end)?.try_into().ok()?) as usize;
+    let end = len_end.checked_add(len)?;
+    let chunk = content.get(len_end..end)?;
+    *offset = end;
+    Some(chunk)
+  }
+
+  let mut entries = Entries::default();
+  let mut offset = 0;
+  while offset < content.len() {
+    let key = read_chunk(content, &mut offset)?;
+    let value = read_chunk(content, &mut offset)?;
+    entries.insert(key.to_vec(), value.to_vec());
+  }
+  Some(entries)
+}
+
+/// Meta layout:
+/// ```text
+/// <version>
+/// <pack index> <pack name>
+/// ...
+/// <checksum of the lines above>
+/// ```
+fn encode_meta(packs: &[(usize, String)], version: &str) -> Vec<u8> {
+  let mut content = format!("{version}\n");
+  for (index, name) in packs {
+    content.push_str(&format!("{index} {name}\n"));
+  }
+  let checksum = hash_bytes(content.as_bytes()).digest(&HashDigest::Hex);
+  content.push_str(checksum.encoded());
+  content.into_bytes()
+}
+
+fn decode_meta(content: &[u8], version: &str) -> Option<Vec<(usize, String)>> {
+  let content = std::str::from_utf8(content).ok()?;
+  let (body, checksum) = content.rsplit_once('\n')?;
+  let body = format!("{body}\n");
+  if hash_bytes(body.as_bytes())
+    .digest(&HashDigest::Hex)
+    .encoded()
+    != checksum
+  {
+    return None;
+  }
+  let mut lines = body.lines();
+  if lines.next()? != version {
+    return None;
+  }
+  lines
+    .map(|line| {
+      let (index, name) = line.split_once(' ')?;
+      let index: usize = index.parse().ok()?;
+      (index < PACK_COUNT).then(|| (index, name.to_string()))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+  use rspack_paths::Utf8Path;
+
+  use super::FileSystemStorage;
+  use crate::cache::persistent::storage::Storage;
+
+  #[tokio::test]
+  async fn should_filesystem_storage_works() {
+    let scope = "test";
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all(Utf8Path::new("/cache/pack-0-0.0.0"))
+      .await
+      .unwrap();
+
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    storage.set(scope, "a".as_bytes().to_vec(), "abc".as_bytes().to_vec());
+    storage.set(scope, "b".as_bytes().to_vec(), "bcd".as_bytes().to_vec());
+    storage.idle().await.unwrap();
+    assert!(fs.read(&storage.root().join(scope).join("meta")).is_ok());
+    // directories of other versions are removed
+    assert!(fs.metadata(Utf8Path::new("/cache/pack-0-0.0.0")).is_err());
+
+    // restore from disk
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    let arr = storage.get_all(scope);
+    assert_eq!(arr.len(), 2);
+    for (key, value) in arr {
+      if key == "a".as_bytes() {
+        assert_eq!(&value, "abc".as_bytes());
+      } else {
+        assert_eq!(&value, "bcd".as_bytes());
+      }
+    }
+
+    storage.remove(scope, "b".as_bytes());
+    storage.idle().await.unwrap();
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    let arr = storage.get_all(scope);
+    assert_eq!(arr.len(), 1);
+    let packs = fs
+      .read_dir(&storage.root().join(scope))
+      .await
+      .unwrap()
+      .into_iter()
+      .filter(|name| name.ends_with(".pack"))
+      .count();
+    assert_eq!(packs, 1);
+  }
+
+  #[tokio::test]
+  async fn should_ignore_broken_meta() {
+    let scope = "test";
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    storage.set(scope, "a".as_bytes().to_vec(), "abc".as_bytes().to_vec());
+    storage.idle().await.unwrap();
+
+    let meta_path = storage.root().join(scope).join("meta");
+    let meta = fs.read(&meta_path).unwrap();
+    fs.write(&meta_path, &meta[..meta.len() - 1]).await.unwrap();
+
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    assert!(storage.get_all(scope).is_empty());
+  }
+
+  #[tokio::test]
+  async fn should_ignore_leftover_tmp_files() {
+    let scope = "test";
+    let fs = Arc::new(MemoryFileSystem::default());
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    storage.set(scope, "a".as_bytes().to_vec(), "abc".as_bytes().to_vec());
+    storage.idle().await.unwrap();
+
+    // simulate a write interrupted before the temporary files are renamed
+    let scope_dir = storage.root().join(scope);
+    let meta = fs.read(&scope_dir.join("meta")).unwrap();
+    fs.write(&scope_dir.join("meta.tmp"), &meta[..meta.len() / 2])
+      .await
+      .unwrap();
+    fs.write(&scope_dir.join("0_0000000000000000.pack.tmp"), b"broken")
+      .await
+      .unwrap();
+
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    assert_eq!(
+      storage.get_all(scope),
+      vec![("a".as_bytes().to_vec(), "abc".as_bytes().to_vec())]
+    );
+
+    // the leftover files are removed by the next write
+    storage.set(scope, "b".as_bytes().to_vec(), "bcd".as_bytes().to_vec());
+    storage.idle().await.unwrap();
+    let names = fs.read_dir(&scope_dir).await.unwrap();
+    assert!(names.iter().all(|name| !name.ends_with(".tmp")));
+
+    let storage = FileSystemStorage::new("/cache".into(), fs.clone());
+    assert_eq!(storage.get_all(scope).len(), 2);
+  }
+}
@@ -1,5 +1,6 @@
 use std::sync::Mutex;
 
+use rspack_error::Result;
 use rustc_hash::FxHashMap as HashMap;
 
 use super::Storage;
@@ -14,6 +15,7 @@ pub struct MemoryStorage {
   inner: Mutex<HashMap<String, HashMap<Vec<u8>, Vec<u8>>>>,
 }
 
+#[async_trait::async_trait]
 impl Storage for MemoryStorage {
   fn get_all(&self, scope: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
     if let Some(value) = self.inner.lock().expect("should get lock").get(scope) {
@@ -31,7 +33,9 @@ impl Storage for MemoryStorage {
     let mut map = self.inner.lock().expect("should get lock");
     map.get_mut(scope).map(|map| map.remove(key));
   }
-  fn idle(&self) {}
+  async fn idle(&self) -> Result<()> {
+    Ok(())
+  }
 }
 
 #[cfg(test)]
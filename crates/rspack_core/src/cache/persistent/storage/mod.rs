@@ -1,7 +1,14 @@
+mod filesystem;
 // TODO add #[cfg(test)]
 mod memory;
 
+use std::sync::Arc;
+
+pub use filesystem::FileSystemStorage;
 pub use memory::MemoryStorage;
+use rspack_error::Result;
+use rspack_fs::FileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
 
 /// Storage Options
 ///
@@ -9,15 +16,30 @@ pub use memory::MemoryStorage;
 /// Since MemoryStorage is only used in unit test, there is no need to add it here.
 #[derive(Debug, Clone)]
 pub enum StorageOptions {
-  // TODO change to FileSystem(configuration)
-  FileSystem,
+  /// Persist to disk, a relative directory is resolved from the compiler context
+  FileSystem { directory: Utf8PathBuf },
 }
 
 // TODO: add batch set/remove
+#[async_trait::async_trait]
 pub trait Storage: std::fmt::Debug + Sync + Send {
   fn get_all(&self, scope: &str) -> Vec<(Vec<u8>, Vec<u8>)>;
   // using immutable reference to support concurrency
   fn set(&self, scope: &str, key: Vec<u8>, value: Vec<u8>);
   fn remove(&self, scope: &str, key: &[u8]);
-  fn idle(&self);
+  /// Persist the changes made since last idle
+  async fn idle(&self) -> Result<()>;
+}
+
+pub fn new_storage(
+  options: &[StorageOptions],
+  context: &Utf8Path,
+  fs: Arc<dyn FileSystem>,
+) -> Arc<dyn Storage> {
+  match options.first() {
+    Some(StorageOptions::FileSystem { directory }) => {
+      Arc::new(FileSystemStorage::new(context.join(directory), fs))
+    }
+    None => Arc::new(MemoryStorage::default()),
+  }
 }
@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 use std::fmt::{self, Display};
 
 use itertools::Itertools;
+use rspack_cacheable::{cacheable, with::Unsupported};
 use rspack_collections::IdentifierMap;
 use rspack_collections::{DatabaseItem, UkeySet};
 use rspack_error::{error, Result};
@@ -500,6 +501,7 @@ impl Display for ChunkGroupOrderKey {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
@@ -528,9 +530,10 @@ impl ChunkGroupOptions {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum GroupOptions {
-  Entrypoint(Box<EntryOptions>),
+  Entrypoint(#[cacheable(with=Unsupported)] Box<EntryOptions>),
   ChunkGroup(ChunkGroupOptions),
 }
 
@@ -320,6 +320,20 @@ impl CodeGenerationResults {
     code_generation_result.hash.as_ref()
   }
 
+  /// Like [Self::get_hash], but returns `None` if there is no result for the runtime.
+  pub fn get_hash_by_runtime(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    runtime: &RuntimeSpec,
+  ) -> Option<&RspackHashDigest> {
+    self
+      .map
+      .get(module_identifier)?
+      .get(runtime)
+      .and_then(|result_id| self.module_generation_result_map.get(result_id))
+      .and_then(|result| result.hash.as_ref())
+  }
+
   pub fn into_inner(
     self,
   ) -> (
@@ -42,10 +42,10 @@ use crate::{
   ChunkContentHash, ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashesResult, ChunkKind,
   ChunkRenderResult, ChunkUkey, CodeGenerationJob, CodeGenerationResult, CodeGenerationResults,
   CompilationLogger, CompilationLogging, CompilerOptions, DependencyId, DependencyType, Entry,
-  EntryData, EntryOptions, EntryRuntime, Entrypoint, ExecuteModuleId, Filename, ImportVarMap,
-  LocalFilenameFn, Logger, ModuleFactory, ModuleGraph, ModuleGraphPartial, ModuleIdentifier,
-  PathData, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpecMap, SharedPluginDriver,
-  SourceType, Stats,
+  EntryData, EntryOptions, EntryRuntime, Entrypoint, ExecuteModuleId, Filename, IdRecords,
+  ImportVarMap, LocalFilenameFn, Logger, ModuleFactory, ModuleGraph, ModuleGraphPartial,
+  ModuleIdentifier, PathData, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpecMap,
+  SharedPluginDriver, SourceType, Stats,
 };
 
 pub type BuildDependency = (
@@ -147,6 +147,9 @@ pub struct Compilation {
   // The status is different, should generate different hash for `.hot-update.js`
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
+  /// Whether the hot update is generated, which is enabled by the `HotModuleReplacementPlugin`.
+  /// The records of the compilation are only collected for the next rebuild if it's enabled.
+  pub hot_module_replacement: bool,
   pub records: Option<CompilationRecords>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
@@ -201,6 +204,8 @@ pub struct Compilation {
 
   pub hash: Option<RspackHashDigest>,
   pub used_chunk_ids: HashSet<String>,
+  pub used_module_ids: HashSet<String>,
+  pub id_records: IdRecords,
 
   pub file_dependencies: IndexSet<ArcPath, BuildHasherDefault<FxHasher>>,
   pub context_dependencies: IndexSet<ArcPath, BuildHasherDefault<FxHasher>>,
@@ -259,6 +264,7 @@ impl Compilation {
     Self {
       id: CompilationId::new(),
       hot_index: 0,
+      hot_module_replacement: false,
       records,
       options,
       other_module_graph: None,
@@ -302,6 +308,8 @@ impl Compilation {
       code_splitting_cache: Default::default(),
       hash: None,
       used_chunk_ids: Default::default(),
+      used_module_ids: Default::default(),
+      id_records: Default::default(),
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -656,7 +664,9 @@ impl Compilation {
   }
 
   pub fn push_diagnostic(&mut self, diagnostic: Diagnostic) {
-    self.diagnostics.push(diagnostic);
+    if let Some(diagnostic) = self.options.diagnostics.apply(diagnostic) {
+      self.diagnostics.push(diagnostic);
+    }
   }
   pub fn splice_diagnostic(
     &mut self,
@@ -668,7 +678,10 @@ impl Compilation {
   }
 
   pub fn extend_diagnostics(&mut self, diagnostics: impl IntoIterator<Item = Diagnostic>) {
-    self.diagnostics.extend(diagnostics);
+    let options = &self.options.diagnostics;
+    self
+      .diagnostics
+      .extend(diagnostics.into_iter().filter_map(|d| options.apply(d)));
   }
 
   pub fn get_errors(&self) -> impl Iterator<Item = &Diagnostic> {
@@ -727,6 +740,30 @@ impl Compilation {
     )
   }
 
+  /// Get the diagnostics with the `advice` severity, which are neither errors nor warnings.
+  pub fn get_advices(&self) -> impl Iterator<Item = &Diagnostic> {
+    self
+      .diagnostics
+      .iter()
+      .filter(|d| matches!(d.severity(), Severity::Advice))
+  }
+
+  /// Get sorted advices, see [Compilation::get_warnings_sorted] for the order.
+  pub fn get_advices_sorted(&self) -> impl Iterator<Item = &Diagnostic> {
+    let get_offset = |d: &dyn rspack_error::miette::Diagnostic| {
+      d.labels()
+        .and_then(|mut l| l.next())
+        .map(|l| l.offset())
+        .unwrap_or_default()
+    };
+    self.get_advices().sorted_by(
+      |a, b| match a.module_identifier().cmp(&b.module_identifier()) {
+        std::cmp::Ordering::Equal => get_offset(a.as_ref()).cmp(&get_offset(b.as_ref())),
+        other => other,
+      },
+    )
+  }
+
   pub fn get_logging(&self) -> &CompilationLogging {
     &self.logging
   }
@@ -773,6 +810,7 @@ impl Compilation {
 
     let artifact = std::mem::take(&mut self.make_artifact);
     self.make_artifact = make_module_graph(self, artifact).await?;
+    self.cache.after_make(&self.make_artifact).await?;
     Ok(())
   }
 
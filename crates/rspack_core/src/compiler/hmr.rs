@@ -1,12 +1,12 @@
 use std::path::Path;
 
-use rayon::iter::{ParallelBridge, ParallelIterator};
-use rspack_collections::{Identifier, IdentifierMap};
+use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+use rspack_collections::{DatabaseItem, Identifier, IdentifierMap};
 use rspack_error::Result;
 use rspack_hash::RspackHashDigest;
 use rspack_paths::ArcPath;
 use rspack_sources::Source;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
   chunk_graph_module::ModuleId, fast_set, incremental::IncrementalPasses, ChunkGraph, ChunkKind,
@@ -19,32 +19,14 @@ impl Compiler {
     changed_files: std::collections::HashSet<String>,
     deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
-    let old = self.compilation.get_stats();
-    let old_hash = self.compilation.hash.clone();
-
-    let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation)?;
-    // TODO: should use `records`
-
-    let all_old_runtime = old
-      .compilation
-      .get_chunk_graph_entries()
-      .filter_map(|entry_ukey| old.compilation.chunk_by_ukey.get(&entry_ukey))
-      .flat_map(|entry_chunk| entry_chunk.runtime().clone())
-      .collect();
-
-    let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
-    for (_, chunk) in old.compilation.chunk_by_ukey.iter() {
-      if chunk.kind() != ChunkKind::HotUpdate {
-        old_chunks.push((chunk.expect_id().to_string(), chunk.runtime().clone()));
+    // the records are persisted by the hmr plugin since the first rebuild,
+    // collect them from the previous compilation if they are not
+    let records = match self.compilation.records.take() {
+      Some(records) => Some(records),
+      None if self.compilation.hot_module_replacement => {
+        Some(CompilationRecords::record(&self.compilation)?)
       }
-    }
-
-    let records = CompilationRecords {
-      old_chunks,
-      all_old_runtime,
-      old_all_modules,
-      old_runtime_modules,
-      old_hash,
+      None => None,
     };
 
     // build without stats
@@ -70,7 +52,7 @@ impl Compiler {
         self.buildtime_plugin_driver.clone(),
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
-        Some(records),
+        records,
         self.cache.clone(),
         self.old_cache.clone(),
         Some(ModuleExecutor::default()),
@@ -169,66 +151,112 @@ impl Compiler {
       // Update `compilation` for each rebuild.
       // Make sure `thisCompilation` hook was called before any other hooks that leverage `JsCompilation`.
       fast_set(&mut self.compilation, new_compilation);
-      self.cache.before_compile(&mut self.compilation);
+      self.cache.before_compile(&mut self.compilation).await?;
       self.compile().await?;
 
       self.old_cache.begin_idle();
     }
 
     self.compile_done().await?;
-    self.cache.after_compile(&self.compilation);
+    self.cache.after_compile(&self.compilation).await?;
 
     Ok(())
   }
 }
 
-#[derive(Debug)]
+/// The records of a compilation, which are compared with the next compilation
+/// to generate the hot update.
+#[derive(Debug, Default)]
 pub struct CompilationRecords {
-  pub old_chunks: Vec<(String, RuntimeSpec)>,
-  pub all_old_runtime: RuntimeSpec,
-  pub old_all_modules: IdentifierMap<(RspackHashDigest, ModuleId)>,
-  pub old_runtime_modules: IdentifierMap<String>,
-  pub old_hash: Option<RspackHashDigest>,
+  /// The id and the runtime of each chunk.
+  pub chunks: Vec<(String, RuntimeSpec)>,
+  pub runtimes: RuntimeSpec,
+  /// The id of each code generated module.
+  pub modules: IdentifierMap<ModuleId>,
+  /// The hashes of the modules in each chunk, keyed by the chunk id.
+  ///
+  /// A module shared by chunks of different runtimes may be generated differently
+  /// for each runtime, so the hash is the one generated for the runtime of the chunk.
+  pub chunk_module_hashes: HashMap<String, IdentifierMap<RspackHashDigest>>,
+  pub runtime_modules: IdentifierMap<String>,
+  pub hash: Option<RspackHashDigest>,
 }
 
-pub type ChangedModules = (
-  IdentifierMap<(RspackHashDigest, ModuleId)>,
-  IdentifierMap<String>,
-);
-pub fn collect_changed_modules(compilation: &Compilation) -> Result<ChangedModules> {
-  let modules_map = compilation
-    .chunk_graph
-    .chunk_graph_module_by_module_identifier
-    .keys()
-    .par_bridge()
-    .filter_map(|identifier| {
-      let cid = ChunkGraph::get_module_id(&compilation.module_ids, *identifier);
-      // TODO: Determine how to calc module hash if module related to multiple runtime code
-      // gen
-      if let Some(code_generation_result) = compilation.code_generation_results.get_one(identifier)
-        && let Some(module_hash) = &code_generation_result.hash
-        && let Some(cid) = cid
-      {
-        Some((*identifier, (module_hash.clone(), cid.clone())))
-      } else {
-        None
-      }
-    })
-    .collect::<IdentifierMap<_>>();
-
-  let old_runtime_modules = compilation
-    .runtime_modules
-    .iter()
-    .map(|(identifier, module)| -> Result<(Identifier, String)> {
-      Ok((
-        *identifier,
-        module
-          .generate_with_custom(compilation)?
-          .source()
-          .to_string(),
-      ))
-    })
-    .collect::<Result<IdentifierMap<String>>>()?;
+impl CompilationRecords {
+  pub fn record(compilation: &Compilation) -> Result<Self> {
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.kind() != ChunkKind::HotUpdate)
+      .filter_map(|chunk| Some((chunk.id()?, chunk)))
+      .collect::<Vec<_>>();
+
+    let runtimes = compilation
+      .get_chunk_graph_entries()
+      .filter_map(|entry_ukey| compilation.chunk_by_ukey.get(&entry_ukey))
+      .flat_map(|entry_chunk| entry_chunk.runtime().clone())
+      .collect();
 
-  Ok((modules_map, old_runtime_modules))
+    let modules = compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .keys()
+      .par_bridge()
+      .filter_map(|identifier| {
+        compilation.code_generation_results.get_one(identifier)?;
+        let id = ChunkGraph::get_module_id(&compilation.module_ids, *identifier)?;
+        Some((*identifier, id.clone()))
+      })
+      .collect::<IdentifierMap<_>>();
+
+    let chunk_module_hashes = chunks
+      .par_iter()
+      .map(|(id, chunk)| {
+        let hashes = compilation
+          .chunk_graph
+          .get_chunk_modules_identifier(&chunk.ukey())
+          .iter()
+          .filter_map(|identifier| {
+            let hash = compilation
+              .code_generation_results
+              .get_hash_by_runtime(identifier, chunk.runtime())
+              .or_else(|| {
+                compilation
+                  .code_generation_results
+                  .get_one(identifier)
+                  .and_then(|result| result.hash.as_ref())
+              })?;
+            Some((*identifier, hash.clone()))
+          })
+          .collect::<IdentifierMap<_>>();
+        ((*id).to_string(), hashes)
+      })
+      .collect::<HashMap<_, _>>();
+
+    let runtime_modules = compilation
+      .runtime_modules
+      .iter()
+      .map(|(identifier, module)| -> Result<(Identifier, String)> {
+        Ok((
+          *identifier,
+          module
+            .generate_with_custom(compilation)?
+            .source()
+            .to_string(),
+        ))
+      })
+      .collect::<Result<IdentifierMap<String>>>()?;
+
+    Ok(Self {
+      chunks: chunks
+        .into_iter()
+        .map(|(id, chunk)| (id.to_string(), chunk.runtime().clone()))
+        .collect(),
+      runtimes,
+      modules,
+      chunk_module_hashes,
+      runtime_modules,
+      hash: compilation.hash.clone(),
+    })
+  }
 }
@@ -4,14 +4,91 @@ use rustc_hash::FxHashSet as HashSet;
 use swc_core::atoms::Atom;
 
 use super::super::MakeArtifact;
-use crate::{AsyncDependenciesBlockIdentifier, GroupOptions, ModuleGraph, ModuleIdentifier};
+use crate::{
+  AsyncDependenciesBlockIdentifier, DependenciesBlock, DependencyId, GroupOptions, ModuleGraph,
+  ModuleIdentifier,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct ChildModuleDeps {
+  // only referenced by weak dependencies, which will not add the module to the chunk graph
+  weak: bool,
+  // imported ids of esm import specifier dependencies
+  ids: HashSet<Atom>,
+}
+
+impl Default for ChildModuleDeps {
+  fn default() -> Self {
+    Self {
+      weak: true,
+      ids: Default::default(),
+    }
+  }
+}
+
+type ChildModules = IndexMap<ModuleIdentifier, ChildModuleDeps>;
+
+fn collect_child_modules(module_graph: &ModuleGraph, deps: &[DependencyId]) -> ChildModules {
+  let mut child_deps = ChildModules::default();
+
+  for dep_id in deps {
+    let dep = module_graph
+      .dependency_by_id(dep_id)
+      .expect("should have dependency");
+
+    let Some(conn) = module_graph.connection_by_dependency_id(dep_id) else {
+      continue;
+    };
+    let identifier = conn.module_identifier();
+    let child = child_deps.entry(*identifier).or_default();
+    child.weak &= dep.as_module_dependency().is_some_and(|dep| dep.weak());
+
+    if matches!(
+      dep.dependency_type(),
+      crate::DependencyType::EsmImportSpecifier
+    ) {
+      let dep_ids = dep.get_ids(module_graph);
+      child.ids.extend(dep_ids.into_iter());
+    }
+  }
+
+  child_deps
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct BlockDeps {
+  id: AsyncDependenciesBlockIdentifier,
+  group_options: Option<GroupOptions>,
+  // child module identifier of current block
+  child_modules: ChildModules,
+  // nested blocks in current block
+  blocks: Vec<BlockDeps>,
+}
+
+impl BlockDeps {
+  fn from_block(module_graph: &ModuleGraph, block_id: &AsyncDependenciesBlockIdentifier) -> Self {
+    let block = module_graph
+      .block_by_id(block_id)
+      .expect("should have block");
+    Self {
+      id: *block_id,
+      group_options: block.get_group_options().cloned(),
+      child_modules: collect_child_modules(module_graph, block.get_dependencies()),
+      blocks: block
+        .get_blocks()
+        .iter()
+        .map(|block_id| Self::from_block(module_graph, block_id))
+        .collect(),
+    }
+  }
+}
 
 #[derive(Debug, Default, Clone)]
 struct ModuleDeps {
   // child module identifier of current module
-  child_modules: IndexMap<ModuleIdentifier, HashSet<Atom>>,
+  child_modules: ChildModules,
   // blocks in current module
-  module_blocks: Vec<(AsyncDependenciesBlockIdentifier, Option<GroupOptions>)>,
+  module_blocks: Vec<BlockDeps>,
 }
 
 impl std::cmp::PartialEq for ModuleDeps {
@@ -38,45 +115,18 @@ impl std::cmp::PartialEq for ModuleDeps {
 
 impl ModuleDeps {
   fn from_module(module_graph: &ModuleGraph, module_identifier: &ModuleIdentifier) -> Self {
-    let mut res = Self::default();
     let module = module_graph
       .module_by_identifier(module_identifier)
       .expect("should have module");
 
-    let deps = module.get_dependencies();
-    let mut child_deps: IndexMap<ModuleIdentifier, HashSet<Atom>> = Default::default();
-
-    for dep_id in deps {
-      let dep = module_graph
-        .dependency_by_id(dep_id)
-        .expect("should have dependency");
-
-      let Some(conn) = module_graph.connection_by_dependency_id(dep_id) else {
-        continue;
-      };
-      let identifier = conn.module_identifier();
-      let ids = child_deps.entry(*identifier).or_default();
-
-      if matches!(
-        dep.dependency_type(),
-        crate::DependencyType::EsmImportSpecifier
-      ) {
-        let dep_ids = dep.get_ids(module_graph);
-        ids.extend(dep_ids.into_iter());
-      }
-    }
-
-    res.child_modules = child_deps;
-    for block_id in module.get_blocks() {
-      let block = module_graph
-        .block_by_id(block_id)
-        .expect("should have block");
-      res
-        .module_blocks
-        .push((*block_id, block.get_group_options().cloned()));
+    Self {
+      child_modules: collect_child_modules(module_graph, module.get_dependencies()),
+      module_blocks: module
+        .get_blocks()
+        .iter()
+        .map(|block_id| BlockDeps::from_block(module_graph, block_id))
+        .collect(),
     }
-
-    res
   }
 }
 
@@ -151,10 +201,11 @@ mod t {
 
   use crate::{
     compiler::make::cutout::has_module_graph_change::ModuleDeps, AffectType, AsContextDependency,
-    BuildInfo, BuildMeta, CodeGenerationResult, Compilation, ConcatenationScope, Context,
-    DependenciesBlock, Dependency, DependencyId, DependencyTemplate, ExportsInfo, FactoryMeta,
-    Module, ModuleDependency, ModuleGraph, ModuleGraphModule, ModuleGraphPartial, ModuleIdentifier,
-    ModuleType, RuntimeSpec, SourceType,
+    AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BuildInfo, BuildMeta,
+    CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, Dependency,
+    DependencyId, DependencyTemplate, ExportsInfo, FactoryMeta, Module, ModuleDependency,
+    ModuleGraph, ModuleGraphModule, ModuleGraphPartial, ModuleIdentifier, ModuleType, RuntimeSpec,
+    SourceType,
   };
 
   #[derive(Debug, Clone)]
@@ -230,6 +281,7 @@ mod t {
   struct TestModule {
     pub(crate) id: ModuleIdentifier,
     deps: Vec<DependencyId>,
+    blocks: Vec<AsyncDependenciesBlockIdentifier>,
   }
 
   impl TestModule {
@@ -237,18 +289,19 @@ mod t {
       Self {
         id: id.into(),
         deps,
+        blocks: vec![],
         source_map_kind: SourceMapKind::empty(),
       }
     }
   }
 
   impl DependenciesBlock for TestModule {
-    fn add_block_id(&mut self, _block: crate::AsyncDependenciesBlockIdentifier) {
-      todo!()
+    fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+      self.blocks.push(block);
     }
 
-    fn get_blocks(&self) -> &[crate::AsyncDependenciesBlockIdentifier] {
-      &[]
+    fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+      &self.blocks
     }
 
     fn add_dependency_id(&mut self, dependency: DependencyId) {
@@ -382,4 +435,71 @@ mod t {
     let module_deps_3 = ModuleDeps::from_module(&mg, &module_orig_id);
     assert_ne!(module_deps_3, module_deps_1);
   }
+
+  #[test]
+  #[allow(unused_results, clippy::unwrap_used)]
+  fn test_module_deps_with_blocks() {
+    let mut partial = ModuleGraphPartial::default();
+    let mut mg = ModuleGraph::new(vec![], Some(&mut partial));
+
+    let module_orig = Box::new(TestModule::new("app", vec![]));
+    let module_orig_id = module_orig.identifier();
+    let module1 = Box::new(TestModule::new("lib_foo", vec![]));
+    let module1_id = module1.id;
+    let module2 = Box::new(TestModule::new("lib_bar", vec![]));
+    let module2_id = module2.id;
+    for module in [module_orig, module1, module2] {
+      let id = module.identifier();
+      mg.add_module(module);
+      mg.add_module_graph_module(ModuleGraphModule::new(id, ExportsInfo::new()));
+    }
+
+    let dep1 = Box::new(TestDep::new(vec![]));
+    let dep1_id = *dep1.id();
+    let mut block = AsyncDependenciesBlock::new(module_orig_id, None, None, vec![], None);
+    block.add_dependency_id(dep1_id);
+    let block_id = block.identifier();
+    mg.add_block(Box::new(block));
+    mg.module_by_identifier_mut(&module_orig_id)
+      .expect("should have module")
+      .add_block_id(block_id);
+    mg.add_dependency(dep1);
+    mg.set_resolved_module(Some(module_orig_id), dep1_id, module1_id)
+      .unwrap();
+
+    let module_deps_1 = ModuleDeps::from_module(&mg, &module_orig_id);
+    assert_eq!(module_deps_1, ModuleDeps::from_module(&mg, &module_orig_id));
+
+    // the block keeps its identifier but imports another module
+    let dep2 = Box::new(TestDep::new(vec![]));
+    let dep2_id = *dep2.id();
+    mg.block_by_id_mut(&block_id)
+      .expect("should have block")
+      .add_dependency_id(dep2_id);
+    mg.add_dependency(dep2);
+    mg.set_resolved_module(Some(module_orig_id), dep2_id, module2_id)
+      .unwrap();
+    let module_deps_2 = ModuleDeps::from_module(&mg, &module_orig_id);
+    assert_ne!(module_deps_1, module_deps_2);
+
+    // add a nested block to the block
+    let mut parent_block = mg
+      .block_by_id(&block_id)
+      .expect("should have block")
+      .clone();
+    let nested_block =
+      AsyncDependenciesBlock::new(module_orig_id, None, Some("nested"), vec![], None);
+    let nested_block_id = nested_block.identifier();
+    parent_block.add_block(nested_block);
+    for nested_block in parent_block.take_blocks() {
+      mg.add_block(nested_block);
+    }
+    mg.add_block(Box::new(parent_block));
+    assert_eq!(
+      mg.get_module_all_blocks(&module_orig_id),
+      vec![block_id, nested_block_id]
+    );
+    let module_deps_3 = ModuleDeps::from_module(&mg, &module_orig_id);
+    assert_ne!(module_deps_2, module_deps_3);
+  }
 }
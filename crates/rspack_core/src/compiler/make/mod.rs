@@ -72,6 +72,7 @@ impl MakeArtifact {
         .remove_batch_file(&build_info.build_dependencies);
     }
     self.revoked_modules.insert(*module_identifier);
+    self.built_modules.remove(module_identifier);
     module_graph.revoke_module(module_identifier)
   }
 
@@ -98,6 +99,14 @@ pub async fn make_module_graph(
   compilation: &Compilation,
   mut artifact: MakeArtifact,
 ) -> Result<MakeArtifact> {
+  // reset temporary data
+  artifact.built_modules = Default::default();
+  artifact.revoked_modules = Default::default();
+  artifact.diagnostics = Default::default();
+  artifact.has_module_graph_change = false;
+
+  compilation.cache.before_make(&mut artifact).await?;
+
   let mut params = Vec::with_capacity(6);
 
   if !compilation.entries.is_empty() {
@@ -127,12 +136,6 @@ pub async fn make_module_graph(
     params.push(MakeParam::ForceBuildDeps(make_failed_dependencies));
   }
 
-  // reset temporary data
-  artifact.built_modules = Default::default();
-  artifact.revoked_modules = Default::default();
-  artifact.diagnostics = Default::default();
-  artifact.has_module_graph_change = false;
-
   artifact = update_module_graph(compilation, artifact, params).await?;
   Ok(artifact)
 }
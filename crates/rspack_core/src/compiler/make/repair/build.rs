@@ -171,8 +171,12 @@ impl Task<MakeTaskContext> for BuildResultTask {
         module_graph.add_dependency(dependency);
       }
       if let Some(current_block) = current_block {
-        module.add_block_id(current_block.identifier());
         module_graph.add_block(current_block);
+      } else {
+        // nested blocks are recorded in their parent block
+        for block in &blocks {
+          module.add_block_id(block.identifier());
+        }
       }
       blocks
     };
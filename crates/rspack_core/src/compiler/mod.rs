@@ -2,8 +2,11 @@ mod compilation;
 mod hmr;
 mod make;
 mod module_executor;
+mod records;
 use std::sync::Arc;
 
+use async_recursion::async_recursion;
+use cow_utils::CowUtils;
 use rspack_error::Result;
 use rspack_fs::{FileSystem, NativeFileSystem, WritableFileSystem};
 use rspack_futures::FuturesResults;
@@ -14,14 +17,16 @@ use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
 
 pub use self::compilation::*;
-pub use self::hmr::{collect_changed_modules, CompilationRecords};
+pub use self::hmr::CompilationRecords;
+pub use self::make::MakeArtifact;
 pub use self::module_executor::{ExecuteModuleId, ExecutedRuntimeModule, ModuleExecutor};
+pub use self::records::{ChunkIdRecords, IdRecords, ModuleIdRecords, RecordId};
 use crate::cache::{new_cache, Cache};
 use crate::incremental::IncrementalPasses;
 use crate::old_cache::Cache as OldCache;
 use crate::{
-  fast_set, include_hash, trim_dir, BoxPlugin, CleanOptions, CompilerOptions, Logger, PluginDriver,
-  ResolverFactory, SharedPluginDriver,
+  fast_set, include_hash, trim_dir, BoxPlugin, CleanKeep, CompilationLogger, CompilerOptions,
+  Logger, PluginDriver, ResolverFactory, SharedPluginDriver,
 };
 use crate::{ContextModuleFactory, NormalModuleFactory};
 
@@ -167,17 +172,18 @@ impl Compiler {
         self.input_filesystem.clone(),
       ),
     );
-    self.cache.before_compile(&mut self.compilation);
+    self.cache.before_compile(&mut self.compilation).await?;
 
     self.compile().await?;
     self.old_cache.begin_idle();
     self.compile_done().await?;
-    self.cache.after_compile(&self.compilation);
+    self.cache.after_compile(&self.compilation).await?;
     Ok(())
   }
 
   #[instrument(name = "compile", skip_all)]
   async fn compile(&mut self) -> Result<()> {
+    self.compilation.id_records = self.read_records()?;
     let mut compilation_params = self.new_compilation_params();
     // FOR BINDING SAFETY:
     // Make sure `thisCompilation` hook was called for each `JsCompilation` update before any access to it.
@@ -259,6 +265,10 @@ impl Compiler {
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     Ok(())
   }
 
@@ -400,52 +410,111 @@ impl Compiler {
     let clean_options = &self.options.output.clean;
 
     // keep all
-    if let CleanOptions::CleanAll(false) = clean_options {
+    if !clean_options.enabled() {
       return Ok(());
     }
 
+    let logger = self.compilation.get_logger("rspack.CleanPlugin");
+    let dry = clean_options.dry();
+
     if self.emitted_asset_versions.is_empty() {
-      if let CleanOptions::KeepPath(p) = clean_options {
-        let path_to_keep = self.options.output.path.join(Utf8Path::new(p));
-        trim_dir(
-          &*self.output_filesystem,
-          &self.options.output.path,
-          &path_to_keep,
-        )
-        .await?;
+      // nothing is emitted by this compiler before
+      if clean_options.emitted_only() {
         return Ok(());
       }
 
-      // CleanOptions::CleanAll(true) only
-      debug_assert!(matches!(clean_options, CleanOptions::CleanAll(true)));
+      if !dry {
+        match clean_options.keep_matcher() {
+          Some(CleanKeep::Path(p)) => {
+            let path_to_keep = self.options.output.path.join(Utf8Path::new(p));
+            trim_dir(
+              &*self.output_filesystem,
+              &self.options.output.path,
+              &path_to_keep,
+            )
+            .await?;
+            return Ok(());
+          }
+          None => {
+            self
+              .output_filesystem
+              .remove_dir_all(&self.options.output.path)
+              .await?;
+            return Ok(());
+          }
+          _ => {}
+        }
+      }
 
-      self
+      if self
         .output_filesystem
-        .remove_dir_all(&self.options.output.path)
-        .await?;
+        .stat(&self.options.output.path)
+        .await
+        .is_ok_and(|stat| stat.is_directory)
+      {
+        self.clean_dir(&self.options.output.path, &logger).await?;
+      }
       return Ok(());
     }
 
     let assets = self.compilation.assets();
-    let _ = self
+    let stale_filenames = self
       .emitted_asset_versions
-      .iter()
-      .filter_map(|(filename, _version)| {
-        if !assets.contains_key(filename) {
-          let filename = filename.to_owned();
-          Some(async {
-            if !clean_options.keep(filename.as_str()) {
-              let filename = Utf8Path::new(&self.options.output.path).join(filename);
-              let _ = self.output_filesystem.remove_file(&filename).await;
-            }
-          })
+      .keys()
+      .filter(|filename| !assets.contains_key(*filename));
+    for filename in stale_filenames {
+      if clean_options.keep(filename).await? {
+        continue;
+      }
+      if dry {
+        logger.info(format!("{filename} will be removed"));
+      } else {
+        let filename = Utf8Path::new(&self.options.output.path).join(filename);
+        let _ = self.output_filesystem.remove_file(&filename).await;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Clean the files under `dir` which are neither kept nor emitted in this compilation,
+  /// returns true if all the files under `dir` are cleaned
+  #[async_recursion]
+  async fn clean_dir(&self, dir: &Utf8Path, logger: &CompilationLogger) -> Result<bool> {
+    let clean_options = &self.options.output.clean;
+    let dry = clean_options.dry();
+    let mut all_cleaned = true;
+    for entry in self.output_filesystem.read_dir(dir).await? {
+      let path = dir.join(entry);
+      if self.output_filesystem.stat(&path).await?.is_directory {
+        if self.clean_dir(&path, logger).await? {
+          if !dry {
+            self.output_filesystem.remove_dir_all(&path).await?;
+          }
         } else {
-          None
+          all_cleaned = false;
         }
-      })
-      .collect::<FuturesResults<_>>();
+        continue;
+      }
 
-    Ok(())
+      let filename = path
+        .strip_prefix(&self.options.output.path)
+        .expect("should be under the output path")
+        .as_str()
+        .cow_replace('\\', "/");
+      if self.compilation.assets().contains_key(filename.as_ref())
+        || clean_options.keep(&filename).await?
+      {
+        all_cleaned = false;
+        continue;
+      }
+      if dry {
+        logger.info(format!("{filename} will be removed"));
+      } else {
+        self.output_filesystem.remove_file(&path).await?;
+      }
+    }
+    Ok(all_cleaned)
   }
 
   fn new_compilation_params(&self) -> CompilationParams {
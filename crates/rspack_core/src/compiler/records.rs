@@ -0,0 +1,172 @@
+use std::{collections::BTreeMap, fmt};
+
+use rspack_error::{error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::Compiler;
+
+/// A module id or chunk id in the records.
+///
+/// Webpack writes numeric ids as JSON numbers, so both numbers and strings are accepted,
+/// and the ids which look like numbers are written back as numbers.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordId {
+  Number(u64),
+  String(String),
+}
+
+impl From<&str> for RecordId {
+  fn from(id: &str) -> Self {
+    match id.parse::<u64>() {
+      // keep the ids like "01" as strings, otherwise they can not be restored as is
+      Ok(n) if n.to_string() == id => Self::Number(n),
+      _ => Self::String(id.to_string()),
+    }
+  }
+}
+
+impl fmt::Display for RecordId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Number(n) => write!(f, "{n}"),
+      Self::String(s) => write!(f, "{s}"),
+    }
+  }
+}
+
+/// The records of module ids, keyed by the module identifier relative to the context
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleIdRecords {
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, RecordId>,
+  #[serde(default)]
+  pub used_ids: Vec<RecordId>,
+}
+
+/// The records of chunk ids, keyed by the chunk name or the chunk sources
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkIdRecords {
+  #[serde(default)]
+  pub by_name: BTreeMap<String, RecordId>,
+  #[serde(default)]
+  pub by_source: BTreeMap<String, RecordId>,
+  #[serde(default)]
+  pub used_ids: Vec<RecordId>,
+}
+
+/// The records read from `recordsInputPath` and written to `recordsOutputPath`,
+/// which are used to keep the module ids and chunk ids stable across builds.
+///
+/// Unknown fields are ignored, so the records written by webpack can be read as well.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdRecords {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub modules: Option<ModuleIdRecords>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub chunks: Option<ChunkIdRecords>,
+}
+
+impl Compiler {
+  pub(super) fn read_records(&self) -> Result<IdRecords> {
+    let Some(records_input_path) = &self.options.records_input_path else {
+      return Ok(IdRecords::default());
+    };
+    // the records of the first build do not exist yet
+    if self.input_filesystem.metadata(records_input_path).is_err() {
+      return Ok(IdRecords::default());
+    }
+    let content = self.input_filesystem.read(records_input_path)?;
+    if content.is_empty() {
+      return Ok(IdRecords::default());
+    }
+    serde_json::from_slice(&content)
+      .map_err(|e| error!("Cannot parse records {records_input_path}: {e}"))
+  }
+
+  pub(super) async fn emit_records(&self) -> Result<()> {
+    let Some(records_output_path) = &self.options.records_output_path else {
+      return Ok(());
+    };
+    let content = serde_json::to_string_pretty(&self.compilation.id_records)
+      .map_err(|e| error!("Cannot serialize records: {e}"))?;
+    if let Some(dir) = records_output_path.parent() {
+      self.input_filesystem.create_dir_all(dir).await?;
+    }
+    self
+      .input_filesystem
+      .write(records_output_path, content.as_bytes())
+      .await?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_read_records_written_by_webpack() {
+    let records: IdRecords = serde_json::from_str(
+      r#"{
+        "aggressiveSplits": [],
+        "chunks": {
+          "byName": { "main": 179, "async": "async" },
+          "bySource": { "0 main": 179, "0 ./index.js ./async": 52 },
+          "usedIds": [52, 179]
+        },
+        "modules": {
+          "byIdentifier": { "./index.js": 0, "./a.js": 138, "./b.js": "./b.js" },
+          "usedIds": [0, 138, "./b.js"]
+        }
+      }"#,
+    )
+    .expect("should parse records");
+
+    let modules = records.modules.as_ref().expect("should have modules");
+    assert_eq!(modules.by_identifier["./index.js"].to_string(), "0");
+    assert_eq!(modules.by_identifier["./a.js"].to_string(), "138");
+    assert_eq!(modules.by_identifier["./b.js"].to_string(), "./b.js");
+    assert_eq!(
+      modules
+        .used_ids
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>(),
+      ["0", "138", "./b.js"]
+    );
+
+    let chunks = records.chunks.as_ref().expect("should have chunks");
+    assert_eq!(chunks.by_name["main"].to_string(), "179");
+    assert_eq!(chunks.by_name["async"].to_string(), "async");
+    assert_eq!(chunks.by_source["0 ./index.js ./async"].to_string(), "52");
+  }
+
+  #[test]
+  fn should_write_numeric_ids_as_numbers() {
+    let records = IdRecords {
+      modules: Some(ModuleIdRecords {
+        by_identifier: BTreeMap::from([
+          ("./a.js".to_string(), RecordId::from("12")),
+          ("./b.js".to_string(), RecordId::from("01")),
+          ("./c.js".to_string(), RecordId::from("./c.js")),
+        ]),
+        used_ids: vec!["12".into(), "01".into(), "./c.js".into()],
+      }),
+      chunks: None,
+    };
+    let json = serde_json::to_value(&records).expect("should serialize records");
+    assert_eq!(
+      json,
+      serde_json::json!({
+        "modules": {
+          "byIdentifier": { "./a.js": 12, "./b.js": "01", "./c.js": "./c.js" },
+          "usedIds": [12, "01", "./c.js"]
+        }
+      })
+    );
+  }
+}
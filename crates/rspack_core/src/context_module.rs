@@ -5,6 +5,7 @@ use cow_utils::CowUtils;
 use derivative::Derivative;
 use indoc::formatdoc;
 use itertools::Itertools;
+use rspack_cacheable::cacheable;
 use rspack_collections::{Identifiable, Identifier, IdentifierMap};
 use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
 use rspack_macros::impl_source_map_config;
@@ -103,6 +104,7 @@ impl ContextNameSpaceObject {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Ord, Eq)]
 pub enum ContextTypePrefix {
   Import,
@@ -977,7 +979,7 @@ impl Module for ContextModule {
     })
   }
 
-  #[tracing::instrument(name = "ContextModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "ContextModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
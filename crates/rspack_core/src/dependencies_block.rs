@@ -1,5 +1,6 @@
 use std::{borrow::Cow, hash::Hash};
 
+use rspack_cacheable::{cacheable, with::Skip};
 use rspack_collections::Identifier;
 use rspack_error::{
   miette::{self, Diagnostic},
@@ -44,6 +45,7 @@ pub fn dependencies_block_update_hash(
   }
 }
 
+#[cacheable(hashable)]
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct AsyncDependenciesBlockIdentifier(Identifier);
 
@@ -65,6 +67,7 @@ impl From<Identifier> for AsyncDependenciesBlockIdentifier {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct AsyncDependenciesBlock {
   id: AsyncDependenciesBlockIdentifier,
@@ -72,9 +75,11 @@ pub struct AsyncDependenciesBlock {
   // Vec<Box<T: Sized>> makes sense if T is a large type (see #3530, 1st comment).
   // #3530: https://github.com/rust-lang/rust-clippy/issues/3530
   #[allow(clippy::vec_box)]
+  #[cacheable(with=Skip)]
   blocks: Vec<Box<AsyncDependenciesBlock>>,
   block_ids: Vec<AsyncDependenciesBlockIdentifier>,
   dependency_ids: Vec<DependencyId>,
+  #[cacheable(with=Skip)]
   dependencies: Vec<BoxDependency>,
   loc: Option<DependencyLocation>,
   parent: ModuleIdentifier,
@@ -137,10 +142,9 @@ impl AsyncDependenciesBlock {
     std::mem::take(&mut self.dependencies)
   }
 
-  pub fn add_block(&mut self, _block: AsyncDependenciesBlock) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block.id);
-    // self.blocks.push(block);
+  pub fn add_block(&mut self, block: AsyncDependenciesBlock) {
+    self.block_ids.push(block.id);
+    self.blocks.push(Box::new(block));
   }
 
   pub fn take_blocks(&mut self) -> Vec<Box<AsyncDependenciesBlock>> {
@@ -183,9 +187,8 @@ impl AsyncDependenciesBlock {
 }
 
 impl DependenciesBlock for AsyncDependenciesBlock {
-  fn add_block_id(&mut self, _block: AsyncDependenciesBlockIdentifier) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block);
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.block_ids.push(block);
   }
 
   fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
@@ -1,10 +1,13 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_util::ext::DynHash;
 
 use crate::{
-  AsDependency, Compilation, DependencyTemplate, InitFragmentExt, InitFragmentKey,
-  InitFragmentStage, NormalInitFragment, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+  AsDependency, CacheableDependencyTemplate, Compilation, DependencyTemplate, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, NormalInitFragment, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
 };
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct CachedConstDependency {
   pub start: u32,
@@ -58,6 +61,15 @@ impl DependencyTemplate for CachedConstDependency {
     self.end.dyn_hash(hasher);
     self.content.dyn_hash(hasher);
   }
+
+  fn as_cacheable_dependency_template(
+    &self,
+  ) -> Option<&(dyn CacheableDependencyTemplate + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependencyTemplate for CachedConstDependency {}
+
 impl AsDependency for CachedConstDependency {}
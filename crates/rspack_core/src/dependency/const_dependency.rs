@@ -1,10 +1,12 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_util::ext::DynHash;
 
 use crate::{
-  AsDependency, Compilation, DependencyTemplate, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  AsDependency, CacheableDependencyTemplate, Compilation, DependencyTemplate, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ConstDependency {
   pub start: u32,
@@ -58,6 +60,15 @@ impl DependencyTemplate for ConstDependency {
     self.content.dyn_hash(hasher);
     self.runtime_requirements.dyn_hash(hasher);
   }
+
+  fn as_cacheable_dependency_template(
+    &self,
+  ) -> Option<&(dyn CacheableDependencyTemplate + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependencyTemplate for ConstDependency {}
+
 impl AsDependency for ConstDependency {}
@@ -1,6 +1,9 @@
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
+use rspack_cacheable::cacheable;
+
+#[cacheable]
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum DependencyCategory {
   #[default]
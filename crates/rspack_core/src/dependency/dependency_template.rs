@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use dyn_clone::{clone_trait_object, DynClone};
+use rspack_cacheable::{cacheable_dyn, with::AsDynConverter};
 use rspack_sources::{BoxSource, ReplaceSource};
 use rspack_util::ext::AsAny;
 
@@ -57,10 +58,31 @@ pub trait DependencyTemplate: Debug + DynClone + Sync + Send + AsDependency + As
     compilation: &Compilation,
     runtime: Option<&RuntimeSpec>,
   );
+
+  /// Returns `Some` when the template can be saved to the persistent cache.
+  fn as_cacheable_dependency_template(
+    &self,
+  ) -> Option<&(dyn CacheableDependencyTemplate + 'static)> {
+    None
+  }
 }
 
+/// A [DependencyTemplate] which can be saved to and restored from the persistent cache.
+#[cacheable_dyn]
+pub trait CacheableDependencyTemplate: DependencyTemplate {}
+
 pub type BoxDependencyTemplate = Box<dyn DependencyTemplate>;
 
+impl AsDynConverter for BoxDependencyTemplate {
+  type Cacheable = dyn CacheableDependencyTemplate;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable> {
+    self.as_cacheable_dependency_template()
+  }
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self {
+    data
+  }
+}
+
 pub trait AsDependencyTemplate {
   fn as_dependency_template(&self) -> Option<&dyn DependencyTemplate> {
     None
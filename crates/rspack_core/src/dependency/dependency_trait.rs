@@ -1,6 +1,7 @@
 use std::{any::Any, fmt::Debug};
 
 use dyn_clone::{clone_trait_object, DynClone};
+use rspack_cacheable::{cacheable_dyn, with::AsDynConverter};
 use rspack_collections::IdentifierSet;
 use rspack_error::Diagnostic;
 use rspack_util::ext::AsAny;
@@ -110,8 +111,17 @@ pub trait Dependency:
   }
 
   fn could_affect_referencing_module(&self) -> AffectType;
+
+  /// Returns `Some` when the dependency can be saved to the persistent cache.
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    None
+  }
 }
 
+/// A [Dependency] which can be saved to and restored from the persistent cache.
+#[cacheable_dyn]
+pub trait CacheableDependency: Dependency {}
+
 impl dyn Dependency + '_ {
   pub fn downcast_ref<D: Any>(&self) -> Option<&D> {
     self.as_any().downcast_ref::<D>()
@@ -141,3 +151,13 @@ impl<T: Dependency> AsDependency for T {
 clone_trait_object!(Dependency);
 
 pub type BoxDependency = Box<dyn Dependency>;
+
+impl AsDynConverter for BoxDependency {
+  type Cacheable = dyn CacheableDependency;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable> {
+    self.as_cacheable_dependency()
+  }
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self {
+    data
+  }
+}
@@ -1,9 +1,12 @@
 use std::fmt::{Debug, Display};
 
+use rspack_cacheable::{cacheable, with::Unsupported};
+
 use crate::ContextTypePrefix;
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
 // Note: This is almost the same with the old `ResolveKind`
+#[cacheable]
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum DependencyType {
   #[default]
@@ -111,7 +114,7 @@ pub enum DependencyType {
   ModuleDecorator,
   DllEntry,
   DelegatedSource,
-  Custom(&'static str),
+  Custom(#[cacheable(with=Unsupported)] &'static str),
 }
 
 impl DependencyType {
@@ -31,6 +31,7 @@ pub use dependency_type::DependencyType;
 pub use entry::*;
 pub use loader_import::*;
 pub use module_dependency::*;
+use rspack_cacheable::cacheable;
 pub use runtime_requirements_dependency::RuntimeRequirementsDependency;
 pub use runtime_template::*;
 use rustc_hash::FxHashMap;
@@ -161,6 +162,7 @@ impl std::fmt::Debug for DependencyCondition {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Serialize)]
 pub struct ImportAttributes(FxHashMap<String, String>);
 
@@ -1,10 +1,12 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_util::ext::DynHash;
 
 use crate::{
-  AsDependency, Compilation, DependencyTemplate, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  AsDependency, CacheableDependencyTemplate, Compilation, DependencyTemplate, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 
+#[cacheable]
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub struct RuntimeRequirementsDependency {
   pub runtime_requirements: RuntimeGlobals,
@@ -33,7 +35,16 @@ impl DependencyTemplate for RuntimeRequirementsDependency {
   ) {
     self.runtime_requirements.dyn_hash(hasher);
   }
+
+  fn as_cacheable_dependency_template(
+    &self,
+  ) -> Option<&(dyn CacheableDependencyTemplate + 'static)> {
+    Some(self)
+  }
 }
+
+#[cacheable_dyn]
+impl CacheableDependencyTemplate for RuntimeRequirementsDependency {}
 impl AsDependency for RuntimeRequirementsDependency {}
 
 impl RuntimeRequirementsDependency {
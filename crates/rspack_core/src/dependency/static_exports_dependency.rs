@@ -1,17 +1,23 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, AsVec},
+};
 use swc_core::ecma::atoms::Atom;
 
 use super::AffectType;
 use crate::{
-  AsContextDependency, AsDependencyTemplate, AsModuleDependency, Dependency, DependencyId,
-  DependencyType, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
+  AsContextDependency, AsDependencyTemplate, AsModuleDependency, CacheableDependency, Dependency,
+  DependencyId, DependencyType, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
 };
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub enum StaticExportsSpec {
   True,
-  Array(Vec<Atom>),
+  Array(#[cacheable(with=AsVec<AsPreset>)] Vec<Atom>),
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct StaticExportsDependency {
   id: DependencyId,
@@ -57,8 +63,15 @@ impl Dependency for StaticExportsDependency {
   fn could_affect_referencing_module(&self) -> AffectType {
     AffectType::True
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for StaticExportsDependency {}
+
 impl AsDependencyTemplate for StaticExportsDependency {}
 impl AsModuleDependency for StaticExportsDependency {}
 
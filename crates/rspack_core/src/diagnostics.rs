@@ -2,12 +2,12 @@ use std::{fmt::Display, path::PathBuf};
 
 use itertools::Itertools;
 use rspack_error::{
+  code::{without_diagnostic_code, MODULE_PARSE_FAILED, MODULE_PARSE_WARNING},
   error, impl_diagnostic_transparent,
   miette::{self, Diagnostic},
   thiserror::{self, Error},
-  DiagnosticExt, Error, TraceableError,
+  DiagnosticCode, DiagnosticExt, Error, TraceableError,
 };
-use rspack_util::ext::AsAny;
 use rustc_hash::FxHashSet;
 
 use crate::{BoxLoader, DependencyRange};
@@ -148,6 +148,20 @@ impl ModuleParseError {
   }
 }
 
+impl From<ModuleParseError> for rspack_error::Diagnostic {
+  fn from(value: ModuleParseError) -> Self {
+    // Keep the code attached to the source, parse errors without one fall back to the general code
+    let code = DiagnosticCode::of_diagnostic(value.source.as_ref()).unwrap_or_else(|| {
+      if value.source.severity().unwrap_or(miette::Severity::Error) >= miette::Severity::Error {
+        &MODULE_PARSE_FAILED
+      } else {
+        &MODULE_PARSE_WARNING
+      }
+    });
+    rspack_error::Diagnostic::from(miette::Error::new(value)).with_diagnostic_code(code)
+  }
+}
+
 #[derive(Debug)]
 pub struct CapturedLoaderError {
   pub message: String,
@@ -244,12 +258,11 @@ pub fn map_box_diagnostics_to_module_parse_diagnostics(
   errors
     .into_iter()
     .map(|e| {
-      let hide_stack = e
-        .as_any()
+      let error: &(dyn std::error::Error + 'static) = without_diagnostic_code(e.as_ref());
+      let hide_stack = error
         .downcast_ref::<TraceableError>()
         .and_then(|e| e.hide_stack());
-      let diagnostic: rspack_error::Diagnostic =
-        rspack_error::miette::Error::new(ModuleParseError::new(e, loaders)).into();
+      let diagnostic: rspack_error::Diagnostic = ModuleParseError::new(e, loaders).into();
       diagnostic.with_hide_stack(hide_stack)
     })
     .collect()
@@ -547,7 +547,7 @@ impl Module for ExternalModule {
     Ok(build_result)
   }
 
-  #[tracing::instrument(name = "ExternalModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "ExternalModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
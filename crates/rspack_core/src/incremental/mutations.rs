@@ -318,9 +318,12 @@ fn compute_affected_modules_with_chunk_graph(
     module: &dyn Module,
   ) -> u64 {
     let mut hasher = FxHasher::default();
-    for block_id in module.get_blocks() {
+    for block_id in compilation
+      .get_module_graph()
+      .get_module_all_blocks(&module.identifier())
+    {
       let Some(chunk_group) =
-        chunk_graph.get_block_chunk_group(block_id, &compilation.chunk_group_by_ukey)
+        chunk_graph.get_block_chunk_group(&block_id, &compilation.chunk_group_by_ukey)
       else {
         continue;
       };
@@ -5,6 +5,7 @@
 #![feature(anonymous_lifetime_in_impl_trait)]
 #![feature(hash_raw_entry)]
 #![feature(option_get_or_insert_default)]
+#![feature(trait_upcasting)]
 
 use std::{fmt, sync::Arc};
 mod cgm_hash_results;
@@ -70,6 +71,7 @@ pub use chunk::*;
 mod dependency;
 pub use dependency::*;
 mod utils;
+use rspack_cacheable::{cacheable, with::AsPreset};
 use ustr::Ustr;
 pub use utils::*;
 mod chunk_graph;
@@ -162,6 +164,7 @@ impl From<&str> for SourceType {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
@@ -183,7 +186,7 @@ pub enum ModuleType {
   ProvideShared,
   ConsumeShared,
   SelfReference,
-  Custom(Ustr),
+  Custom(#[cacheable(with=AsPreset)] Ustr),
 }
 
 impl ModuleType {
@@ -5,6 +5,10 @@ use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use json::JsonValue;
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsDynConverter, AsOption, AsPreset, AsVec},
+};
 use rspack_collections::{Identifiable, Identifier, IdentifierSet};
 use rspack_error::{Diagnosable, Diagnostic, Result};
 use rspack_fs::FileSystem;
@@ -42,6 +46,7 @@ pub enum BuildExtraDataType {
   JavaScriptParserAndGenerator,
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct BuildInfo {
   /// Whether the result is cacheable, i.e shared between builds.
@@ -52,10 +57,13 @@ pub struct BuildInfo {
   pub context_dependencies: HashSet<ArcPath>,
   pub missing_dependencies: HashSet<ArcPath>,
   pub build_dependencies: HashSet<ArcPath>,
+  #[cacheable(with=AsVec<AsPreset>)]
   pub esm_named_exports: HashSet<Atom>,
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
+  #[cacheable(with=AsOption<AsPreset>)]
   pub json_data: Option<JsonValue>,
+  #[cacheable(with=AsOption<AsVec<AsPreset>>)]
   pub top_level_declarations: Option<HashSet<Atom>>,
   pub module_concatenation_bailout: Option<String>,
 }
@@ -80,6 +88,7 @@ impl Default for BuildInfo {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum BuildMetaExportsType {
@@ -99,6 +108,7 @@ pub enum ExportsType {
   Dynamic,
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, Copy, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum BuildMetaDefaultObject {
@@ -114,6 +124,7 @@ pub enum BuildMetaDefaultObject {
   },
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, Copy, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ModuleArgument {
@@ -131,6 +142,7 @@ impl Display for ModuleArgument {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ExportsArgument {
@@ -148,6 +160,7 @@ impl Display for ExportsArgument {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildMeta {
@@ -175,6 +188,7 @@ pub struct BuildResult {
   pub optimization_bailouts: Vec<String>,
 }
 
+#[cacheable]
 #[derive(Debug, Default, Clone)]
 pub struct FactoryMeta {
   pub side_effect_free: Option<bool>,
@@ -381,8 +395,17 @@ pub trait Module:
   fn need_id(&self) -> bool {
     true
   }
+
+  /// Returns `Some` when the module can be saved to the persistent cache.
+  fn as_cacheable_module(&self) -> Option<&(dyn CacheableModule + 'static)> {
+    None
+  }
 }
 
+/// A [Module] which can be saved to and restored from the persistent cache.
+#[cacheable_dyn]
+pub trait CacheableModule: Module {}
+
 fn get_exports_type_impl(
   identifier: ModuleIdentifier,
   build_meta: Option<&BuildMeta>,
@@ -519,6 +542,16 @@ impl<T: Module> ModuleExt for T {
 
 pub type BoxModule = Box<dyn Module>;
 
+impl AsDynConverter for BoxModule {
+  type Cacheable = dyn CacheableModule;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable> {
+    self.as_cacheable_module()
+  }
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self {
+    data
+  }
+}
+
 impl Identifiable for Box<dyn Module> {
   /// Uniquely identify a module. If two modules share the same module identifier, then they are considered as the same module.
   /// e.g `javascript/auto|<absolute-path>/index.js` and `javascript/auto|<absolute-path>/index.js` are considered as the same.
@@ -1,4 +1,4 @@
-use std::collections::hash_map::Entry;
+use std::collections::{hash_map::Entry, VecDeque};
 
 use rspack_collections::{IdentifierMap, UkeyMap};
 use rspack_error::Result;
@@ -7,8 +7,8 @@ use rustc_hash::FxHashMap as HashMap;
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, Compilation, Dependency,
-  ExportProvided, ProvidedExports, RuntimeSpec, UsedExports,
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, Compilation, DependenciesBlock,
+  Dependency, ExportProvided, ProvidedExports, RuntimeSpec, UsedExports,
 };
 mod module;
 pub use module::*;
@@ -251,10 +251,7 @@ impl<'a> ModuleGraph<'a> {
   }
 
   pub fn revoke_module(&mut self, module_id: &ModuleIdentifier) -> Vec<BuildDependency> {
-    let blocks = self
-      .module_by_identifier(module_id)
-      .map(|m| Vec::from(m.get_blocks()))
-      .unwrap_or_default();
+    let blocks = self.get_module_all_blocks(module_id);
 
     let (outgoing_connections, incoming_connections) = self
       .module_graph_module_by_identifier(module_id)
@@ -585,6 +582,25 @@ impl<'a> ModuleGraph<'a> {
       .expect("block has been removed to None")
   }
 
+  /// Get the blocks of the module, including the blocks nested in other blocks
+  pub fn get_module_all_blocks(
+    &self,
+    module_identifier: &ModuleIdentifier,
+  ) -> Vec<AsyncDependenciesBlockIdentifier> {
+    let mut res = vec![];
+    let Some(module) = self.module_by_identifier(module_identifier) else {
+      return res;
+    };
+    let mut queue = VecDeque::from(module.get_blocks().to_vec());
+    while let Some(block_id) = queue.pop_front() {
+      if let Some(block) = self.block_by_id(&block_id) {
+        queue.extend(block.get_blocks());
+      }
+      res.push(block_id);
+    }
+    res
+  }
+
   pub fn dependencies(&self) -> HashMap<DependencyId, &BoxDependency> {
     let mut res = HashMap::default();
     for item in self.partials.iter() {
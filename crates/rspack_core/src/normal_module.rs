@@ -11,8 +11,15 @@ use std::{
 
 use dashmap::DashMap;
 use derivative::Derivative;
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsDyn, AsOption, AsPreset, AsVec, Skip, Unsupported},
+};
 use rspack_collections::{Identifiable, IdentifierSet};
-use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result, Severity};
+use rspack_error::{
+  code::MODULE_BUILD_FAILED, error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result,
+  Severity,
+};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::define_hook;
 use rspack_loader_runner::{run_loaders, AdditionalData, Content, LoaderContext, ResourceData};
@@ -32,8 +39,8 @@ use crate::{
   contextify,
   diagnostics::{CapturedLoaderError, ModuleBuildError},
   get_context, impl_module_meta_info, module_update_hash, AsyncDependenciesBlockIdentifier,
-  BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph,
-  CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState, Context,
+  BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, CacheableModule,
+  ChunkGraph, CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState, Context,
   DependenciesBlock, DependencyId, DependencyTemplate, FactoryMeta, GenerateContext,
   GeneratorOptions, LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier,
   ModuleLayer, ModuleType, OutputOptions, ParseContext, ParseResult, ParserAndGenerator,
@@ -41,6 +48,7 @@ use crate::{
   SourceType,
 };
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub enum ModuleIssuer {
   Unset,
@@ -92,6 +100,7 @@ pub struct NormalModuleHooks {
 }
 
 #[impl_source_map_config]
+#[cacheable]
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct NormalModule {
@@ -112,6 +121,7 @@ pub struct NormalModule {
   /// Layer of the module
   layer: Option<ModuleLayer>,
   /// Affiliated parser and generator to the module type
+  #[cacheable(with=AsDyn)]
   parser_and_generator: Box<dyn ParserAndGenerator>,
   /// Resource matched with inline match resource, (`!=!` syntax)
   match_resource: Option<ResourceData>,
@@ -119,26 +129,35 @@ pub struct NormalModule {
   resource_data: Arc<ResourceData>,
   /// Loaders for the module
   #[derivative(Debug = "ignore")]
+  #[cacheable(with=Skip)]
   loaders: Vec<BoxLoader>,
 
   /// Original content of this module, will be available after module build
+  #[cacheable(with=AsOption<AsPreset>)]
   original_source: Option<BoxSource>,
   /// Built source of this module (passed with loaders)
   source: NormalModuleSource,
 
   /// Resolve options derived from [Rule.resolve]
+  #[cacheable(with=AsOption<Unsupported>)]
   resolve_options: Option<Box<Resolve>>,
   /// Parser options derived from [Rule.parser]
   parser_options: Option<ParserOptions>,
   /// Generator options derived from [Rule.generator]
+  #[cacheable(with=AsOption<Unsupported>)]
   generator_options: Option<GeneratorOptions>,
 
   #[allow(unused)]
+  #[cacheable(with=Skip)]
   debug_id: usize,
+  #[cacheable(with=Skip)]
   cached_source_sizes: DashMap<SourceType, f64, BuildHasherDefault<FxHasher>>,
+  #[cacheable(with=Skip)]
   diagnostics: Mutex<Vec<Diagnostic>>,
 
+  #[cacheable(with=AsOption<AsVec<Unsupported>>)]
   code_generation_dependencies: Option<Vec<Box<dyn ModuleDependency>>>,
+  #[cacheable(with=AsOption<AsVec<AsDyn>>)]
   presentational_dependencies: Option<Vec<Box<dyn DependencyTemplate>>>,
 
   factory_meta: Option<FactoryMeta>,
@@ -148,11 +167,12 @@ pub struct NormalModule {
   last_successful_build_meta: BuildMeta,
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub enum NormalModuleSource {
   Unbuild,
-  BuiltSucceed(BoxSource),
-  BuiltFailed(Diagnostic),
+  BuiltSucceed(#[cacheable(with=AsPreset)] BoxSource),
+  BuiltFailed(#[cacheable(with=Unsupported)] Diagnostic),
 }
 
 impl NormalModuleSource {
@@ -390,6 +410,7 @@ impl Module for NormalModule {
     }
   }
 
+  #[tracing::instrument(name = "NormalModule::build", skip_all, fields(identifier = %self.identifier()))]
   async fn build(
     &mut self,
     build_context: BuildContext,
@@ -471,6 +492,7 @@ impl Module for NormalModule {
             }))
             .boxed(),
           )
+          .with_diagnostic_code(&MODULE_BUILD_FAILED)
           .with_stack(stack)
           .with_hide_stack(captured_error.hide_stack)
         } else {
@@ -479,6 +501,7 @@ impl Module for NormalModule {
           let hide_stack = node_error.and_then(|e| e.hide_stack);
           let e = ModuleBuildError(r).boxed();
           Diagnostic::from(e)
+            .with_diagnostic_code(&MODULE_BUILD_FAILED)
             .with_stack(stack)
             .with_hide_stack(hide_stack)
         };
@@ -617,7 +640,7 @@ impl Module for NormalModule {
     })
   }
 
-  #[tracing::instrument(name = "NormalModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "NormalModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
@@ -798,8 +821,24 @@ impl Module for NormalModule {
       .parser_and_generator
       .get_concatenation_bailout_reason(self, mg, cg)
   }
+
+  fn as_cacheable_module(&self) -> Option<&(dyn CacheableModule + 'static)> {
+    // modules with diagnostics or uncacheable loader results have to be rebuilt
+    let has_diagnostics = !self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .is_empty();
+    if has_diagnostics || !self.build_info.as_ref().is_some_and(|info| info.cacheable) {
+      return None;
+    }
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableModule for NormalModule {}
+
 impl Diagnosable for NormalModule {
   fn add_diagnostic(&self, diagnostic: Diagnostic) {
     self
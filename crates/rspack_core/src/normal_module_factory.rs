@@ -2,7 +2,7 @@ use std::sync::LazyLock;
 use std::{borrow::Cow, sync::Arc};
 
 use regex::Regex;
-use rspack_error::{error, Result};
+use rspack_error::{code::EMPTY_DEPENDENCY, error, DiagnosticExt, Error, MietteExt, Result};
 use rspack_hook::define_hook;
 use rspack_loader_runner::{get_scheme, Loader, Scheme};
 use rspack_paths::Utf8PathBuf;
@@ -231,7 +231,11 @@ impl NormalModuleFactory {
 
         if first_char.is_none() {
           let span = dependency.source_span().unwrap_or_default();
-          return Err(EmptyDependency::new(DependencyRange::new(span.start, span.end)).into());
+          return Err(Error::new_boxed(
+            EmptyDependency::new(DependencyRange::new(span.start, span.end))
+              .boxed()
+              .with_diagnostic_code(&EMPTY_DEPENDENCY),
+          ));
         }
 
         // See: https://webpack.js.org/concepts/loaders/#inline
@@ -305,9 +309,25 @@ impl NormalModuleFactory {
           .call(data, &mut resource_data, &context_scheme)
           .await?
           .unwrap_or_default();
-        handled.then_some(resource_data)
+        if handled {
+          Some(resource_data)
+        } else {
+          // relative requests are resolved against the context of the scheme,
+          // e.g. `./b.js` imported by `vfs:/lib/a.js` is `vfs:/lib/b.js`
+          resolve_relative_in_scheme(&data.context, &resource).map(ResourceData::new)
+        }
       }
     {
+      let scheme = resource_data.get_scheme().clone();
+      let mut resource_data = resource_data;
+      // resources resolved within a scheme are handled by the plugin of their own scheme
+      if !scheme.is_none() && resource_data.resource_path.is_none() {
+        plugin_driver
+          .normal_module_factory_hooks
+          .resolve_for_scheme
+          .call(data, &mut resource_data, &scheme)
+          .await?;
+      }
       resource_data
     } else {
       // resource without scheme and without path
@@ -380,7 +400,6 @@ impl NormalModuleFactory {
 
       vec![]
     } else {
-      //TODO: with contextScheme
       self
         .calculate_module_rules(
           if let Some(match_resource_data) = match_resource_data.as_ref() {
@@ -731,6 +750,7 @@ impl NormalModuleFactory {
         (ParserOptions::CssModule(a), ParserOptions::CssModule(b)) => {
           ParserOptions::CssModule(a.merge_from(b))
         }
+        (ParserOptions::Json(a), ParserOptions::Json(b)) => ParserOptions::Json(a.merge_from(b)),
         (
           ParserOptions::Javascript(a),
           ParserOptions::JavascriptAuto(b)
@@ -841,6 +861,22 @@ impl NormalModuleFactory {
   }
 }
 
+/// Resolve a relative request (`./`, `../` or `/`) against a context with scheme,
+/// with the semantics of URL, returns `None` if the context is not hierarchical,
+/// e.g. `data:` URIs, then the request should be resolved as a normal request.
+fn resolve_relative_in_scheme(context: &Context, request: &str) -> Option<String> {
+  if !(request.starts_with("./") || request.starts_with("../") || request.starts_with(SLASH))
+    || request.starts_with("//")
+  {
+    return None;
+  }
+  let base = url::Url::parse(&format!("{}/", context.as_str().trim_end_matches(SLASH))).ok()?;
+  if base.cannot_be_a_base() {
+    return None;
+  }
+  base.join(request).ok().map(|url| url.to_string())
+}
+
 /// Using `u32` instead of `usize` to reduce memory usage,
 /// `u32` is 4 bytes on 64bit machine, comparing to `usize` which is 8 bytes.
 /// ## Warning
@@ -885,3 +921,25 @@ fn match_webpack_ext() {
 
   assert_eq!(cap.get(1).unwrap().as_str(), "type/javascript");
 }
+
+#[test]
+fn relative_in_scheme() {
+  let context = Context::from("vfs:/lib");
+  assert_eq!(
+    resolve_relative_in_scheme(&context, "./b.js").as_deref(),
+    Some("vfs:/lib/b.js")
+  );
+  assert_eq!(
+    resolve_relative_in_scheme(&context, "../b.js?q#f").as_deref(),
+    Some("vfs:/b.js?q#f")
+  );
+  assert_eq!(
+    resolve_relative_in_scheme(&Context::from("https://example.com/lib"), "/b.js").as_deref(),
+    Some("https://example.com/b.js")
+  );
+  assert_eq!(resolve_relative_in_scheme(&context, "b"), None);
+  assert_eq!(
+    resolve_relative_in_scheme(&Context::from("data:text/"), "./b.js"),
+    None
+  );
+}
@@ -1,25 +1,104 @@
-use std::{path::PathBuf, str::FromStr};
+use std::{fmt, path::PathBuf, str::FromStr};
 
+use futures::future::BoxFuture;
+use rspack_error::Result;
 use rspack_paths::Utf8PathBuf;
+use rspack_regex::RspackRegex;
+
+pub type CleanKeepFn = Box<dyn Fn(String) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
+/// Matches the files to keep, the path is relative to the output path and uses `/` as separator
+pub enum CleanKeep {
+  // keep the files under this path
+  Path(Utf8PathBuf),
+  // keep the files matched by this glob pattern
+  Glob(String),
+  // keep the files matched by this regex
+  Regexp(RspackRegex),
+  // keep the files when the function returns true
+  Fn(CleanKeepFn),
+}
+
+impl fmt::Debug for CleanKeep {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+      Self::Glob(glob) => f.debug_tuple("Glob").field(glob).finish(),
+      Self::Regexp(regex) => f.debug_tuple("Regexp").field(regex).finish(),
+      Self::Fn(_) => "Fn(...)".fmt(f),
+    }
+  }
+}
+
+impl CleanKeep {
+  pub async fn try_match(&self, path: &str) -> Result<bool> {
+    match self {
+      Self::Path(value) => Ok(PathBuf::from(path).starts_with(value)),
+      Self::Glob(glob) => Ok(fast_glob::glob_match(glob, path)),
+      Self::Regexp(regex) => Ok(regex.test(path)),
+      Self::Fn(func) => func(path.to_string()).await,
+    }
+  }
+}
+
+impl From<&str> for CleanKeep {
+  fn from(value: &str) -> Self {
+    if value.contains(['*', '?', '[', '{']) {
+      Self::Glob(value.trim_start_matches("./").to_string())
+    } else {
+      Self::Path(Utf8PathBuf::from_str(value).expect("should be a valid path"))
+    }
+  }
+}
 
 /// rust representation of the clean options
-// TODO: support RegExp and function type
 #[derive(Debug)]
 pub enum CleanOptions {
   // if true, clean all files
   CleanAll(bool),
-  // keep the files under this path
-  KeepPath(Utf8PathBuf),
+  // clean the files which are not kept
+  Detailed {
+    keep: Option<CleanKeep>,
+    // only report the files that would be removed
+    dry: bool,
+    // only clean the files emitted by this compiler before
+    emitted_only: bool,
+  },
 }
 
 impl CleanOptions {
-  pub fn keep(&self, path: &str) -> bool {
+  pub async fn keep(&self, path: &str) -> Result<bool> {
     match self {
-      Self::CleanAll(value) => !*value,
-      Self::KeepPath(value) => {
-        let path = PathBuf::from(path);
-        path.starts_with(value)
+      Self::CleanAll(value) => Ok(!*value),
+      Self::Detailed { keep, .. } => match keep {
+        Some(keep) => keep.try_match(path).await,
+        None => Ok(false),
+      },
+    }
+  }
+
+  pub fn enabled(&self) -> bool {
+    !matches!(self, Self::CleanAll(false))
+  }
+
+  pub fn dry(&self) -> bool {
+    matches!(self, Self::Detailed { dry: true, .. })
+  }
+
+  pub fn emitted_only(&self) -> bool {
+    matches!(
+      self,
+      Self::Detailed {
+        emitted_only: true,
+        ..
       }
+    )
+  }
+
+  pub fn keep_matcher(&self) -> Option<&CleanKeep> {
+    match self {
+      Self::CleanAll(_) => None,
+      Self::Detailed { keep, .. } => keep.as_ref(),
     }
   }
 }
@@ -30,22 +109,54 @@ impl From<bool> for CleanOptions {
   }
 }
 
+impl From<CleanKeep> for CleanOptions {
+  fn from(value: CleanKeep) -> Self {
+    Self::Detailed {
+      keep: Some(value),
+      dry: false,
+      emitted_only: false,
+    }
+  }
+}
+
 impl From<&'_ str> for CleanOptions {
   fn from(value: &str) -> Self {
-    let pb = Utf8PathBuf::from_str(value).expect("should be a valid path");
-    Self::KeepPath(pb)
+    CleanKeep::from(value).into()
   }
 }
+
 impl From<&String> for CleanOptions {
   fn from(value: &String) -> Self {
-    let pb = Utf8PathBuf::from_str(value).expect("should be a valid path");
-    Self::KeepPath(pb)
+    CleanKeep::from(value.as_str()).into()
   }
 }
 
 impl From<String> for CleanOptions {
   fn from(value: String) -> Self {
-    let pb = Utf8PathBuf::from_str(&value).expect("should be a valid path");
-    Self::KeepPath(pb)
+    CleanKeep::from(value.as_str()).into()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_regex::RspackRegex;
+
+  use super::CleanKeep;
+
+  #[tokio::test]
+  async fn should_match_keep() {
+    let path = CleanKeep::from("ignored/dir");
+    assert!(matches!(path, CleanKeep::Path(_)));
+    assert!(path.try_match("ignored/dir/file.js").await.unwrap());
+    assert!(!path.try_match("this/is/ignored/dir/file.js").await.unwrap());
+
+    let glob = CleanKeep::from("./**/ignored/dir/**");
+    assert!(matches!(glob, CleanKeep::Glob(_)));
+    assert!(glob.try_match("this/is/ignored/dir/file.js").await.unwrap());
+    assert!(!glob.try_match("this/dir/file.js").await.unwrap());
+
+    let regex = CleanKeep::Regexp(RspackRegex::new(r"\.wasm$").unwrap());
+    assert!(regex.try_match("module.wasm").await.unwrap());
+    assert!(!regex.try_match("main.js").await.unwrap());
   }
 }
@@ -1,6 +1,8 @@
+use rspack_paths::Utf8PathBuf;
+
 use crate::{
-  CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
-  Resolve, SnapshotOptions, StatsOptions,
+  CacheOptions, Context, DiagnosticsOptions, Experiments, Mode, ModuleOptions, NodeOption,
+  Optimization, OutputOptions, Resolve, SnapshotOptions, StatsOptions,
 };
 
 #[derive(Debug)]
@@ -20,6 +22,9 @@ pub struct CompilerOptions {
   pub profile: bool,
   pub amd: Option<String>,
   pub bail: bool,
+  pub diagnostics: DiagnosticsOptions,
+  pub records_input_path: Option<Utf8PathBuf>,
+  pub records_output_path: Option<Utf8PathBuf>,
   pub __references: References,
 }
 
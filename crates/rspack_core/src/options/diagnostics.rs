@@ -0,0 +1,103 @@
+use rspack_error::{Diagnostic, DiagnosticCode, RspackSeverity};
+use rustc_hash::FxHashMap as HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+  Error,
+  Warning,
+  Advice,
+  Off,
+}
+
+impl DiagnosticLevel {
+  pub fn severity(&self) -> Option<RspackSeverity> {
+    match self {
+      DiagnosticLevel::Error => Some(RspackSeverity::Error),
+      DiagnosticLevel::Warning => Some(RspackSeverity::Warn),
+      DiagnosticLevel::Advice => Some(RspackSeverity::Advice),
+      DiagnosticLevel::Off => None,
+    }
+  }
+}
+
+/// Overrides the severity of the diagnostics by their stable codes.
+#[derive(Debug, Default)]
+pub struct DiagnosticsOptions {
+  levels: HashMap<&'static str, DiagnosticLevel>,
+}
+
+impl DiagnosticsOptions {
+  pub fn new(levels: impl IntoIterator<Item = (&'static DiagnosticCode, DiagnosticLevel)>) -> Self {
+    Self {
+      levels: levels
+        .into_iter()
+        .map(|(code, level)| (code.code(), level))
+        .collect(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.levels.is_empty()
+  }
+
+  /// Applies the configured level to the diagnostic,
+  /// returns `None` if the diagnostic is turned off.
+  pub fn apply(&self, diagnostic: Diagnostic) -> Option<Diagnostic> {
+    if self.levels.is_empty() {
+      return Some(diagnostic);
+    }
+    let Some(level) = diagnostic
+      .diagnostic_code()
+      .and_then(|code| self.levels.get(code.code()))
+    else {
+      return Some(diagnostic);
+    };
+    level
+      .severity()
+      .map(|severity| diagnostic.with_severity(severity))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_error::code::{CSS_CONFLICTING_ORDER, MODULE_NOT_FOUND};
+
+  use super::*;
+
+  #[test]
+  fn should_override_severity_by_code() {
+    let options = DiagnosticsOptions::new([
+      (&CSS_CONFLICTING_ORDER, DiagnosticLevel::Error),
+      (&MODULE_NOT_FOUND, DiagnosticLevel::Off),
+    ]);
+
+    let diagnostic = options
+      .apply(
+        Diagnostic::warn("Conflicting order".into(), "message".into())
+          .with_diagnostic_code(&CSS_CONFLICTING_ORDER),
+      )
+      .unwrap();
+    assert_eq!(diagnostic.severity(), RspackSeverity::Error);
+
+    assert!(options
+      .apply(
+        Diagnostic::error("ModuleNotFoundError".into(), "message".into())
+          .with_diagnostic_code(&MODULE_NOT_FOUND)
+      )
+      .is_none());
+
+    // the code is attached where the diagnostic is created, the title doesn't matter
+    let diagnostic = options
+      .apply(Diagnostic::warn(
+        "Conflicting order".into(),
+        "message".into(),
+      ))
+      .unwrap();
+    assert_eq!(diagnostic.severity(), RspackSeverity::Warn);
+
+    let diagnostic = options
+      .apply(Diagnostic::warn("Unknown".into(), "message".into()))
+      .unwrap();
+    assert_eq!(diagnostic.severity(), RspackSeverity::Warn);
+  }
+}
@@ -33,3 +33,5 @@ mod filename;
 pub use filename::*;
 mod clean_options;
 pub use clean_options::*;
+mod diagnostics;
+pub use diagnostics::*;
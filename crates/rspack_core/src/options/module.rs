@@ -7,6 +7,8 @@ use async_recursion::async_recursion;
 use bitflags::bitflags;
 use derivative::Derivative;
 use futures::future::BoxFuture;
+use json::JsonValue;
+use rspack_cacheable::{cacheable, with::Unsupported};
 use rspack_error::Result;
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
@@ -30,6 +32,7 @@ impl ParserOptionsMap {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub enum ParserOptions {
   Asset(AssetParserOptions),
@@ -40,6 +43,7 @@ pub enum ParserOptions {
   JavascriptAuto(JavascriptParserOptions),
   JavascriptEsm(JavascriptParserOptions),
   JavascriptDynamic(JavascriptParserOptions),
+  Json(JsonParserOptions),
   Unknown,
 }
 
@@ -67,8 +71,10 @@ impl ParserOptions {
     JavascriptDynamic,
     JavascriptParserOptions
   );
+  get_variant!(get_json, Json, JsonParserOptions);
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom)]
 pub enum DynamicImportMode {
   Lazy,
@@ -92,6 +98,7 @@ impl From<&str> for DynamicImportMode {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DynamicImportFetchPriority {
   Low,
@@ -120,6 +127,7 @@ impl fmt::Display for DynamicImportFetchPriority {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom)]
 pub enum JavascriptParserUrl {
   Enable,
@@ -137,6 +145,7 @@ impl From<&str> for JavascriptParserUrl {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom)]
 pub enum JavascriptParserOrder {
   Disable,
@@ -168,6 +177,7 @@ impl From<&str> for JavascriptParserOrder {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom)]
 pub enum ExportPresenceMode {
   None,
@@ -203,6 +213,7 @@ impl ExportPresenceMode {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, Copy, MergeFrom)]
 pub enum OverrideStrict {
   Strict,
@@ -219,6 +230,7 @@ impl From<&str> for OverrideStrict {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct JavascriptParserOptions {
   pub dynamic_import_mode: Option<DynamicImportMode>,
@@ -242,35 +254,126 @@ pub struct JavascriptParserOptions {
   pub import_dynamic: Option<bool>,
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetParserOptions {
   pub data_url_condition: Option<AssetParserDataUrl>,
 }
 
-#[derive(Debug, Clone, MergeFrom)]
+pub struct AssetParserDataUrlFnCtx {
+  pub filename: String,
+  pub context: String,
+}
+
+pub type AssetParserDataUrlFn =
+  Arc<dyn Fn(Vec<u8>, AssetParserDataUrlFnCtx) -> Result<bool> + Sync + Send>;
+
+#[cacheable]
 pub enum AssetParserDataUrl {
   Options(AssetParserDataUrlOptions),
-  // TODO: Function
+  Func(#[cacheable(with=Unsupported)] AssetParserDataUrlFn),
+}
+
+impl fmt::Debug for AssetParserDataUrl {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Options(i) => i.fmt(f),
+      Self::Func(_) => "Func(...)".fmt(f),
+    }
+  }
+}
+
+impl Clone for AssetParserDataUrl {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Options(i) => Self::Options(i.clone()),
+      Self::Func(i) => Self::Func(i.clone()),
+    }
+  }
+}
+
+impl MergeFrom for AssetParserDataUrl {
+  fn merge_from(self, other: &Self) -> Self {
+    match (self, other) {
+      (Self::Options(a), Self::Options(b)) => Self::Options(a.merge_from(b)),
+      (_, other) => other.clone(),
+    }
+  }
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetParserDataUrlOptions {
   pub max_size: Option<f64>,
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssAutoParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
 }
 
+#[cacheable]
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssModuleParserOptions {
   pub named_exports: Option<bool>,
+  pub ignore_order: Option<bool>,
+}
+
+pub type JsonParseFn = Arc<dyn Fn(String) -> Result<JsonValue> + Sync + Send>;
+
+/// How to turn the source of a `json` module into a json value
+#[cacheable]
+pub enum JsonParse {
+  Json,
+  Json5,
+  Toml,
+  Yaml,
+  Func(#[cacheable(with=Unsupported)] JsonParseFn),
+}
+
+impl fmt::Debug for JsonParse {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Json => "Json".fmt(f),
+      Self::Json5 => "Json5".fmt(f),
+      Self::Toml => "Toml".fmt(f),
+      Self::Yaml => "Yaml".fmt(f),
+      Self::Func(_) => "Func(...)".fmt(f),
+    }
+  }
+}
+
+impl Clone for JsonParse {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Json => Self::Json,
+      Self::Json5 => Self::Json5,
+      Self::Toml => Self::Toml,
+      Self::Yaml => Self::Yaml,
+      Self::Func(i) => Self::Func(i.clone()),
+    }
+  }
+}
+
+impl MergeFrom for JsonParse {
+  fn merge_from(self, other: &Self) -> Self {
+    other.clone()
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct JsonParserOptions {
+  pub parse: Option<JsonParse>,
 }
 
 #[derive(Debug)]
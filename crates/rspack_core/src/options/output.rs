@@ -115,8 +115,8 @@ impl From<ChunkLoading> for String {
   }
 }
 
-impl From<&ChunkLoading> for &str {
-  fn from(value: &ChunkLoading) -> Self {
+impl<'a> From<&'a ChunkLoading> for &'a str {
+  fn from(value: &'a ChunkLoading) -> Self {
     match value {
       ChunkLoading::Enable(ty) => ty.into(),
       ChunkLoading::Disable => "false",
@@ -131,7 +131,8 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// Chunk loading type provided by a plugin, the plugin should add the runtime modules for it
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -142,7 +143,7 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      custom => Self::Custom(custom.to_string()),
     }
   }
 }
@@ -153,14 +154,15 @@ impl From<ChunkLoadingType> for String {
   }
 }
 
-impl From<&ChunkLoadingType> for &str {
-  fn from(value: &ChunkLoadingType) -> Self {
+impl<'a> From<&'a ChunkLoadingType> for &'a str {
+  fn from(value: &'a ChunkLoadingType) -> Self {
     match value {
       ChunkLoadingType::Jsonp => "jsonp",
       ChunkLoadingType::ImportScripts => "import-scripts",
       ChunkLoadingType::Require => "require",
       ChunkLoadingType::AsyncNode => "async-node",
       ChunkLoadingType::Import => "import",
+      ChunkLoadingType::Custom(value) => value.as_str(),
     }
   }
 }
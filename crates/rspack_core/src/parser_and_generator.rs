@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 
 use derivative::Derivative;
+use rspack_cacheable::{cacheable_dyn, with::AsDynConverter};
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_loader_runner::{AdditionalData, ResourceData};
 use rspack_sources::BoxSource;
@@ -116,6 +117,27 @@ pub trait ParserAndGenerator: Send + Sync + Debug + AsAny {
   ) -> Result<()> {
     Ok(())
   }
+
+  /// Returns `Some` when the parser and generator can be saved to the persistent cache.
+  fn as_cacheable_parser_and_generator(
+    &self,
+  ) -> Option<&(dyn CacheableParserAndGenerator + 'static)> {
+    None
+  }
+}
+
+/// A [ParserAndGenerator] which can be saved to and restored from the persistent cache.
+#[cacheable_dyn]
+pub trait CacheableParserAndGenerator: ParserAndGenerator {}
+
+impl AsDynConverter for Box<dyn ParserAndGenerator> {
+  type Cacheable = dyn CacheableParserAndGenerator;
+  fn to_cacheable(&self) -> Option<&Self::Cacheable> {
+    self.as_cacheable_parser_and_generator()
+  }
+  fn from_cacheable(data: Box<Self::Cacheable>) -> Self {
+    data
+  }
 }
 
 impl dyn ParserAndGenerator + '_ {
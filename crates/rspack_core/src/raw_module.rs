@@ -124,7 +124,7 @@ impl Module for RawModule {
     })
   }
 
-  #[tracing::instrument(name = "RawModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "RawModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     _compilation: &crate::Compilation,
@@ -5,8 +5,9 @@ use std::{
 };
 
 use rspack_error::{
+  code::MODULE_NOT_FOUND,
   miette::{diagnostic, Diagnostic},
-  DiagnosticExt, Severity, TraceableError,
+  DiagnosticExt, MietteExt, Severity, TraceableError,
 };
 use rspack_fs::FileSystem;
 use rspack_loader_runner::DescriptionData;
@@ -309,7 +310,9 @@ fn map_resolver_error(
 
   let importer = args.importer;
   if importer.is_none() {
-    return diagnostic!("Module not found: Can't resolve '{request}' in '{context}'").boxed();
+    return diagnostic!("Module not found: Can't resolve '{request}' in '{context}'")
+      .boxed()
+      .with_diagnostic_code(&MODULE_NOT_FOUND);
   }
 
   let span = args.span.unwrap_or_default();
@@ -334,4 +337,5 @@ fn map_resolver_error(
     },
   )
   .boxed()
+  .with_diagnostic_code(&MODULE_NOT_FOUND)
 }
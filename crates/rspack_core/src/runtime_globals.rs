@@ -1,6 +1,7 @@
 use std::fmt;
 
 use bitflags::bitflags;
+use rkyv::{rancor::Fallible, rend::u128_le, Archive, Deserialize, Place, Serialize};
 use swc_core::ecma::atoms::Atom;
 
 bitflags! {
@@ -254,6 +255,11 @@ bitflags! {
     // amd module support
     const AMD_DEFINE = 1 << 67;
     const AMD_OPTIONS = 1 << 68;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 69;
   }
 }
 
@@ -270,6 +276,28 @@ impl Default for RuntimeGlobals {
   }
 }
 
+// RuntimeGlobals is archived as its bits, so dependencies carrying it can be persisted
+impl Archive for RuntimeGlobals {
+  type Archived = u128_le;
+  type Resolver = ();
+
+  fn resolve(&self, _resolver: Self::Resolver, out: Place<Self::Archived>) {
+    out.write(u128_le::from_native(self.bits()));
+  }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for RuntimeGlobals {
+  fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<RuntimeGlobals, D> for u128_le {
+  fn deserialize(&self, _deserializer: &mut D) -> Result<RuntimeGlobals, D::Error> {
+    Ok(RuntimeGlobals::from_bits_retain(self.to_native()))
+  }
+}
+
 impl RuntimeGlobals {
   pub const fn name(&self) -> &'static str {
     use RuntimeGlobals as R;
@@ -306,6 +334,7 @@ impl RuntimeGlobals {
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
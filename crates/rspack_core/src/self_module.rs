@@ -125,7 +125,7 @@ impl Module for SelfModule {
     })
   }
 
-  #[tracing::instrument(name = "SelfModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "SelfModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     _compilation: &Compilation,
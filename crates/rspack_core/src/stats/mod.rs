@@ -8,7 +8,7 @@ use rspack_collections::{DatabaseItem, IdentifierSet};
 use rspack_error::emitter::{
   DiagnosticDisplay, DiagnosticDisplayer, StdioDiagnosticDisplay, StringDiagnosticDisplay,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rustc_hash::FxHashMap as HashMap;
 
 mod utils;
@@ -34,15 +34,17 @@ impl<'compilation> Stats<'compilation> {
 
   pub fn emit_diagnostics(&self) -> Result<()> {
     let mut displayer = StdioDiagnosticDisplay::default();
+    displayer.emit_batch_diagnostic(self.compilation.get_advices())?;
     displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
     displayer.emit_batch_diagnostic(self.compilation.get_errors())
   }
 
   pub fn emit_diagnostics_string(&self, sorted: bool) -> Result<String> {
     let mut displayer = StringDiagnosticDisplay::default().with_sorted(sorted);
+    let advices = displayer.emit_batch_diagnostic(self.compilation.get_advices())?;
     let warnings = displayer.emit_batch_diagnostic(self.compilation.get_warnings())?;
     let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
-    Ok(format!("{warnings}{errors}"))
+    Ok(format!("{advices}{warnings}{errors}"))
   }
 }
 
@@ -577,6 +579,7 @@ impl Stats<'_> {
           message: diagnostic_displayer
             .emit_diagnostic(d)
             .expect("should print diagnostics"),
+          code: d.diagnostic_code().map(|code| code.code()),
           module_identifier,
           module_name,
           module_id: module_id.flatten(),
@@ -596,10 +599,20 @@ impl Stats<'_> {
   }
 
   pub fn get_warnings(&self) -> Vec<StatsWarning> {
+    self.get_stats_warnings(self.compilation.get_warnings_sorted())
+  }
+
+  /// Advices are neither errors nor warnings, they are reported in the same shape as warnings.
+  pub fn get_advices(&self) -> Vec<StatsWarning> {
+    self.get_stats_warnings(self.compilation.get_advices_sorted())
+  }
+
+  fn get_stats_warnings<'a>(
+    &'a self,
+    diagnostics: impl Iterator<Item = &'a Diagnostic>,
+  ) -> Vec<StatsWarning<'a>> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
-    self
-      .compilation
-      .get_warnings_sorted()
+    diagnostics
       .map(|d| {
         let module_identifier = d.module_identifier();
         let (module_name, module_id) = module_identifier
@@ -628,6 +641,7 @@ impl Stats<'_> {
           message: diagnostic_displayer
             .emit_diagnostic(d)
             .expect("should print diagnostics"),
+          code: d.diagnostic_code().map(|code| code.code()),
           module_identifier,
           module_name,
           module_id: module_id.flatten(),
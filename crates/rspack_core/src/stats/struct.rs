@@ -41,6 +41,8 @@ pub struct ExtendedStatsOptions {
 #[derive(Debug)]
 pub struct StatsError<'s> {
   pub message: String,
+  /// The stable code of the diagnostic, such as `RSPACK_E0001`
+  pub code: Option<&'static str>,
   pub module_identifier: Option<ModuleIdentifier>,
   pub module_name: Option<Cow<'s, str>>,
   pub module_id: Option<&'s str>,
@@ -59,6 +61,8 @@ pub struct StatsError<'s> {
 #[derive(Debug)]
 pub struct StatsWarning<'s> {
   pub message: String,
+  /// The stable code of the diagnostic, such as `RSPACK_E0001`
+  pub code: Option<&'static str>,
   pub module_identifier: Option<ModuleIdentifier>,
   pub module_name: Option<Cow<'s, str>>,
   pub module_id: Option<&'s str>,
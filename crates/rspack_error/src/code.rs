@@ -0,0 +1,159 @@
+use cow_utils::CowUtils;
+
+const DIAGNOSTIC_CODES_URL: &str = "https://rspack.dev/config/other-options";
+
+/// A stable code of a built-in diagnostic kind, such as `RSPACK_E0001`.
+///
+/// Unlike the title of a diagnostic, which may be reworded between versions,
+/// the code is kept stable and can be used to configure the severity of diagnostics.
+///
+/// The code is attached where a diagnostic of the kind is created, see
+/// [crate::Diagnostic::with_diagnostic_code] and [crate::MietteExt::with_diagnostic_code].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode {
+  code: &'static str,
+  help: Option<&'static str>,
+}
+
+impl DiagnosticCode {
+  pub fn code(&self) -> &'static str {
+    self.code
+  }
+
+  pub fn help(&self) -> Option<&'static str> {
+    self.help
+  }
+
+  pub fn url(&self) -> String {
+    format!(
+      "{DIAGNOSTIC_CODES_URL}#{}",
+      self.code.cow_to_ascii_lowercase()
+    )
+  }
+
+  /// Find a registered code, the comparison is case insensitive.
+  pub fn from_code(code: &str) -> Option<&'static DiagnosticCode> {
+    DIAGNOSTIC_CODES
+      .iter()
+      .find(|c| c.code.eq_ignore_ascii_case(code))
+      .copied()
+  }
+
+  pub fn all() -> &'static [&'static DiagnosticCode] {
+    DIAGNOSTIC_CODES
+  }
+
+  /// Get the code attached to a miette diagnostic by [crate::MietteExt::with_diagnostic_code].
+  pub fn of_diagnostic(
+    diagnostic: &(dyn miette::Diagnostic + Send + Sync + 'static),
+  ) -> Option<&'static DiagnosticCode> {
+    crate::miette_helpers::attached_diagnostic_code(diagnostic)
+  }
+}
+
+/// Look through the wrapper added by [crate::MietteExt::with_diagnostic_code],
+/// so the original diagnostic can be downcasted.
+pub fn without_diagnostic_code<'a>(
+  diagnostic: &'a (dyn miette::Diagnostic + Send + Sync + 'static),
+) -> &'a (dyn miette::Diagnostic + Send + Sync + 'static) {
+  crate::miette_helpers::without_diagnostic_code(diagnostic)
+}
+
+impl std::fmt::Display for DiagnosticCode {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.code)
+  }
+}
+
+macro_rules! define_diagnostic_codes {
+  ($($name:ident = $code:literal, $help:expr;)*) => {
+    $(
+      pub static $name: DiagnosticCode = DiagnosticCode {
+        code: $code,
+        help: $help,
+      };
+    )*
+
+    static DIAGNOSTIC_CODES: &[&DiagnosticCode] = &[$(&$name),*];
+  };
+}
+
+// Codes are append only, NEVER reuse or renumber a code once it's released.
+define_diagnostic_codes! {
+  MODULE_NOT_FOUND = "RSPACK_E0001",
+    Some("Check the request, `resolve.alias` and `resolve.extensions`, or install the missing package.");
+  MODULE_BUILD_FAILED = "RSPACK_E0002",
+    Some("A loader failed to process the module, see the error of the loader for details.");
+  MODULE_PARSE_FAILED = "RSPACK_E0003",
+    Some("The module may need an appropriate loader to handle its file type.");
+  MODULE_PARSE_WARNING = "RSPACK_E0004",
+    None;
+  MODULE_ERROR = "RSPACK_E0005",
+    None;
+  MODULE_WARNING = "RSPACK_E0006",
+    None;
+  EMPTY_DEPENDENCY = "RSPACK_E0007",
+    None;
+  CRITICAL_DEPENDENCY = "RSPACK_E0008",
+    Some("The request of the dependency can't be statically analyzed, consider using a static request.");
+  EXPORT_NOT_FOUND = "RSPACK_E0009",
+    Some("Check the exports of the imported module, or configure `module.parser.javascript.exportsPresence`.");
+  UNSUPPORTED_FEATURE = "RSPACK_E0010",
+    None;
+  MAGIC_COMMENTS = "RSPACK_E0011",
+    None;
+  CSS_CONFLICTING_ORDER = "RSPACK_E0012",
+    Some("Import the css modules in a consistent order, or set `module.parser.css.ignoreOrder` to disable the check.");
+  ASSETS_OVER_SIZE_LIMIT = "RSPACK_E0013",
+    Some("Consider reducing the size of the assets, or adjust `performance.maxAssetSize`.");
+  ENTRYPOINTS_OVER_SIZE_LIMIT = "RSPACK_E0014",
+    Some("Consider splitting the entrypoints, or adjust `performance.maxEntrypointSize`.");
+  NO_ASYNC_CHUNKS = "RSPACK_E0015",
+    Some("Consider lazy loading with `import()` to split the application into smaller chunks.");
+  ASYNC_DEPENDENCY_TO_INITIAL_CHUNK = "RSPACK_E0016",
+    None;
+  CASE_SENSITIVE_MODULES = "RSPACK_E0017",
+    Some("Use the same casing for every request of a module.");
+  WASM_IN_INITIAL_CHUNK = "RSPACK_E0018",
+    Some("Import the WebAssembly module with `import()` so it's loaded asynchronously.");
+  WASM_PARSE_FAILED = "RSPACK_E0019",
+    None;
+  COPY_FAILED = "RSPACK_E0020",
+    None;
+  CSS_MINIMIZE_WARNING = "RSPACK_E0021",
+    None;
+  SHARED_MODULE_VERSION = "RSPACK_E0022",
+    Some("Specify the version in the shared config, or add a version to the description file of the package.");
+  SWC_LOADER_OPTIONS = "RSPACK_E0023",
+    None;
+  CONFLICTING_STAR_EXPORTS = "RSPACK_E0024",
+    Some("Re-export the conflicting name explicitly to choose the module providing it.");
+  HTML_SCRIPT_LOADING_CONFLICT = "RSPACK_E0025",
+    Some("Remove `scriptLoading` or set it to 'module' when `output.scriptType` is 'module'.");
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_have_unique_codes() {
+    let mut codes = DIAGNOSTIC_CODES.iter().map(|c| c.code).collect::<Vec<_>>();
+    codes.sort_unstable();
+    codes.dedup();
+    assert_eq!(codes.len(), DIAGNOSTIC_CODES.len());
+  }
+
+  #[test]
+  fn should_find_codes() {
+    assert_eq!(
+      DiagnosticCode::from_code("rspack_e0012"),
+      Some(&CSS_CONFLICTING_ORDER)
+    );
+    assert_eq!(
+      CSS_CONFLICTING_ORDER.url(),
+      "https://rspack.dev/config/other-options#rspack_e0012"
+    );
+    assert_eq!(DiagnosticCode::from_code("RSPACK_E9999"), None);
+  }
+}
@@ -4,15 +4,22 @@ use cow_utils::CowUtils;
 use miette::{GraphicalTheme, IntoDiagnostic, MietteDiagnostic};
 use rspack_collections::Identifier;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
 use swc_core::common::{SourceMap, Span};
 
-use crate::{graphical::GraphicalReportHandler, Error};
+use crate::{
+  code::DiagnosticCode, graphical::GraphicalReportHandler,
+  miette_helpers::attached_diagnostic_code_of_error, Error,
+};
 
-#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RspackSeverity {
   #[default]
   Error,
+  #[serde(rename = "warning")]
   Warn,
+  Advice,
 }
 
 pub type Severity = RspackSeverity;
@@ -22,6 +29,7 @@ impl From<RspackSeverity> for miette::Severity {
     match value {
       RspackSeverity::Error => miette::Severity::Error,
       RspackSeverity::Warn => miette::Severity::Warning,
+      RspackSeverity::Advice => miette::Severity::Advice,
     }
   }
 }
@@ -31,7 +39,7 @@ impl From<miette::Severity> for RspackSeverity {
     match value {
       miette::Severity::Error => RspackSeverity::Error,
       miette::Severity::Warning => RspackSeverity::Warn,
-      miette::Severity::Advice => unimplemented!("Not supported miette severity"),
+      miette::Severity::Advice => RspackSeverity::Advice,
     }
   }
 }
@@ -41,6 +49,7 @@ impl From<&str> for RspackSeverity {
     let s = value.cow_to_ascii_lowercase();
     match s.as_ref() {
       "warning" => RspackSeverity::Warn,
+      "advice" | "info" => RspackSeverity::Advice,
       _ => RspackSeverity::Error,
     }
   }
@@ -54,6 +63,7 @@ impl fmt::Display for RspackSeverity {
       match self {
         RspackSeverity::Error => "error",
         RspackSeverity::Warn => "warning",
+        RspackSeverity::Advice => "advice",
       }
     )
   }
@@ -98,6 +108,8 @@ pub struct Diagnostic {
   hide_stack: Option<bool>,
   chunk: Option<u32>,
   stack: Option<String>,
+  code: Option<&'static DiagnosticCode>,
+  severity: Option<Severity>,
 }
 
 impl From<Box<dyn miette::Diagnostic + Send + Sync>> for Diagnostic {
@@ -108,6 +120,7 @@ impl From<Box<dyn miette::Diagnostic + Send + Sync>> for Diagnostic {
 
 impl From<miette::Error> for Diagnostic {
   fn from(value: miette::Error) -> Self {
+    let code = attached_diagnostic_code_of_error(&value);
     Self {
       inner: Arc::new(value),
       module_identifier: None,
@@ -116,6 +129,8 @@ impl From<miette::Error> for Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code,
+      severity: None,
     }
   }
 }
@@ -143,6 +158,8 @@ impl Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code: None,
+      severity: None,
     }
   }
 
@@ -160,6 +177,8 @@ impl Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code: None,
+      severity: None,
     }
   }
 }
@@ -167,7 +186,7 @@ impl Diagnostic {
 impl Diagnostic {
   pub fn render_report(&self, colored: bool) -> crate::Result<String> {
     let mut buf = String::new();
-    let h = GraphicalReportHandler::new()
+    let mut h = GraphicalReportHandler::new()
       .with_theme(if colored {
         GraphicalTheme::unicode()
       } else {
@@ -175,7 +194,22 @@ impl Diagnostic {
       })
       .with_context_lines(2)
       .with_width(usize::MAX);
-    h.render_report(&mut buf, self.as_ref()).into_diagnostic()?;
+    // so the diagnostic can be looked up by its code in the documentation
+    if let Some(code) = self.code {
+      h = h.with_footer(match code.help() {
+        Some(help) => format!("{code}: {help} See {}", code.url()),
+        None => format!("{code}: See {}", code.url()),
+      });
+    }
+    if let Some(severity) = self.severity {
+      let diagnostic = WithSeverity {
+        inner: self.as_ref(),
+        severity: severity.into(),
+      };
+      h.render_report(&mut buf, &diagnostic).into_diagnostic()?;
+    } else {
+      h.render_report(&mut buf, self.as_ref()).into_diagnostic()?;
+    }
     Ok(buf)
   }
 
@@ -184,7 +218,25 @@ impl Diagnostic {
   }
 
   pub fn severity(&self) -> Severity {
-    self.inner.severity().unwrap_or_default().into()
+    self
+      .severity
+      .unwrap_or_else(|| self.inner.severity().unwrap_or_default().into())
+  }
+
+  /// Override the severity of the diagnostic, the severity of the inner error is kept as is.
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = Some(severity);
+    self
+  }
+
+  /// The stable code of the diagnostic, which is attached where the diagnostic is created.
+  pub fn diagnostic_code(&self) -> Option<&'static DiagnosticCode> {
+    self.code
+  }
+
+  pub fn with_diagnostic_code(mut self, code: &'static DiagnosticCode) -> Self {
+    self.code = Some(code);
+    self
   }
 
   pub fn module_identifier(&self) -> Option<Identifier> {
@@ -252,6 +304,57 @@ impl Diagnostic {
   }
 }
 
+/// Renders a diagnostic with an overridden severity.
+struct WithSeverity<'a> {
+  inner: &'a (dyn miette::Diagnostic + Send + Sync + 'static),
+  severity: miette::Severity,
+}
+
+impl fmt::Debug for WithSeverity<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(self.inner, f)
+  }
+}
+
+impl fmt::Display for WithSeverity<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self.inner, f)
+  }
+}
+
+impl std::error::Error for WithSeverity<'_> {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    self.inner.source()
+  }
+}
+
+impl miette::Diagnostic for WithSeverity<'_> {
+  fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    self.inner.code()
+  }
+  fn severity(&self) -> Option<miette::Severity> {
+    Some(self.severity)
+  }
+  fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    self.inner.help()
+  }
+  fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    self.inner.url()
+  }
+  fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+    self.inner.source_code()
+  }
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+    self.inner.labels()
+  }
+  fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+    self.inner.related()
+  }
+  fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
+    self.inner.diagnostic_source()
+  }
+}
+
 pub trait Diagnosable {
   fn add_diagnostic(&self, _diagnostic: Diagnostic) {
     unimplemented!("`<T as Diagnosable>::add_diagnostic` is not implemented")
@@ -299,3 +402,71 @@ macro_rules! impl_empty_diagnosable_trait {
 pub fn errors_to_diagnostics(errs: Vec<Error>) -> Vec<Diagnostic> {
   errs.into_iter().map(Diagnostic::from).collect()
 }
+
+#[cfg(test)]
+mod test {
+  use miette::miette;
+
+  use super::*;
+  use crate::{
+    code::{CRITICAL_DEPENDENCY, MODULE_NOT_FOUND, MODULE_WARNING},
+    DiagnosticExt, MietteExt, TraceableError,
+  };
+
+  #[test]
+  fn should_keep_attached_code() {
+    let diagnostic: Diagnostic = TraceableError::from_lazy_file(
+      0,
+      1,
+      "Critical dependency".into(),
+      "the request of a dependency is an expression".into(),
+    )
+    .boxed()
+    .with_diagnostic_code(&CRITICAL_DEPENDENCY)
+    .into();
+    assert_eq!(diagnostic.diagnostic_code(), Some(&CRITICAL_DEPENDENCY));
+
+    // the helpers wrapping the diagnostic afterwards keep the code
+    let diagnostic: Diagnostic = miette!("Module not found: Can't resolve './a'")
+      .with_diagnostic_code(&MODULE_NOT_FOUND)
+      .with_help("Did you mean './a.js'?")
+      .into();
+    assert_eq!(diagnostic.diagnostic_code(), Some(&MODULE_NOT_FOUND));
+    assert_eq!(
+      diagnostic.message(),
+      "Module not found: Can't resolve './a'"
+    );
+
+    let diagnostic = Diagnostic::from(miette::Error::new(TraceableError::from_lazy_file(
+      0,
+      1,
+      "Module not found".into(),
+      "message".into(),
+    )));
+    assert_eq!(diagnostic.diagnostic_code(), None);
+  }
+
+  #[test]
+  fn should_render_code() {
+    let diagnostic: Diagnostic = miette!("Module not found: Can't resolve './a'")
+      .with_diagnostic_code(&MODULE_NOT_FOUND)
+      .into();
+    assert_eq!(
+      diagnostic.render_report(false).unwrap(),
+      "  × Module not found: Can't resolve './a'\n\n  RSPACK_E0001: Check the request, `resolve.alias` and `resolve.extensions`, or install the missing package. See https://rspack.dev/config/other-options#rspack_e0001\n"
+    );
+
+    let diagnostic = Diagnostic::warn("ModuleWarning".into(), "Something is off".into())
+      .with_diagnostic_code(&MODULE_WARNING);
+    assert_eq!(
+      diagnostic.render_report(false).unwrap(),
+      "  ⚠ Something is off\n\n  RSPACK_E0006: See https://rspack.dev/config/other-options#rspack_e0006\n"
+    );
+
+    let diagnostic = Diagnostic::warn("ModuleWarning".into(), "Without code".into());
+    assert_eq!(
+      diagnostic.render_report(false).unwrap(),
+      "  ⚠ Without code\n"
+    );
+  }
+}
@@ -1,11 +1,15 @@
-use std::io::Write;
+use std::{io::Write, path::Path};
 
 use anyhow::Context;
+use cow_utils::CowUtils;
 use miette::IntoDiagnostic;
+use serde::Serialize;
+use serde_json::json;
 use termcolor::{Buffer, ColorSpec, StandardStreamLock, WriteColor};
 use termcolor::{ColorChoice, StandardStream};
+use url::Url;
 
-use crate::Diagnostic;
+use crate::{Diagnostic, Severity};
 
 pub trait FlushDiagnostic {
   fn flush_diagnostic(&mut self) {}
@@ -200,3 +204,401 @@ impl DiagnosticDisplay for DiagnosticDisplayer {
     }
   }
 }
+
+/// The serializable shape of a [Diagnostic], emitted by the machine-readable displayers
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedDiagnostic {
+  pub severity: Severity,
+  /// The stable code of the diagnostic, such as `RSPACK_E0001`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub loc: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module_identifier: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub chunk: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub details: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+}
+
+impl From<&Diagnostic> for SerializedDiagnostic {
+  fn from(diagnostic: &Diagnostic) -> Self {
+    let code = diagnostic.diagnostic_code();
+    Self {
+      severity: diagnostic.severity(),
+      code: code.map(|code| code.code().to_string()),
+      name: diagnostic.code().map(|name| name.to_string()),
+      message: diagnostic.message(),
+      file: diagnostic.file().map(|file| file.to_string()),
+      loc: diagnostic.loc(),
+      module_identifier: diagnostic.module_identifier().map(|id| id.to_string()),
+      chunk: diagnostic.chunk(),
+      details: diagnostic.details(),
+      help: code.and_then(|code| code.help()).map(Into::into),
+      url: code.map(|code| code.url()),
+    }
+  }
+}
+
+/// Emits every diagnostic as a line of json, a.k.a. newline-delimited json
+#[derive(Debug, Default, Clone)]
+pub struct JsonDiagnosticDisplay;
+
+impl DiagnosticDisplay for JsonDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let mut buf = String::new();
+    for diagnostic in diagnostics {
+      buf.push_str(&self.emit_diagnostic(diagnostic)?);
+    }
+    Ok(buf)
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    let mut line =
+      serde_json::to_string(&SerializedDiagnostic::from(diagnostic)).into_diagnostic()?;
+    line.push('\n');
+    Ok(line)
+  }
+}
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Emits the diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log,
+/// which contains a single run of the tool.
+#[derive(Debug, Clone)]
+pub struct SarifDiagnosticDisplay {
+  tool_name: String,
+  tool_version: Option<String>,
+}
+
+impl Default for SarifDiagnosticDisplay {
+  fn default() -> Self {
+    Self {
+      tool_name: "rspack".to_string(),
+      tool_version: None,
+    }
+  }
+}
+
+impl SarifDiagnosticDisplay {
+  pub fn with_tool(self, name: String, version: Option<String>) -> Self {
+    Self {
+      tool_name: name,
+      tool_version: version,
+    }
+  }
+
+  fn render_log(&self, diagnostics: Vec<SerializedDiagnostic>) -> crate::Result<String> {
+    let mut rule_diagnostics = diagnostics
+      .iter()
+      .filter_map(|d| sarif_rule_id(d).map(|id| (id, d)))
+      .collect::<Vec<_>>();
+    rule_diagnostics.sort_unstable_by_key(|(id, _)| *id);
+    rule_diagnostics.dedup_by_key(|(id, _)| *id);
+    let rules = rule_diagnostics
+      .into_iter()
+      .map(|(id, diagnostic)| {
+        let mut rule = json!({ "id": id });
+        if diagnostic.code.is_some()
+          && let Some(name) = &diagnostic.name
+        {
+          rule["name"] = json!(name);
+        }
+        if let Some(help) = &diagnostic.help {
+          rule["help"] = json!({ "text": help });
+        }
+        if let Some(url) = &diagnostic.url {
+          rule["helpUri"] = json!(url);
+        }
+        rule
+      })
+      .collect::<Vec<_>>();
+
+    let mut driver = json!({
+      "name": self.tool_name,
+      "rules": rules,
+    });
+    if let Some(version) = &self.tool_version {
+      driver["version"] = json!(version);
+    }
+
+    let results = diagnostics.iter().map(sarif_result).collect::<Vec<_>>();
+    let log = json!({
+      "$schema": SARIF_SCHEMA,
+      "version": SARIF_VERSION,
+      "runs": [{
+        "tool": { "driver": driver },
+        "results": results,
+      }],
+    });
+    serde_json::to_string_pretty(&log).into_diagnostic()
+  }
+}
+
+impl DiagnosticDisplay for SarifDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    self.render_log(diagnostics.map(SerializedDiagnostic::from).collect())
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    self.render_log(vec![SerializedDiagnostic::from(diagnostic)])
+  }
+}
+
+fn sarif_result(diagnostic: &SerializedDiagnostic) -> serde_json::Value {
+  let mut result = json!({
+    "level": match diagnostic.severity {
+      Severity::Error => "error",
+      Severity::Warn => "warning",
+      Severity::Advice => "note",
+    },
+    "message": { "text": diagnostic.message },
+  });
+  if let Some(rule_id) = sarif_rule_id(diagnostic) {
+    result["ruleId"] = json!(rule_id);
+  }
+
+  let mut location = serde_json::Map::new();
+  if let Some(file) = &diagnostic.file {
+    let mut physical_location = json!({
+      "artifactLocation": { "uri": sarif_uri(file) },
+    });
+    if let Some(region) = diagnostic.loc.as_deref().and_then(sarif_region) {
+      physical_location["region"] = region;
+    }
+    location.insert("physicalLocation".into(), physical_location);
+  }
+  if let Some(module_identifier) = &diagnostic.module_identifier {
+    location.insert(
+      "logicalLocations".into(),
+      json!([{ "fullyQualifiedName": module_identifier, "kind": "module" }]),
+    );
+  }
+  if !location.is_empty() {
+    result["locations"] = json!([location]);
+  }
+
+  let mut properties = serde_json::Map::new();
+  if let Some(chunk) = diagnostic.chunk {
+    properties.insert("chunk".into(), json!(chunk));
+  }
+  if let Some(details) = &diagnostic.details {
+    properties.insert("details".into(), json!(details));
+  }
+  if !properties.is_empty() {
+    result["properties"] = properties.into();
+  }
+  result
+}
+
+/// The stable code is preferred as the rule id, the title is used for the diagnostics without a code.
+fn sarif_rule_id(diagnostic: &SerializedDiagnostic) -> Option<&str> {
+  diagnostic.code.as_deref().or(diagnostic.name.as_deref())
+}
+
+/// Converts a file path to an uri of the SARIF artifact location,
+/// absolute paths become `file://` uris and relative paths are kept as uri references.
+fn sarif_uri(file: &str) -> String {
+  let path = Path::new(file);
+  if path.is_absolute()
+    && let Ok(url) = Url::from_file_path(path)
+  {
+    return url.into();
+  }
+  let normalized = file.cow_replace('\\', "/");
+  let segments = normalized
+    .trim_start_matches('/')
+    .split('/')
+    .map(encode_path_segment)
+    .collect::<Vec<_>>()
+    .join("/");
+  // the absolute paths of another platform, such as `C:/path/to/file` on unix
+  if normalized.starts_with('/') || normalized.as_bytes().get(1) == Some(&b':') {
+    format!("file:///{segments}")
+  } else {
+    segments
+  }
+}
+
+/// Percent-encodes a path segment, such as `#`, `%` and non-ASCII characters,
+/// the `.` and `..` segments are kept as is.
+fn encode_path_segment(segment: &str) -> String {
+  if matches!(segment, "." | "..") {
+    return segment.to_string();
+  }
+  let mut url = Url::parse("file:///").expect("should be a valid url");
+  url
+    .path_segments_mut()
+    .expect("file url should have path segments")
+    .pop_if_empty()
+    .push(segment);
+  url.path()[1..].to_string()
+}
+
+/// Converts the `loc` of a diagnostic to a SARIF region, the formats of `loc` are
+/// `line:column`, `line:column-endColumn` and `line:column-endLine:endColumn`,
+/// where the columns are 0-based and the SARIF columns are 1-based.
+fn sarif_region(loc: &str) -> Option<serde_json::Value> {
+  fn parse_position(pos: &str) -> Option<(usize, usize)> {
+    let (line, column) = pos.split_once(':')?;
+    Some((line.parse().ok()?, column.parse().ok()?))
+  }
+
+  let (start, end) = match loc.split_once('-') {
+    Some((start, end)) => (start, Some(end)),
+    None => (loc, None),
+  };
+  let (start_line, start_column) = parse_position(start)?;
+  let mut region = json!({
+    "startLine": start_line,
+    "startColumn": start_column + 1,
+  });
+  if let Some(end) = end {
+    let (end_line, end_column) = match parse_position(end) {
+      Some(pos) => pos,
+      None => (start_line, end.parse().ok()?),
+    };
+    region["endLine"] = json!(end_line);
+    region["endColumn"] = json!(end_column + 1);
+  }
+  Some(region)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::code::{MODULE_PARSE_FAILED, MODULE_WARNING};
+
+  fn diagnostics() -> Vec<Diagnostic> {
+    vec![
+      Diagnostic::error("ModuleParseError".into(), "Unexpected token".into())
+        .with_diagnostic_code(&MODULE_PARSE_FAILED)
+        .with_file(Some("/project/src/index.js".into()))
+        .with_loc(Some("1:4-2:0".into()))
+        .with_module_identifier(Some("javascript/auto|/project/src/index.js".into())),
+      Diagnostic::warn("ModuleWarning".into(), "Something is off".into())
+        .with_diagnostic_code(&MODULE_WARNING)
+        .with_file(Some("src/a b.js".into()))
+        .with_loc(Some("3:2-8".into()))
+        .with_chunk(Some(1)),
+      Diagnostic::warn("ModuleWarning".into(), "Without location".into())
+        .with_diagnostic_code(&MODULE_WARNING)
+        .with_severity(Severity::Advice),
+      Diagnostic::warn("Custom".into(), "Without code".into()),
+    ]
+  }
+
+  #[test]
+  fn should_emit_json_lines() {
+    let output = JsonDiagnosticDisplay
+      .emit_batch_diagnostic(diagnostics().iter())
+      .unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+      lines[0],
+      r#"{"severity":"error","code":"RSPACK_E0003","name":"ModuleParseError","message":"Unexpected token","file":"/project/src/index.js","loc":"1:4-2:0","moduleIdentifier":"javascript/auto|/project/src/index.js","help":"The module may need an appropriate loader to handle its file type.","url":"https://rspack.dev/config/other-options#rspack_e0003"}"#
+    );
+    assert_eq!(
+      lines[2],
+      r#"{"severity":"advice","code":"RSPACK_E0006","name":"ModuleWarning","message":"Without location","url":"https://rspack.dev/config/other-options#rspack_e0006"}"#
+    );
+  }
+
+  #[test]
+  fn should_emit_sarif_log() {
+    let output = SarifDiagnosticDisplay::default()
+      .with_tool("rspack".into(), Some("1.0.0".into()))
+      .emit_batch_diagnostic(diagnostics().iter())
+      .unwrap();
+    let log: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["version"], "1.0.0");
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([
+        { "id": "Custom" },
+        {
+          "id": "RSPACK_E0003",
+          "name": "ModuleParseError",
+          "help": { "text": "The module may need an appropriate loader to handle its file type." },
+          "helpUri": "https://rspack.dev/config/other-options#rspack_e0003",
+        },
+        {
+          "id": "RSPACK_E0006",
+          "name": "ModuleWarning",
+          "helpUri": "https://rspack.dev/config/other-options#rspack_e0006",
+        },
+      ])
+    );
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0]["level"], "error");
+    assert_eq!(results[0]["ruleId"], "RSPACK_E0003");
+    assert_eq!(
+      results[0]["locations"][0]["physicalLocation"],
+      json!({
+        "artifactLocation": { "uri": "file:///project/src/index.js" },
+        "region": { "startLine": 1, "startColumn": 5, "endLine": 2, "endColumn": 1 },
+      })
+    );
+    assert_eq!(
+      results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+      "javascript/auto|/project/src/index.js"
+    );
+    assert_eq!(results[1]["level"], "warning");
+    assert_eq!(
+      results[1]["locations"][0]["physicalLocation"],
+      json!({
+        "artifactLocation": { "uri": "src/a%20b.js" },
+        "region": { "startLine": 3, "startColumn": 3, "endLine": 3, "endColumn": 9 },
+      })
+    );
+    assert_eq!(results[1]["properties"]["chunk"], 1);
+    assert_eq!(results[2]["level"], "note");
+    assert!(results[2].get("locations").is_none());
+    assert_eq!(results[3]["ruleId"], "Custom");
+  }
+
+  #[test]
+  fn should_encode_sarif_uri() {
+    assert_eq!(sarif_uri("src/a b#c%d.js"), "src/a%20b%23c%25d.js");
+    assert_eq!(sarif_uri("src/中文?.js"), "src/%E4%B8%AD%E6%96%87%3F.js");
+    assert_eq!(sarif_uri("src\\a b.js"), "src/a%20b.js");
+    assert_eq!(sarif_uri("../src/./a#b.js"), "../src/./a%23b.js");
+    assert_eq!(
+      sarif_uri("C:\\project\\a#b\\中文.js"),
+      "file:///C:/project/a%23b/%E4%B8%AD%E6%96%87.js"
+    );
+    #[cfg(not(windows))]
+    assert_eq!(
+      sarif_uri("/project/a b#c%d/中文.js"),
+      "file:///project/a%20b%23c%25d/%E4%B8%AD%E6%96%87.js"
+    );
+  }
+}
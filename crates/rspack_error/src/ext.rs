@@ -2,7 +2,10 @@ use std::{borrow::Cow, error::Error};
 
 use miette::Diagnostic;
 
-use crate::miette_helpers::{WithHelp, WithLabel};
+use crate::{
+  miette_helpers::{WithDiagnosticCode, WithHelp, WithLabel},
+  DiagnosticCode,
+};
 
 /// Useful to convert [std::error::Error] to [crate::DiagnosticError]
 pub trait ErrorExt {
@@ -31,6 +34,10 @@ pub trait MietteExt {
     self,
     labels: impl Iterator<Item = miette::LabeledSpan>,
   ) -> Box<dyn Diagnostic + Send + Sync>;
+  /// Attach a stable code to the diagnostic, which is kept when the diagnostic
+  /// is converted into a [crate::Diagnostic].
+  fn with_diagnostic_code(self, code: &'static DiagnosticCode)
+    -> Box<dyn Diagnostic + Send + Sync>;
 }
 
 impl MietteExt for Box<dyn Diagnostic + Send + Sync> {
@@ -45,6 +52,13 @@ impl MietteExt for Box<dyn Diagnostic + Send + Sync> {
     let l = WithLabel::from(self).with_label(labels);
     <WithLabel as DiagnosticExt>::boxed(l)
   }
+  fn with_diagnostic_code(
+    self,
+    code: &'static DiagnosticCode,
+  ) -> Box<dyn Diagnostic + Send + Sync> {
+    let c = WithDiagnosticCode::new(miette::Error::new_boxed(self), code);
+    <WithDiagnosticCode as DiagnosticExt>::boxed(c)
+  }
 }
 
 impl MietteExt for miette::Error {
@@ -59,4 +73,11 @@ impl MietteExt for miette::Error {
     let l = WithLabel::from(self).with_label(labels);
     <WithLabel as DiagnosticExt>::boxed(l)
   }
+  fn with_diagnostic_code(
+    self,
+    code: &'static DiagnosticCode,
+  ) -> Box<dyn Diagnostic + Send + Sync> {
+    let c = WithDiagnosticCode::new(self, code);
+    <WithDiagnosticCode as DiagnosticExt>::boxed(c)
+  }
 }
@@ -1,13 +1,16 @@
 #![feature(let_chains)]
 #![feature(anonymous_lifetime_in_impl_trait)]
+#![feature(trait_upcasting)]
 
 mod catch_unwind;
+pub mod code;
 mod diagnostic;
 mod error;
 mod ext;
 pub(crate) mod graphical;
 pub(crate) mod miette_helpers;
 pub use catch_unwind::*;
+pub use code::DiagnosticCode;
 pub use diagnostic::*;
 pub use error::*;
 pub use ext::*;
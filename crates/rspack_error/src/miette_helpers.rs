@@ -5,7 +5,7 @@ use miette::Diagnostic;
 use once_cell::sync::OnceCell;
 use thiserror::Error;
 
-use crate::Error;
+use crate::{DiagnosticCode, Error};
 
 /// Wrap diagnostic with additional help message.
 #[derive(Debug, Error)]
@@ -165,3 +165,96 @@ impl miette::Diagnostic for WithLabel {
     self.err.diagnostic_source()
   }
 }
+
+/// Attach a stable [DiagnosticCode] to the diagnostic.
+#[derive(Debug, Error)]
+#[error("{err}")]
+pub(crate) struct WithDiagnosticCode {
+  err: Error,
+  code: &'static DiagnosticCode,
+}
+
+impl WithDiagnosticCode {
+  pub(crate) fn new(err: Error, code: &'static DiagnosticCode) -> Self {
+    Self { err, code }
+  }
+}
+
+impl miette::Diagnostic for WithDiagnosticCode {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.code()
+  }
+
+  fn severity(&self) -> Option<miette::Severity> {
+    self.err.severity()
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.help()
+  }
+
+  fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.err.url()
+  }
+
+  fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+    self.err.source_code()
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+    self.err.labels()
+  }
+
+  fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+    self.err.related()
+  }
+
+  fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+    self.err.diagnostic_source()
+  }
+}
+
+/// Get the [DiagnosticCode] attached by [crate::MietteExt::with_diagnostic_code],
+/// the helpers wrapping the diagnostic afterwards, such as [WithHelp], are looked through.
+pub(crate) fn attached_diagnostic_code(
+  diagnostic: &(dyn Diagnostic + Send + Sync + 'static),
+) -> Option<&'static DiagnosticCode> {
+  let error: &(dyn std::error::Error + 'static) = diagnostic;
+  if let Some(e) = error.downcast_ref::<WithDiagnosticCode>() {
+    Some(e.code)
+  } else if let Some(e) = error.downcast_ref::<WithHelp>() {
+    attached_diagnostic_code_of_error(&e.err)
+  } else if let Some(e) = error.downcast_ref::<WithLabel>() {
+    attached_diagnostic_code_of_error(&e.err)
+  } else {
+    None
+  }
+}
+
+pub(crate) fn attached_diagnostic_code_of_error(error: &Error) -> Option<&'static DiagnosticCode> {
+  if let Some(boxed) = error.downcast_ref::<Box<dyn Diagnostic + Send + Sync>>() {
+    attached_diagnostic_code(boxed.as_ref())
+  } else if let Some(e) = error.downcast_ref::<WithDiagnosticCode>() {
+    Some(e.code)
+  } else if let Some(e) = error.downcast_ref::<WithHelp>() {
+    attached_diagnostic_code_of_error(&e.err)
+  } else if let Some(e) = error.downcast_ref::<WithLabel>() {
+    attached_diagnostic_code_of_error(&e.err)
+  } else {
+    None
+  }
+}
+
+pub(crate) fn without_diagnostic_code<'a>(
+  diagnostic: &'a (dyn Diagnostic + Send + Sync + 'static),
+) -> &'a (dyn Diagnostic + Send + Sync + 'static) {
+  let error: &(dyn std::error::Error + 'static) = diagnostic;
+  let Some(e) = error.downcast_ref::<WithDiagnosticCode>() else {
+    return diagnostic;
+  };
+  if let Some(boxed) = e.err.downcast_ref::<Box<dyn Diagnostic + Send + Sync>>() {
+    boxed.as_ref()
+  } else {
+    &*e.err
+  }
+}
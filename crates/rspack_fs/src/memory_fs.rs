@@ -65,6 +65,13 @@ impl FileType {
       Self::File { metadata, .. } => metadata,
     }
   }
+
+  pub fn metadata_mut(&mut self) -> &mut FileMetadata {
+    match self {
+      Self::Dir(metadata) => metadata,
+      Self::File { metadata, .. } => metadata,
+    }
+  }
 }
 
 #[derive(Debug, Default)]
@@ -79,6 +86,19 @@ impl MemoryFileSystem {
     files.clear();
   }
 
+  /// Set the modified time of a file or directory, so the time based checks
+  /// can be tested without waiting for the clock.
+  pub fn set_mtime(&self, path: &Utf8Path, mtime_ms: u64) -> Result<()> {
+    let mut files = self.files.lock().expect("should get lock");
+    match files.get_mut(path) {
+      Some(ft) => {
+        ft.metadata_mut().mtime_ms = mtime_ms;
+        Ok(())
+      }
+      None => Err(new_error("file not exist")),
+    }
+  }
+
   fn contains_dir(&self, dir: &Utf8Path) -> Result<bool> {
     let files = self.files.lock().expect("should get lock");
     if let Some(ft) = files.get(dir) {
@@ -196,6 +216,24 @@ impl WritableFileSystem for MemoryFileSystem {
     Ok(())
   }
 
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    if !self.contains_file(from)? {
+      return Err(new_error("file not exist"));
+    }
+    let p = to.parent().expect("should have parent dir");
+    if !self.contains_dir(p)? {
+      return Err(new_error("parent dir not exist"));
+    }
+
+    let mut files = self.files.lock().expect("should get lock");
+    if let Some(FileType::Dir(_)) = files.get(to) {
+      return Err(new_error("invalid file path"));
+    }
+    let file = files.remove(from).expect("should have file");
+    files.insert(to.to_path_buf(), file);
+    Ok(())
+  }
+
   fn remove_file<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
     let fut = async move { self._remove_file(file) };
     Box::pin(fut)
@@ -247,6 +285,11 @@ impl ReadableFileSystem for MemoryFileSystem {
     let path = dunce::canonicalize(path)?;
     Ok(path.assert_utf8())
   }
+
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    self._read_dir(dir)
+  }
+
   fn async_read<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<Vec<u8>>> {
     let fut = async move { ReadableFileSystem::read(self, file) };
     Box::pin(fut)
@@ -409,6 +452,35 @@ mod tests {
       .await
       .is_err(),);
 
+    // rename
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/file3"), Utf8Path::new("/a/file4"))
+        .await
+        .is_err(),
+    );
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/file1"), Utf8Path::new("/a/b"))
+        .await
+        .is_err(),
+    );
+    WritableFileSystem::write(&fs, Utf8Path::new("/a/file3"), "3".as_bytes())
+      .await
+      .unwrap();
+    assert!(
+      WritableFileSystem::rename(&fs, Utf8Path::new("/a/file3"), Utf8Path::new("/a/file1"))
+        .await
+        .is_ok(),
+    );
+    assert!(WritableFileSystem::stat(&fs, Utf8Path::new("/a/file3"))
+      .await
+      .is_err(),);
+    assert_eq!(
+      ReadableFileSystem::async_read(&fs, Utf8Path::new("/a/file1"))
+        .await
+        .unwrap(),
+      "3".as_bytes()
+    );
+
     // remove dir
     assert!(
       WritableFileSystem::remove_dir_all(&fs, Utf8Path::new("/a3/b3/c3"))
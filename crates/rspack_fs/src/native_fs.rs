@@ -22,6 +22,10 @@ impl WritableFileSystem for NativeFileSystem {
     fs::write(file, data).map_err(Error::from)
   }
 
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    tokio::fs::rename(from, to).await.map_err(Error::from)
+  }
+
   fn remove_file<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
     let fut = async move { tokio::fs::remove_file(file).await.map_err(Error::from) };
     Box::pin(fut)
@@ -80,6 +84,15 @@ impl ReadableFileSystem for NativeFileSystem {
     Ok(path.assert_utf8())
   }
 
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let mut res = vec![];
+    for entry in fs::read_dir(dir)? {
+      let entry = entry?;
+      res.push(entry.file_name().to_string_lossy().to_string());
+    }
+    Ok(res)
+  }
+
   fn async_read<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<Vec<u8>>> {
     let fut = async move { tokio::fs::read(file).await.map_err(Error::from) };
     Box::pin(fut)
@@ -4,7 +4,7 @@ use futures::future::BoxFuture;
 use rspack_paths::Utf8Path;
 use rspack_paths::Utf8PathBuf;
 
-use crate::{FileMetadata, Result};
+use crate::{Error, FileMetadata, Result};
 pub trait ReadableFileSystem: Debug + Send + Sync {
   /// See [std::fs::read]
   fn read(&self, path: &Utf8Path) -> Result<Vec<u8>>;
@@ -17,6 +17,17 @@ pub trait ReadableFileSystem: Debug + Send + Sync {
 
   /// See [std::fs::canonicalize]
   fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf>;
+
+  /// Read the names of the entries in a directory.
+  ///
+  /// See [std::fs::read_dir]
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    Err(Error::Io(std::io::Error::new(
+      std::io::ErrorKind::Unsupported,
+      format!("read_dir_sync is not supported, failed to read {dir}"),
+    )))
+  }
+
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
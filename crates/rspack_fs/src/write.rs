@@ -39,4 +39,14 @@ pub trait WritableFileSystem: Debug + Send + Sync {
   fn read_file<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<Vec<u8>>>;
 
   fn stat<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<FileMetadata>>;
+
+  /// Rename a file, replacing the destination if it already exists.
+  ///
+  /// NOTE: The default implementation copies the content and is not atomic,
+  /// file systems that support renaming natively should override it.
+  async fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    let data = self.read_file(from).await?;
+    self.write(to, &data).await?;
+    self.remove_file(from).await
+  }
 }
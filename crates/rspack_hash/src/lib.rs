@@ -1,25 +1,44 @@
 use std::{
   fmt,
   hash::{Hash, Hasher},
+  str::FromStr,
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use md5::Md5;
+use rspack_cacheable::{
+  cacheable,
+  with::{AsRefStr, AsRefStrConverter},
+};
+use sha2::{Sha256, Sha512};
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash3_128,
   MD4,
+  MD5,
+  Sha256,
+  Sha512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl FromStr for HashFunction {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash3-128" => Ok(HashFunction::Xxhash3_128),
+      "md4" => Ok(HashFunction::MD4),
+      "md5" => Ok(HashFunction::MD5),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      _ => Err(format!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"xxhash3-128\", \"md4\", \"md5\", \"sha256\" or \"sha512\""
+      )),
     }
   }
 }
@@ -27,13 +46,32 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
+  /// Lowercase letters only, safe on case-insensitive file systems
+  Base26,
+  /// Digits and lowercase letters, safe on case-insensitive file systems
+  Base36,
+  /// The bitcoin alphabet, without the look-alike characters `0`, `O`, `I` and `l`
+  Base58,
+  Base62,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl FromStr for HashDigest {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base26" => Ok(HashDigest::Base26),
+      "base36" => Ok(HashDigest::Base36),
+      "base58" => Ok(HashDigest::Base58),
+      "base62" => Ok(HashDigest::Base62),
+      _ => Err(format!(
+        "Unsupported hash digest \"{value}\", expected one of \"hex\", \"base64\", \"base64url\", \"base26\", \"base36\", \"base58\" or \"base62\""
+      )),
     }
   }
 }
@@ -56,14 +94,22 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
+  Xxhash3_128(Box<Xxh3>),
   MD4(Box<md4::Md4>),
+  MD5(Box<Md5>),
+  Sha256(Box<Sha256>),
+  Sha512(Box<Sha512>),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash3_128(_) => write!(f, "RspackHash(Xxhash3_128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::MD5(_) => write!(f, "RspackHash(MD5)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
     }
   }
 }
@@ -72,7 +118,11 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
+      HashFunction::Xxhash3_128 => Self::Xxhash3_128(Box::new(Xxh3::new())),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
+      HashFunction::MD5 => Self::MD5(Box::new(Md5::new())),
+      HashFunction::Sha256 => Self::Sha256(Box::new(Sha256::new())),
+      HashFunction::Sha512 => Self::Sha512(Box::new(Sha512::new())),
     }
   }
 
@@ -87,7 +137,11 @@ impl RspackHash {
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_be_bytes().to_vec(),
+      RspackHash::Xxhash3_128(hasher) => hasher.digest128().to_be_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::MD5(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,30 +151,34 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash3_128(hasher) => (hasher.digest128() >> 64) as u64,
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::MD5(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash3_128(hasher) => hasher.update(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::MD5(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
     }
   }
 }
 
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
+#[cacheable(with=AsRefStr)]
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -138,6 +196,20 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base26 => encode_base_n(&inner, b"abcdefghijklmnopqrstuvwxyz").into(),
+      HashDigest::Base36 => encode_base_n(&inner, b"0123456789abcdefghijklmnopqrstuvwxyz").into(),
+      HashDigest::Base58 => encode_base_n(
+        &inner,
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+      )
+      .into(),
+      HashDigest::Base62 => encode_base_n(
+        &inner,
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+      )
+      .into(),
     };
     Self { encoded }
   }
@@ -152,6 +224,37 @@ impl RspackHashDigest {
   }
 }
 
+/// Encode the bytes as a big-endian number in the base of the alphabet length,
+/// the most significant digit comes first so a prefix of the result stays well distributed.
+fn encode_base_n(bytes: &[u8], alphabet: &[u8]) -> String {
+  let base = alphabet.len() as u32;
+  let mut number = bytes.to_vec();
+  let mut digits = vec![];
+  while number.iter().any(|byte| *byte != 0) {
+    let mut remainder = 0u32;
+    for byte in number.iter_mut() {
+      let value = (remainder << 8) | *byte as u32;
+      *byte = (value / base) as u8;
+      remainder = value % base;
+    }
+    digits.push(alphabet[remainder as usize]);
+  }
+  if digits.is_empty() {
+    digits.push(alphabet[0]);
+  }
+  digits.reverse();
+  String::from_utf8(digits).expect("alphabet should be ascii")
+}
+
+impl AsRefStrConverter for RspackHashDigest {
+  fn as_str(&self) -> &str {
+    self.encoded()
+  }
+  fn from_str(s: &str) -> Self {
+    Self::from(s)
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -163,3 +266,102 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hash(function: &str, input: &[u8], digest: &str) -> String {
+    let mut hasher = RspackHash::new(&function.parse().expect("should be a hash function"));
+    hasher.write(input);
+    hasher
+      .digest(&digest.parse().expect("should be a hash digest"))
+      .encoded()
+      .to_string()
+  }
+
+  #[test]
+  fn md5() {
+    // test suite of RFC 1321
+    for (input, expected) in [
+      ("", "d41d8cd98f00b204e9800998ecf8427e"),
+      ("a", "0cc175b9c0f1b6a831c399e269772661"),
+      ("abc", "900150983cd24fb0d6963f7d28e17f72"),
+      ("message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+      (
+        "abcdefghijklmnopqrstuvwxyz",
+        "c3fcd3d76192e4007dfb496cca67e13b",
+      ),
+      (
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        "d174ab98d277d9f5a5611c2c9f419d9f",
+      ),
+      (
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        "57edf4a22be3c955ac49da2e2107b67a",
+      ),
+    ] {
+      assert_eq!(hash("md5", input.as_bytes(), "hex"), expected);
+    }
+  }
+
+  #[test]
+  fn sha2() {
+    // examples of NIST FIPS 180-2
+    assert_eq!(
+      hash("sha256", b"abc", "hex"),
+      "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+      hash(
+        "sha256",
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "hex"
+      ),
+      "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(
+      hash("sha512", b"abc", "hex"),
+      "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+    assert_eq!(
+      hash(
+        "sha512",
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        "hex"
+      ),
+      "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+    );
+  }
+
+  #[test]
+  fn xxhash() {
+    assert_eq!(hash("xxhash64", b"", "hex"), "ef46db3751d8e999");
+    assert_eq!(
+      hash("xxhash3-128", b"", "hex"),
+      "99aa06d3014798d86001c324468d497f"
+    );
+  }
+
+  #[test]
+  fn digests() {
+    let md5 = |digest| hash("md5", b"abc", digest);
+    assert_eq!(md5("base64"), "kAFQmDzST7DWlj99KOF/cg==");
+    assert_eq!(md5("base64url"), "kAFQmDzST7DWlj99KOF_cg");
+    assert_eq!(md5("base26"), "bfclgjoftqfzmukvbaghcfwzvbok");
+    assert_eq!(md5("base36"), "8iwzik1bwczwanvt8k8d8ylwy");
+    assert_eq!(md5("base58"), "JnNtQjvG5Df5UEqLvtyni5");
+    assert_eq!(md5("base62"), "4NjUkTaz87FZto8hbWIO6E");
+
+    let encode = |alphabet: &[u8]| encode_base_n(b"hello world", alphabet);
+    assert_eq!(
+      encode(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"),
+      "StV1DL6CwTryKyV"
+    );
+    assert_eq!(
+      encode(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"),
+      "AAwf93rvy4aWQVw"
+    );
+    assert_eq!(encode_base_n(&[0, 0], b"0123456789"), "0");
+  }
+}
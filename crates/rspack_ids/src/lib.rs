@@ -14,3 +14,5 @@ mod natural_module_ids_plugin;
 pub use natural_module_ids_plugin::NaturalModuleIdsPlugin;
 mod natural_chunk_ids_plugin;
 pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
+mod record_ids_plugin;
+pub use record_ids_plugin::RecordIdsPlugin;
@@ -26,8 +26,11 @@ impl NamedChunkIdsPlugin {
 
 #[plugin_hook(CompilationChunkIds for NamedChunkIdsPlugin)]
 fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
-  // set default value
+  // set default value, the chunks whose ids are revived from the records keep their ids
   for chunk in compilation.chunk_by_ukey.values_mut() {
+    if chunk.id().is_some() {
+      continue;
+    }
     if let Some(name) = chunk.name() {
       chunk.set_id(Some(name.to_owned()));
     }
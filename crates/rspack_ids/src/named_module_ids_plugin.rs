@@ -152,7 +152,13 @@ fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result<()> {
         acc
       })
   } else {
-    module_graph.modules().keys().copied().collect()
+    // skip the modules whose ids are already assigned, e.g. revived from the records
+    module_graph
+      .modules()
+      .keys()
+      .filter(|m| !module_ids.contains_key(*m))
+      .copied()
+      .collect()
   };
 
   modules.retain(|m| {
@@ -0,0 +1,203 @@
+use itertools::Itertools;
+use rspack_collections::DatabaseItem;
+use rspack_core::{
+  ApplyContext, Chunk, ChunkGraph, ChunkIdRecords, Compilation, CompilationChunkIds,
+  CompilationModuleIds, CompilerOptions, ModuleIdRecords, ModuleIdentifier, Plugin, PluginContext,
+  RecordId,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::FxHashSet as HashSet;
+
+/// Revives the module ids and chunk ids from the records before the ids are assigned,
+/// and records the assigned ids afterwards, so the ids keep stable across builds.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct RecordIdsPlugin;
+
+fn get_module_identifier(compilation: &Compilation, module: &ModuleIdentifier) -> String {
+  make_paths_relative(compilation.options.context.as_str(), module)
+}
+
+fn get_chunk_sources(compilation: &Compilation, chunk: &Chunk) -> Vec<String> {
+  let mut sources = vec![];
+  for group in chunk.groups() {
+    let group = compilation.chunk_group_by_ukey.expect_get(group);
+    let Some(index) = group.chunks.iter().position(|c| *c == chunk.ukey()) else {
+      continue;
+    };
+    if let Some(name) = group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in group.origins() {
+      let Some(module) = &origin.module_id else {
+        continue;
+      };
+      let module = get_module_identifier(compilation, module);
+      if let Some(request) = &origin.request {
+        sources.push(format!("{index} {module} {request}"));
+      } else if let Some(loc) = &origin.loc {
+        sources.push(format!("{index} {module} {loc}"));
+      }
+    }
+  }
+  sources.sort_unstable();
+  sources
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = i32::MIN)]
+fn revive_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation.id_records.modules.take() else {
+    return Ok(());
+  };
+
+  let mut module_ids = std::mem::take(&mut compilation.module_ids);
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = HashSet::default();
+  for module in module_graph
+    .modules()
+    .values()
+    .filter(|m| m.need_id())
+    .sorted_unstable_by_key(|m| m.identifier())
+  {
+    if ChunkGraph::get_module_id(&module_ids, module.identifier()).is_some() {
+      continue;
+    }
+    let identifier = get_module_identifier(compilation, &module.identifier());
+    let Some(id) = records.by_identifier.get(&identifier) else {
+      continue;
+    };
+    let id = id.to_string();
+    if used_ids.contains(&id) {
+      continue;
+    }
+    ChunkGraph::set_module_id(&mut module_ids, module.identifier(), id.as_str().into());
+    used_ids.insert(id);
+  }
+
+  compilation.module_ids = module_ids;
+  compilation.used_module_ids = records.used_ids.iter().map(ToString::to_string).collect();
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = i32::MAX)]
+fn record_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut records = ModuleIdRecords::default();
+  for (module, id) in &compilation.module_ids {
+    records.by_identifier.insert(
+      get_module_identifier(compilation, module),
+      id.as_str().into(),
+    );
+    records.used_ids.push(id.as_str().into());
+  }
+  records.used_ids.sort_unstable();
+  records.used_ids.dedup();
+  compilation.id_records.modules = Some(records);
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = i32::MIN)]
+fn revive_chunks(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation.id_records.chunks.take() else {
+    return Ok(());
+  };
+
+  let mut chunks = compilation
+    .chunk_by_ukey
+    .keys()
+    .copied()
+    .collect::<Vec<_>>();
+  chunks.sort_unstable();
+  let mut used_ids = HashSet::default();
+
+  for ukey in &chunks {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(ukey);
+    if chunk.id().is_some() {
+      continue;
+    }
+    let Some(id) = chunk.name().and_then(|name| records.by_name.get(name)) else {
+      continue;
+    };
+    let id = id.to_string();
+    if used_ids.contains(&id) {
+      continue;
+    }
+    chunk.set_id(Some(id.clone()));
+    used_ids.insert(id);
+  }
+
+  for ukey in &chunks {
+    let chunk = compilation.chunk_by_ukey.expect_get(ukey);
+    if chunk.id().is_some() {
+      continue;
+    }
+    let id = get_chunk_sources(compilation, chunk)
+      .iter()
+      .filter_map(|source| records.by_source.get(source))
+      .map(ToString::to_string)
+      .find(|id| !used_ids.contains(id));
+    if let Some(id) = id {
+      compilation
+        .chunk_by_ukey
+        .expect_get_mut(ukey)
+        .set_id(Some(id.clone()));
+      used_ids.insert(id);
+    }
+  }
+
+  compilation.used_chunk_ids = records.used_ids.iter().map(ToString::to_string).collect();
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = i32::MAX)]
+fn record_chunks(&self, compilation: &mut Compilation) -> Result<()> {
+  let mut records = ChunkIdRecords::default();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = chunk.id() else {
+      continue;
+    };
+    if let Some(name) = chunk.name() {
+      records.by_name.insert(name.to_string(), RecordId::from(id));
+    }
+    for source in get_chunk_sources(compilation, chunk) {
+      records.by_source.insert(source, RecordId::from(id));
+    }
+    records.used_ids.push(RecordId::from(id));
+  }
+  records.used_ids.sort_unstable();
+  records.used_ids.dedup();
+  compilation.id_records.chunks = Some(records);
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "RecordIdsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(revive_modules::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(record_modules::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(revive_chunks::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(record_chunks::new(self));
+    Ok(())
+  }
+}
@@ -6,6 +6,10 @@ use std::{
 
 use anymap::CloneAny;
 use once_cell::sync::OnceCell;
+use rspack_cacheable::{
+  cacheable,
+  with::{AsInner, AsOption, AsPreset, AsString, Skip},
+};
 use rspack_error::{Error, Result};
 use rspack_paths::Utf8PathBuf;
 
@@ -104,11 +108,13 @@ impl Debug for Content {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ResourceData {
   /// Resource with absolute path, query and fragment
   pub resource: String,
   /// Absolute resource path only
+  #[cacheable(with=AsOption<AsPreset>)]
   pub resource_path: Option<Utf8PathBuf>,
   /// Resource query with `?` prefix
   pub resource_query: Option<String>,
@@ -119,6 +125,7 @@ pub struct ResourceData {
   pub parameters: Option<String>,
   pub encoding: Option<String>,
   pub encoded_content: Option<String>,
+  #[cacheable(with=Skip)]
   pub(crate) scheme: OnceCell<Scheme>,
 }
 
@@ -144,6 +151,7 @@ impl ResourceData {
 
   pub fn set_resource(&mut self, v: String) {
     self.resource = v;
+    self.scheme = OnceCell::new();
   }
 
   pub fn path<P: Into<Utf8PathBuf>>(mut self, v: P) -> Self {
@@ -244,12 +252,15 @@ impl ResourceData {
 
 /// Used for [Rule.descriptionData](https://www.rspack.dev/config/module.html#ruledescriptiondata) and
 /// package.json.sideEffects in tree shaking.
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct DescriptionData {
   /// Path to package.json
+  #[cacheable(with=AsString)]
   path: PathBuf,
 
   /// Raw package.json
+  #[cacheable(with=AsInner<AsPreset>)]
   json: Arc<serde_json::Value>,
 }
 
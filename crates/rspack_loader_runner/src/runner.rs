@@ -5,6 +5,7 @@ use rspack_fs::FileSystem;
 use rspack_sources::SourceMap;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::task::spawn_blocking;
+use tracing::Instrument;
 
 use crate::{
   content::{AdditionalData, Content, ResourceData},
@@ -143,7 +144,8 @@ pub async fn run_loaders<Context: Send>(
 
         cx.current_loader().set_pitch_executed();
         let loader = cx.current_loader().loader().clone();
-        loader.pitch(&mut cx).await?;
+        let span = tracing::info_span!("Loader::pitch", loader = %loader.identifier());
+        loader.pitch(&mut cx).instrument(span).await?;
         if cx.content.is_some() {
           cx.state.transition(State::Normal);
           cx.loader_index -= 1;
@@ -177,7 +179,8 @@ pub async fn run_loaders<Context: Send>(
 
         cx.current_loader().set_normal_executed();
         let loader = cx.current_loader().loader().clone();
-        loader.run(&mut cx).await?;
+        let span = tracing::info_span!("Loader::run", loader = %loader.identifier());
+        loader.run(&mut cx).instrument(span).await?;
         if !cx.current_loader().finish_called() {
           // If nothing is returned from this loader,
           // we set everything to [None] and move to the next loader.
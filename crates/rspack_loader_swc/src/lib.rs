@@ -10,7 +10,7 @@ use compiler::{IntoJsAst, SwcCompiler};
 use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 use rspack_core::{Mode, RunnerContext};
-use rspack_error::{error, AnyhowError, Diagnostic, Result};
+use rspack_error::{code::SWC_LOADER_OPTIONS, error, AnyhowError, Diagnostic, Result};
 use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
 use rspack_plugin_javascript::ast::{self, SourceMapConfig};
 use rspack_plugin_javascript::TransformOutput;
@@ -73,10 +73,13 @@ impl SwcLoader {
       swc_options.source_file_name = Some(resource_path.as_str().to_string());
 
       if swc_options.config.jsc.target.is_some() && swc_options.config.env.is_some() {
-        loader_context.emit_diagnostic(Diagnostic::warn(
-          SWC_LOADER_IDENTIFIER.to_string(),
-          "`env` and `jsc.target` cannot be used together".to_string(),
-        ));
+        loader_context.emit_diagnostic(
+          Diagnostic::warn(
+            SWC_LOADER_IDENTIFIER.to_string(),
+            "`env` and `jsc.target` cannot be used together".to_string(),
+          )
+          .with_diagnostic_code(&SWC_LOADER_OPTIONS),
+        );
       }
       swc_options
     };
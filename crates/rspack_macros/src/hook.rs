@@ -87,18 +87,26 @@ impl DefineHookInput {
       })
       .collect::<Result<Punctuated<&Ident, Comma>>>()?;
     let call_body = exec_kind.body(arg_names);
-    let call_fn = quote! {
-      fn call(&self, #args) -> #ret {
-        #call_body
-      }
-    };
+    let hook_name = Ident::new(&format!("{trait_name}Hook"), trait_name.span());
+    let hook_name_lit_str = LitStr::new(&hook_name.to_string(), trait_name.span());
+    let tracing_name = LitStr::new(&format!("{hook_name}::call"), trait_name.span());
     let call_fn = if is_async {
-      quote! { async #call_fn }
+      quote! {
+        async fn call(&self, #args) -> #ret {
+          use rspack_hook::__macro_helper::tracing::Instrument;
+          let span = self.span();
+          async move { #call_body }.instrument(span).await
+        }
+      }
     } else {
-      call_fn
+      quote! {
+        fn call(&self, #args) -> #ret {
+          let span = self.span();
+          let _guard = span.enter();
+          #call_body
+        }
+      }
     };
-    let hook_name = Ident::new(&format!("{trait_name}Hook"), trait_name.span());
-    let hook_name_lit_str = LitStr::new(&hook_name.to_string(), trait_name.span());
     Ok(quote! {
       #attr
       pub trait #trait_name {
@@ -146,6 +154,15 @@ impl DefineHookInput {
         pub fn tap(&mut self, tap: impl #trait_name + Send + Sync + 'static) {
           self.taps.push(Box::new(tap));
         }
+
+        // Only trace the hooks that may run something, empty hooks are called too often to be useful.
+        fn span(&self) -> rspack_hook::__macro_helper::tracing::Span {
+          if self.taps.is_empty() && self.interceptors.is_empty() {
+            rspack_hook::__macro_helper::tracing::Span::none()
+          } else {
+            rspack_hook::__macro_helper::tracing::trace_span!(#tracing_name)
+          }
+        }
       }
     })
   }
@@ -6,6 +6,10 @@ use std::{
 };
 
 pub use camino::{Utf8Component, Utf8Components, Utf8Path, Utf8PathBuf, Utf8Prefix};
+use rspack_cacheable::{
+  cacheable,
+  with::{AsRefStr, AsRefStrConverter},
+};
 
 pub trait AssertUtf8 {
   type Output;
@@ -42,6 +46,7 @@ impl<'a> AssertUtf8 for &'a Path {
   }
 }
 
+#[cacheable(with=AsRefStr)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArcPath(Arc<Path>);
 
@@ -89,6 +94,15 @@ impl Borrow<Path> for ArcPath {
   }
 }
 
+impl AsRefStrConverter for ArcPath {
+  fn as_str(&self) -> &str {
+    self.0.to_str().expect("expect utf8 str")
+  }
+  fn from_str(s: &str) -> Self {
+    Self::from(Path::new(s))
+  }
+}
+
 fn _assert_size() {
   use std::mem::size_of;
   assert_eq!(size_of::<ArcPath>(), size_of::<[usize; 2]>());
@@ -7,11 +7,11 @@ use rayon::prelude::*;
 use rspack_core::{
   rspack_sources::{BoxSource, RawBufferSource, RawStringSource, SourceExt},
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnArgs, AssetInfo, AssetParserDataUrl,
-  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo,
-  CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, CompilationRenderManifest,
-  CompilerOptions, Filename, GenerateContext, GeneratorOptions, LocalFilenameFn, Module,
-  ModuleGraph, NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin, PublicPath,
-  RenderManifestEntry, ResourceData, RuntimeGlobals, RuntimeSpec, SourceType,
+  AssetParserDataUrlFnCtx, BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey,
+  CodeGenerationDataAssetInfo, CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation,
+  CompilationRenderManifest, CompilerOptions, Filename, GenerateContext, GeneratorOptions,
+  LocalFilenameFn, Module, ModuleGraph, NormalModule, ParseContext, ParserAndGenerator, PathData,
+  Plugin, PublicPath, RenderManifestEntry, ResourceData, RuntimeGlobals, RuntimeSpec, SourceType,
   NAMESPACE_OBJECT_EXPORT,
 };
 use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result};
@@ -341,6 +341,9 @@ impl ParserAndGenerator for AssetParserAndGenerator {
   ) -> Result<rspack_error::TWithDiagnosticArray<rspack_core::ParseResult>> {
     let ParseContext {
       source,
+      module_context,
+      module_parser_options,
+      resource_data,
       build_meta,
       build_info,
       ..
@@ -354,24 +357,34 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       DataUrlOptions::Source => Some(CanonicalizedDataUrlOption::Source),
       DataUrlOptions::Inline(val) => Some(CanonicalizedDataUrlOption::Asset(*val)),
       DataUrlOptions::Auto(option) => {
-        let limit_size = parse_context
-          .module_parser_options
-          .and_then(|x| {
-            x.get_asset()
-              .and_then(|x| x.data_url_condition.as_ref())
-              .and_then(|x| match x {
-                AssetParserDataUrl::Options(x) => x.max_size,
-              })
-          })
-          .or_else(|| {
-            option.as_ref().and_then(|x| match x {
-              AssetParserDataUrl::Options(x) => x.max_size,
-            })
-          })
-          .unwrap_or(DEFAULT_MAX_SIZE);
-        Some(CanonicalizedDataUrlOption::Asset(
-          size <= limit_size as usize,
-        ))
+        let data_url_condition = module_parser_options
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.data_url_condition.as_ref());
+        let max_size = |x: &AssetParserDataUrl| match x {
+          AssetParserDataUrl::Options(x) => x.max_size,
+          AssetParserDataUrl::Func(_) => None,
+        };
+        let is_inline =
+          if let Some(AssetParserDataUrl::Func(func)) = data_url_condition.or(option.as_ref()) {
+            func(
+              source.buffer().to_vec(),
+              AssetParserDataUrlFnCtx {
+                filename: resource_data
+                  .resource_path
+                  .as_deref()
+                  .map(|p| p.as_str().to_string())
+                  .unwrap_or_default(),
+                context: module_context.as_str().to_string(),
+              },
+            )?
+          } else {
+            let limit_size = data_url_condition
+              .and_then(max_size)
+              .or_else(|| option.as_ref().and_then(max_size))
+              .unwrap_or(DEFAULT_MAX_SIZE);
+            size <= limit_size as usize
+          };
+        Some(CanonicalizedDataUrlOption::Asset(is_inline))
       }
     };
 
@@ -19,7 +19,7 @@ use rspack_core::{
   AssetInfo, AssetInfoRelated, Compilation, CompilationAsset, CompilationLogger,
   CompilationProcessAssets, FilenameTemplate, Logger, PathData, Plugin,
 };
-use rspack_error::{Diagnostic, DiagnosticError, Error, ErrorExt, Result};
+use rspack_error::{code::COPY_FAILED, Diagnostic, DiagnosticError, Error, ErrorExt, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
@@ -200,10 +200,10 @@ impl CopyRspackPlugin {
               diagnostics
                 .lock()
                 .expect("failed to obtain lock of `diagnostics`")
-                .push(Diagnostic::error(
-                  "Run copy to fn error".into(),
-                  e.to_string(),
-                ));
+                .push(
+                  Diagnostic::error("Run copy to fn error".into(), e.to_string())
+                    .with_diagnostic_code(&COPY_FAILED),
+                );
               "".to_string()
             }
           };
@@ -297,10 +297,10 @@ impl CopyRspackPlugin {
               diagnostics
                 .lock()
                 .expect("failed to obtain lock of `diagnostics`")
-                .push(Diagnostic::error(
-                  "Run copy transform fn error".into(),
-                  e.to_string(),
-                ));
+                .push(
+                  Diagnostic::error("Run copy transform fn error".into(), e.to_string())
+                    .with_diagnostic_code(&COPY_FAILED),
+                );
             }
           };
         }
@@ -496,10 +496,13 @@ impl CopyRspackPlugin {
           diagnostics
             .lock()
             .expect("failed to obtain lock of `diagnostics`")
-            .push(Diagnostic::error(
-              "CopyRspackPlugin Error".into(),
-              format!("unable to locate '{glob_query}' glob"),
-            ));
+            .push(
+              Diagnostic::error(
+                "CopyRspackPlugin Error".into(),
+                format!("unable to locate '{glob_query}' glob"),
+              )
+              .with_diagnostic_code(&COPY_FAILED),
+            );
         }
 
         let output_path = &compilation.options.output.path;
@@ -531,10 +534,13 @@ impl CopyRspackPlugin {
           diagnostics
             .lock()
             .expect("failed to obtain lock of `diagnostics`")
-            .push(Diagnostic::error(
-              "CopyRspackPlugin Error".into(),
-              format!("unable to locate '{glob_query}' glob"),
-            ));
+            .push(
+              Diagnostic::error(
+                "CopyRspackPlugin Error".into(),
+                format!("unable to locate '{glob_query}' glob"),
+              )
+              .with_diagnostic_code(&COPY_FAILED),
+            );
           return None;
         }
 
@@ -564,7 +570,10 @@ impl CopyRspackPlugin {
         diagnostics
           .lock()
           .expect("failed to obtain lock of `diagnostics`")
-          .push(Diagnostic::error("Glob Error".into(), e.msg.to_string()));
+          .push(
+            Diagnostic::error("Glob Error".into(), e.msg.to_string())
+              .with_diagnostic_code(&COPY_FAILED),
+          );
 
         None
       }
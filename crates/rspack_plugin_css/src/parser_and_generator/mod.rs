@@ -78,6 +78,7 @@ pub struct CssParserAndGenerator {
   pub local_ident_name: Option<LocalIdentName>,
   pub exports_only: bool,
   pub named_exports: bool,
+  pub ignore_order: bool,
   pub es_module: bool,
   pub exports: Option<CssExports>,
   pub hot: bool,
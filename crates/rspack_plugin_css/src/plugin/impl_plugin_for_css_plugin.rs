@@ -17,9 +17,9 @@ use rspack_core::{
   AssetInfo, ChunkGraph, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
   CompilationContentHash, CompilationParams, CompilationRenderManifest,
   CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, DependencyType,
-  LibIdentOptions, ModuleGraph, PublicPath, RuntimeGlobals, SelfModuleFactory,
+  LibIdentOptions, ModuleGraph, ModuleIdentifier, PublicPath, RuntimeGlobals, SelfModuleFactory,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{code::CSS_CONFLICTING_ORDER, Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::plugin_hook;
 use rspack_plugin_runtime::is_enabled_for_chunk;
@@ -87,7 +87,7 @@ impl CssPlugin {
     };
     let mut diagnostics = vec![];
     if let Some(conflicts) = conflicts {
-      diagnostics.extend(conflicts.into_iter().map(|conflict| {
+      diagnostics.extend(conflicts.into_iter().filter_map(|conflict| {
         let chunk = compilation.chunk_by_ukey.expect_get(&conflict.chunk);
 
         let failed_module = mg
@@ -96,25 +96,100 @@ impl CssPlugin {
         let selected_module = mg
           .module_by_identifier(&conflict.selected_module)
           .expect("should have module");
-
-        Diagnostic::warn(
-          "Conflicting order".into(),
-          format!(
-            "chunk {}\nConflicting order between {} and {}",
-            chunk
-              .name()
-              .unwrap_or(chunk.id().expect("should have chunk id")),
-            failed_module.readable_identifier(&compilation.options.context),
-            selected_module.readable_identifier(&compilation.options.context)
-          ),
+        if Self::is_order_ignored(failed_module.as_ref())
+          || Self::is_order_ignored(selected_module.as_ref())
+        {
+          return None;
+        }
+
+        let context = &compilation.options.context;
+        let failed_name = failed_module.readable_identifier(context);
+        let selected_name = selected_module.readable_identifier(context);
+        Some(
+          Diagnostic::warn(
+            "Conflicting order".into(),
+            format!(
+              r#"chunk {}
+Conflicting order between {failed_name} and {selected_name}:
+ * {failed_name} is imported before {selected_name} in chunk group(s) {}
+ * {selected_name} is imported before {failed_name} in chunk group(s) {}
+Set `ignoreOrder: true` in the CSS parser options of these modules to ignore this warning."#,
+              chunk
+                .name()
+                .unwrap_or(chunk.id().expect("should have chunk id")),
+              Self::get_chunk_groups_in_order(
+                compilation,
+                chunk,
+                &conflict.failed_module,
+                &conflict.selected_module
+              ),
+              Self::get_chunk_groups_in_order(
+                compilation,
+                chunk,
+                &conflict.selected_module,
+                &conflict.failed_module
+              ),
+            ),
+          )
+          .with_file(Some(output_path.to_owned().into()))
+          .with_chunk(Some(chunk.ukey().as_u32()))
+          .with_diagnostic_code(&CSS_CONFLICTING_ORDER),
         )
-        .with_file(Some(output_path.to_owned().into()))
-        .with_chunk(Some(chunk.ukey().as_u32()))
       }));
     }
     Ok((source, diagnostics))
   }
 
+  fn is_order_ignored(module: &dyn Module) -> bool {
+    module
+      .as_normal_module()
+      .and_then(|module| {
+        module
+          .parser_and_generator()
+          .downcast_ref::<CssParserAndGenerator>()
+      })
+      .is_some_and(|parser_and_generator| parser_and_generator.ignore_order)
+  }
+
+  /// The names of the chunk groups which import `first` before `second`
+  fn get_chunk_groups_in_order(
+    compilation: &Compilation,
+    chunk: &Chunk,
+    first: &ModuleIdentifier,
+    second: &ModuleIdentifier,
+  ) -> String {
+    let mut names = chunk
+      .groups()
+      .iter()
+      .map(|group| compilation.chunk_group_by_ukey.expect_get(group))
+      .filter(|group| {
+        matches!(
+          (group.module_post_order_index(first), group.module_post_order_index(second)),
+          (Some(a), Some(b)) if a < b
+        )
+      })
+      .filter_map(|group| match group.name() {
+        Some(name) => Some(name.to_string()),
+        None => {
+          let mut requests = group
+            .origins()
+            .iter()
+            .filter_map(|origin| origin.request.as_ref())
+            .map(|request| format!("import(\"{request}\")"))
+            .collect::<Vec<_>>();
+          requests.sort_unstable();
+          requests.dedup();
+          (!requests.is_empty()).then(|| requests.join(", "))
+        }
+      })
+      .collect::<Vec<_>>();
+    if names.is_empty() {
+      return "(unnamed)".to_string();
+    }
+    names.sort_unstable();
+    names.join(", ")
+  }
+
   fn render_chunk_to_source(
     compilation: &Compilation,
     chunk: &Chunk,
@@ -422,6 +497,7 @@ impl Plugin for CssPlugin {
           local_ident_name: None,
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          ignore_order: p.ignore_order.unwrap_or_default(),
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
         }) as Box<dyn ParserAndGenerator>
@@ -449,6 +525,7 @@ impl Plugin for CssPlugin {
           ),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          ignore_order: p.ignore_order.unwrap_or_default(),
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
         }) as Box<dyn ParserAndGenerator>
@@ -476,6 +553,7 @@ impl Plugin for CssPlugin {
           ),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          ignore_order: p.ignore_order.unwrap_or_default(),
           es_module: g.es_module.expect("should have es_module"),
           hot: false,
         }) as Box<dyn ParserAndGenerator>
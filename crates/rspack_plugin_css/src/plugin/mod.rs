@@ -146,8 +146,6 @@ impl CssPlugin {
       }
       if let Some(has_failed) = has_failed {
         // There is a not resolve-able conflict with the selectedModule
-        // TODO(hyf0): we should emit a warning here
-        tracing::warn!("Conflicting order between");
         let conflict = CssOrderConflicts {
           chunk: chunk.ukey(),
           failed_module: has_failed.identifier(),
@@ -158,20 +156,6 @@ impl CssPlugin {
         } else {
           conflicts = Some(vec![conflict])
         }
-        // 		if (compilation) {
-        // 			// TODO print better warning
-        // 			compilation.warnings.push(
-        // 				new Error(
-        // 					`chunk ${
-        // 						chunk.name || chunk.id
-        // 					}\nConflicting order between ${hasFailed.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)} and ${selectedModule.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)}`
-        // 				)
-        // 			);
-        // 		}
 
         selected_module = has_failed;
       }
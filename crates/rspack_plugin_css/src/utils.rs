@@ -418,7 +418,7 @@ pub fn css_parsing_traceable_error(
     end as usize,
     match severity {
       RspackSeverity::Error => "CSS parsing error".to_string(),
-      RspackSeverity::Warn => "CSS parsing warning".to_string(),
+      RspackSeverity::Warn | RspackSeverity::Advice => "CSS parsing warning".to_string(),
     },
     message.into(),
   )
@@ -16,7 +16,7 @@ use rspack_core::{
   PluginContext, RenderManifestEntry, RuntimeGlobals, SourceType,
 };
 use rspack_core::{AssetInfo, ChunkGraph};
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{code::CSS_CONFLICTING_ORDER, Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::{
@@ -358,6 +358,7 @@ despite it was not able to fulfill desired ordering with these modules:
         )
         .with_file(Some(filename.to_owned().into()))
         .with_chunk(Some(chunk.ukey().as_u32()))
+        .with_diagnostic_code(&CSS_CONFLICTING_ORDER)
       }));
     }
 
@@ -6,7 +6,6 @@ use async_trait::async_trait;
 use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_collections::{DatabaseItem, IdentifierSet, UkeyMap};
 use rspack_core::{
-  collect_changed_modules,
   rspack_sources::{RawStringSource, SourceExt},
   ApplyContext, AssetInfo, Chunk, ChunkKind, ChunkUkey, Compilation,
   CompilationAdditionalTreeRuntimeRequirements, CompilationAsset, CompilationParams,
@@ -37,6 +36,7 @@ async fn compilation(
   compilation: &mut Compilation,
   params: &mut CompilationParams,
 ) -> Result<()> {
+  compilation.hot_module_replacement = true;
   compilation.set_dependency_factory(
     DependencyType::ImportMetaHotAccept,
     params.normal_module_factory.clone(),
@@ -58,16 +58,30 @@ async fn compilation(
 
 #[plugin_hook(CompilationProcessAssets for HotModuleReplacementPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
-  let Some(CompilationRecords {
-    old_chunks,
-    all_old_runtime,
-    old_all_modules,
-    old_runtime_modules,
-    old_hash,
-  }) = compilation.records.take()
-  else {
+  // the records only exist if there is a previous compilation to compare with
+  let Some(old_records) = compilation.records.take() else {
     return Ok(());
   };
+  let records = CompilationRecords::record(compilation)?;
+  emit_hot_update(compilation, old_records, &records).await?;
+  // persist the records for the next rebuild, so they won't be collected from this compilation again
+  compilation.records = Some(records);
+  Ok(())
+}
+
+async fn emit_hot_update(
+  compilation: &mut Compilation,
+  old_records: CompilationRecords,
+  records: &CompilationRecords,
+) -> Result<()> {
+  let CompilationRecords {
+    chunks: old_chunks,
+    runtimes: all_old_runtime,
+    modules: old_all_modules,
+    chunk_module_hashes: old_chunk_module_hashes,
+    runtime_modules: old_runtime_modules,
+    hash: old_hash,
+  } = old_records;
 
   let mut hot_update_main_content_by_runtime = all_old_runtime
     .iter()
@@ -84,45 +98,26 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     return Ok(());
   }
 
-  let (now_all_modules, now_runtime_modules) = collect_changed_modules(compilation)?;
-
-  let mut updated_modules: IdentifierSet = Default::default();
   let mut updated_runtime_modules: IdentifierSet = Default::default();
   let mut completely_removed_modules: HashSet<String> = Default::default();
   let mut updated_chunks: UkeyMap<ChunkUkey, HashSet<String>> = Default::default();
 
-  for (old_uri, (old_hash, old_module_id)) in &old_all_modules {
-    if let Some((now_hash, _)) = now_all_modules.get(old_uri) {
-      // updated
-      if now_hash != old_hash {
-        updated_modules.insert(*old_uri);
-      }
-    } else {
+  for (old_uri, old_module_id) in &old_all_modules {
+    if !records.modules.contains_key(old_uri) {
       // deleted
       completely_removed_modules.insert(old_module_id.to_string());
     }
   }
-  for identifier in now_all_modules.keys() {
-    if !old_all_modules.contains_key(identifier) {
-      // added
-      updated_modules.insert(*identifier);
-    }
-  }
-
-  // println!(
-  //   "updated_modules: {:?}\n, remove modules {:?}",
-  //   updated_modules, completely_removed_modules
-  // );
 
   for (identifier, old_runtime_module_content) in &old_runtime_modules {
-    if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
+    if let Some(new_runtime_module_content) = records.runtime_modules.get(identifier) {
       // updated
       if new_runtime_module_content != old_runtime_module_content {
         updated_runtime_modules.insert(*identifier);
       }
     }
   }
-  for identifier in now_runtime_modules.keys() {
+  for identifier in records.runtime_modules.keys() {
     if !old_runtime_modules.contains_key(identifier) {
       // added
       updated_runtime_modules.insert(*identifier);
@@ -159,11 +154,21 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         continue;
       }
 
+      // compare the hashes of the runtime of the chunk, so a module shared by runtimes
+      // is only updated in the runtimes it's changed for
+      let old_module_hashes = old_chunk_module_hashes.get(&chunk_id);
+      let module_hashes = records.chunk_module_hashes.get(&chunk_id);
       new_modules = compilation
         .chunk_graph
         .get_chunk_modules_identifier(&current_chunk.ukey())
         .iter()
-        .filter_map(|module| updated_modules.contains(module).then_some(*module))
+        .filter(|module| {
+          let Some(hash) = module_hashes.and_then(|hashes| hashes.get(*module)) else {
+            return false;
+          };
+          old_module_hashes.and_then(|hashes| hashes.get(*module)) != Some(hash)
+        })
+        .copied()
         .collect::<Vec<_>>();
 
       new_runtime_modules = compilation
@@ -167,6 +167,7 @@ pub struct HtmlPluginAssetTags {
 impl HtmlPluginAssetTags {
   pub fn from_assets(
     config: &HtmlRspackPluginOptions,
+    script_loading: HtmlScriptLoading,
     assets: &HtmlPluginAssets,
     asset_map: &HashMap<String, &CompilationAsset>,
   ) -> Self {
@@ -177,7 +178,7 @@ impl HtmlPluginAssetTags {
       assets
         .js
         .par_iter()
-        .map(|x| HtmlPluginTag::create_script(x.as_str(), &config.script_loading))
+        .map(|x| HtmlPluginTag::create_script(x.as_str(), &script_loading))
         .collect::<Vec<_>>(),
     );
 
@@ -262,7 +263,7 @@ impl HtmlPluginAssetTags {
         HtmlInject::Head => head_tags.push(tag.to_owned()),
         HtmlInject::Body => body_tags.push(tag.to_owned()),
         HtmlInject::False => {
-          if matches!(config.script_loading, HtmlScriptLoading::Blocking) {
+          if matches!(config.script_loading, Some(HtmlScriptLoading::Blocking)) {
             body_tags.push(tag.to_owned());
           } else {
             head_tags.push(tag.to_owned());
@@ -54,6 +54,17 @@ pub enum HtmlScriptLoading {
   SystemjsModule,
 }
 
+impl fmt::Display for HtmlScriptLoading {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      HtmlScriptLoading::Blocking => "blocking",
+      HtmlScriptLoading::Defer => "defer",
+      HtmlScriptLoading::Module => "module",
+      HtmlScriptLoading::SystemjsModule => "systemjs-module",
+    })
+  }
+}
+
 impl FromStr for HtmlScriptLoading {
   type Err = anyhow::Error;
 
@@ -89,7 +100,7 @@ impl std::fmt::Debug for TemplateParameterFn {
 
 #[derive(Debug)]
 pub enum TemplateParameters {
-  Map(HashMap<String, String>),
+  Map(HashMap<String, serde_json::Value>),
   Function(TemplateParameterFn),
   Disabled,
 }
@@ -157,9 +168,10 @@ pub struct HtmlRspackPluginOptions {
   pub inject: HtmlInject,
   /// path or `auto`
   pub public_path: Option<String>,
-  /// `blocking`, `defer`, or `module`
-  #[serde(default = "default_script_loading")]
-  pub script_loading: HtmlScriptLoading,
+  /// `blocking`, `defer`, or `module`, defaults to `module` if `output.scriptType` is `module`,
+  /// otherwise `defer`
+  #[serde(default)]
+  pub script_loading: Option<HtmlScriptLoading>,
 
   /// entry_chunk_name (only entry chunks are supported)
   pub chunks: Option<Vec<String>>,
@@ -182,10 +194,6 @@ fn default_filename() -> Vec<String> {
   vec![String::from("index.html")]
 }
 
-fn default_script_loading() -> HtmlScriptLoading {
-  HtmlScriptLoading::Defer
-}
-
 fn default_inject() -> HtmlInject {
   HtmlInject::Head
 }
@@ -204,7 +212,7 @@ impl Default for HtmlRspackPluginOptions {
       template_parameters: TemplateParameters::Map(Default::default()),
       inject: default_inject(),
       public_path: None,
-      script_loading: default_script_loading(),
+      script_loading: None,
       chunks: None,
       exclude_chunks: None,
       chunks_sort_mode: default_chunks_sort_mode(),
@@ -220,6 +228,15 @@ impl Default for HtmlRspackPluginOptions {
 }
 
 impl HtmlRspackPluginOptions {
+  /// Chunks loaded as `type="module"` scripts are injected as module scripts by default.
+  pub fn get_script_loading(&self, compilation: &Compilation) -> HtmlScriptLoading {
+    match self.script_loading {
+      Some(script_loading) => script_loading,
+      None if compilation.options.output.script_type == "module" => HtmlScriptLoading::Module,
+      None => HtmlScriptLoading::Defer,
+    }
+  }
+
   pub fn get_public_path(&self, compilation: &Compilation, filename: &str) -> String {
     match &self.public_path {
       Some(p) => PublicPath::ensure_ends_with_slash(p.clone()),
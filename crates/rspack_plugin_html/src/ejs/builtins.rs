@@ -0,0 +1,851 @@
+use std::rc::Rc;
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use super::{interpreter::Interpreter, value::Value};
+
+const STRING_METHODS: &[&str] = &[
+  "at",
+  "charAt",
+  "concat",
+  "endsWith",
+  "includes",
+  "indexOf",
+  "lastIndexOf",
+  "padEnd",
+  "padStart",
+  "repeat",
+  "replace",
+  "replaceAll",
+  "slice",
+  "split",
+  "startsWith",
+  "substring",
+  "toLowerCase",
+  "toString",
+  "toUpperCase",
+  "trim",
+  "trimEnd",
+  "trimStart",
+];
+
+const ARRAY_METHODS: &[&str] = &[
+  "at",
+  "concat",
+  "every",
+  "filter",
+  "find",
+  "findIndex",
+  "flat",
+  "forEach",
+  "includes",
+  "indexOf",
+  "join",
+  "map",
+  "pop",
+  "push",
+  "reduce",
+  "reverse",
+  "shift",
+  "slice",
+  "some",
+  "sort",
+  "toString",
+  "unshift",
+];
+
+fn arg(args: &[Value], index: usize) -> Value {
+  args.get(index).cloned().unwrap_or(Value::Undefined)
+}
+
+fn object(properties: impl IntoIterator<Item = (&'static str, Value)>) -> Value {
+  Value::object(
+    properties
+      .into_iter()
+      .map(|(key, value)| (key.to_string(), value))
+      .collect(),
+  )
+}
+
+fn math(f: fn(f64) -> f64) -> Value {
+  Value::native(move |_, _, args| Ok(Value::Number(f(arg(&args, 0).to_number()))))
+}
+
+pub fn globals() -> FxHashMap<String, Value> {
+  let globals = [
+    ("NaN", Value::Number(f64::NAN)),
+    ("Infinity", Value::Number(f64::INFINITY)),
+    (
+      "include",
+      Value::native(|interpreter, _, args| {
+        let Some(request) = args.first().and_then(Value::as_str) else {
+          return Err("TypeError: include() expects the path of a template".to_string());
+        };
+        interpreter.include(request, args.get(1))
+      }),
+    ),
+    (
+      "print",
+      Value::native(|interpreter, _, args| {
+        for value in args {
+          interpreter.write(&value.to_js_string());
+        }
+        Ok(Value::Undefined)
+      }),
+    ),
+    (
+      "String",
+      Value::native(|_, _, args| {
+        Ok(Value::string(
+          args.first().map(Value::to_js_string).unwrap_or_default(),
+        ))
+      }),
+    ),
+    (
+      "Number",
+      Value::native(|_, _, args| Ok(Value::Number(args.first().map_or(0.0, Value::to_number)))),
+    ),
+    (
+      "Boolean",
+      Value::native(|_, _, args| Ok(Value::Bool(arg(&args, 0).truthy()))),
+    ),
+    (
+      "parseInt",
+      Value::native(|_, _, args| {
+        let radix = arg(&args, 1).to_number();
+        let radix = if radix.is_nan() { 0 } else { radix as u32 };
+        Ok(Value::Number(parse_int(
+          &arg(&args, 0).to_js_string(),
+          radix,
+        )))
+      }),
+    ),
+    (
+      "parseFloat",
+      Value::native(|_, _, args| Ok(Value::Number(parse_float(&arg(&args, 0).to_js_string())))),
+    ),
+    (
+      "isNaN",
+      Value::native(|_, _, args| Ok(Value::Bool(arg(&args, 0).to_number().is_nan()))),
+    ),
+    (
+      "encodeURIComponent",
+      Value::native(|_, _, args| {
+        Ok(Value::string(
+          urlencoding::encode(&arg(&args, 0).to_js_string()).as_ref(),
+        ))
+      }),
+    ),
+    (
+      "decodeURIComponent",
+      Value::native(|_, _, args| {
+        let value = arg(&args, 0).to_js_string();
+        urlencoding::decode(&value)
+          .map(|decoded| Value::string(decoded.as_ref()))
+          .map_err(|_| "URIError: URI malformed".to_string())
+      }),
+    ),
+    (
+      "JSON",
+      object([
+        (
+          "stringify",
+          Value::native(|_, _, args| json_stringify(&args)),
+        ),
+        (
+          "parse",
+          Value::native(|_, _, args| {
+            serde_json::from_str::<serde_json::Value>(&arg(&args, 0).to_js_string())
+              .map(|value| Value::from_json(&value))
+              .map_err(|err| format!("SyntaxError: {err}"))
+          }),
+        ),
+      ]),
+    ),
+    (
+      "Object",
+      object([
+        (
+          "keys",
+          Value::native(|_, _, args| Ok(entries(&arg(&args, 0), |key, _| Value::string(key)))),
+        ),
+        (
+          "values",
+          Value::native(|_, _, args| Ok(entries(&arg(&args, 0), |_, value| value))),
+        ),
+        (
+          "entries",
+          Value::native(|_, _, args| {
+            Ok(entries(&arg(&args, 0), |key, value| {
+              Value::array(vec![Value::string(key), value])
+            }))
+          }),
+        ),
+        (
+          "assign",
+          Value::native(|_, _, args| {
+            let target = arg(&args, 0);
+            if let Value::Object(target) = &target {
+              for source in args.iter().skip(1) {
+                if let Value::Object(source) = source
+                  && !Rc::ptr_eq(target, source)
+                {
+                  let source = source.borrow();
+                  let mut target = target.borrow_mut();
+                  for (key, value) in source.iter() {
+                    target.insert(key.clone(), value.clone());
+                  }
+                }
+              }
+            }
+            Ok(target)
+          }),
+        ),
+      ]),
+    ),
+    (
+      "Array",
+      object([(
+        "isArray",
+        Value::native(|_, _, args| Ok(Value::Bool(matches!(arg(&args, 0), Value::Array(_))))),
+      )]),
+    ),
+    (
+      "Math",
+      object([
+        ("abs", math(f64::abs)),
+        ("ceil", math(f64::ceil)),
+        ("floor", math(f64::floor)),
+        ("round", math(|n| (n + 0.5).floor())),
+        ("trunc", math(f64::trunc)),
+        (
+          "max",
+          Value::native(|_, _, args| {
+            Ok(Value::Number(args.iter().map(Value::to_number).fold(
+              f64::NEG_INFINITY,
+              |max, n| {
+                if n.is_nan() || max.is_nan() {
+                  f64::NAN
+                } else {
+                  max.max(n)
+                }
+              },
+            )))
+          }),
+        ),
+        (
+          "min",
+          Value::native(|_, _, args| {
+            Ok(Value::Number(args.iter().map(Value::to_number).fold(
+              f64::INFINITY,
+              |min, n| {
+                if n.is_nan() || min.is_nan() {
+                  f64::NAN
+                } else {
+                  min.min(n)
+                }
+              },
+            )))
+          }),
+        ),
+      ]),
+    ),
+  ];
+  globals
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect()
+}
+
+fn entries(value: &Value, f: impl Fn(&str, Value) -> Value) -> Value {
+  Value::array(match value {
+    Value::Object(properties) => properties
+      .borrow()
+      .iter()
+      .map(|(key, value)| f(key, value.clone()))
+      .collect(),
+    Value::Array(items) => items
+      .borrow()
+      .iter()
+      .enumerate()
+      .map(|(index, value)| f(&index.to_string(), value.clone()))
+      .collect(),
+    _ => vec![],
+  })
+}
+
+fn json_stringify(args: &[Value]) -> Result<Value, String> {
+  let Some(json) = arg(args, 0).to_json() else {
+    return Ok(Value::Undefined);
+  };
+  let indent = match arg(args, 2) {
+    Value::Number(n) => " ".repeat(n.clamp(0.0, 10.0) as usize),
+    Value::String(s) => s.chars().take(10).collect(),
+    _ => String::new(),
+  };
+  let result = if indent.is_empty() {
+    serde_json::to_string(&json)
+  } else {
+    let mut buffer = vec![];
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+    serde::Serialize::serialize(&json, &mut serializer)
+      .map(|_| String::from_utf8_lossy(&buffer).into_owned())
+  };
+  result
+    .map(Value::string)
+    .map_err(|err| format!("TypeError: {err}"))
+}
+
+fn parse_int(input: &str, radix: u32) -> f64 {
+  let input = input.trim();
+  let (sign, input) = match input.strip_prefix('-') {
+    Some(rest) => (-1.0, rest),
+    None => (1.0, input.strip_prefix('+').unwrap_or(input)),
+  };
+  let (radix, input) = match radix {
+    0 | 16 => match input
+      .strip_prefix("0x")
+      .or_else(|| input.strip_prefix("0X"))
+    {
+      Some(rest) => (16, rest),
+      None => (if radix == 0 { 10 } else { radix }, input),
+    },
+    2..=36 => (radix, input),
+    _ => return f64::NAN,
+  };
+  let digits = input
+    .chars()
+    .map_while(|c| c.to_digit(radix))
+    .collect::<Vec<_>>();
+  if digits.is_empty() {
+    return f64::NAN;
+  }
+  sign
+    * digits.into_iter().fold(0.0, |result, digit| {
+      result * f64::from(radix) + f64::from(digit)
+    })
+}
+
+fn parse_float(input: &str) -> f64 {
+  let input = input.trim_start();
+  let unsigned = input.trim_start_matches(['+', '-']);
+  if unsigned.starts_with("Infinity") && input.len() - unsigned.len() <= 1 {
+    return if input.starts_with('-') {
+      f64::NEG_INFINITY
+    } else {
+      f64::INFINITY
+    };
+  }
+  // the longest prefix that is a decimal literal
+  let bytes = input.as_bytes();
+  let digits = |mut i: usize| {
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+      i += 1;
+    }
+    i
+  };
+  let mut end = digits(usize::from(matches!(bytes.first(), Some(b'+' | b'-'))));
+  if end < bytes.len() && bytes[end] == b'.' {
+    end = digits(end + 1);
+  }
+  if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+    let exponent = end + 1 + usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+    if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+      end = digits(exponent);
+    }
+  }
+  input[..end].parse().unwrap_or(f64::NAN)
+}
+
+/// Gets a property of a value that is neither `null` nor `undefined`, including the supported methods.
+pub fn get_property(value: &Value, key: &str) -> Value {
+  match value {
+    Value::String(s) | Value::Html(s) => match key {
+      "length" => Value::Number(s.chars().count() as f64),
+      _ => {
+        if let Ok(index) = key.parse::<usize>() {
+          return s
+            .chars()
+            .nth(index)
+            .map_or(Value::Undefined, |c| Value::string(c.to_string()));
+        }
+        match STRING_METHODS.iter().find(|method| **method == key) {
+          Some(&method) => Value::native(move |_, this, args| {
+            string_method(method, this.as_str().unwrap_or_default(), &args)
+          }),
+          None => Value::Undefined,
+        }
+      }
+    },
+    Value::Array(items) => match key {
+      "length" => Value::Number(items.borrow().len() as f64),
+      _ => {
+        if let Ok(index) = key.parse::<usize>() {
+          return items
+            .borrow()
+            .get(index)
+            .cloned()
+            .unwrap_or(Value::Undefined);
+        }
+        match ARRAY_METHODS.iter().find(|method| **method == key) {
+          Some(&method) => Value::native(move |interpreter, this, args| match this {
+            Value::Array(items) => array_method(interpreter, method, &items, args),
+            _ => Ok(Value::Undefined),
+          }),
+          None => Value::Undefined,
+        }
+      }
+    },
+    Value::Object(properties) => {
+      if let Some(value) = properties.borrow().get(key) {
+        return value.clone();
+      }
+      match key {
+        "hasOwnProperty" => Value::native(|_, this, args| {
+          let key = arg(&args, 0).to_js_string();
+          Ok(Value::Bool(match this {
+            Value::Object(properties) => properties.borrow().contains_key(&key),
+            _ => false,
+          }))
+        }),
+        _ => Value::Undefined,
+      }
+    }
+    Value::Number(_) => match key {
+      "toFixed" => Value::native(|_, this, args| {
+        let digits = arg(&args, 0).to_number();
+        let digits = if digits.is_nan() {
+          0
+        } else {
+          digits.clamp(0.0, 100.0) as usize
+        };
+        Ok(Value::string(format!("{:.*}", digits, this.to_number())))
+      }),
+      "toString" => Value::native(|_, this, _| Ok(Value::string(this.to_js_string()))),
+      _ => Value::Undefined,
+    },
+    Value::Bool(_) => match key {
+      "toString" => Value::native(|_, this, _| Ok(Value::string(this.to_js_string()))),
+      _ => Value::Undefined,
+    },
+    _ => Value::Undefined,
+  }
+}
+
+/// Resolves a relative index like `slice` does, negative indexes count from the end.
+fn relative_index(value: &Value, len: usize, default: usize) -> usize {
+  if value.is_nullish() {
+    return default;
+  }
+  let index = value.to_number();
+  let index = if index.is_nan() { 0.0 } else { index.trunc() };
+  if index < 0.0 {
+    (len as f64 + index).max(0.0) as usize
+  } else {
+    (index as usize).min(len)
+  }
+}
+
+fn char_index(s: &str, byte_index: usize) -> f64 {
+  s[..byte_index].chars().count() as f64
+}
+
+fn string_method(method: &str, this: &str, args: &[Value]) -> Result<Value, String> {
+  let string_arg = |index: usize| arg(args, index).to_js_string();
+  Ok(match method {
+    "at" => {
+      let chars = this.chars().collect::<Vec<_>>();
+      let index = arg(args, 0).to_number();
+      let index = if index < 0.0 {
+        chars.len() as f64 + index
+      } else {
+        index
+      };
+      chars
+        .get(index as usize)
+        .filter(|_| index >= 0.0)
+        .map_or(Value::Undefined, |c| Value::string(c.to_string()))
+    }
+    "charAt" => Value::string(
+      this
+        .chars()
+        .nth(arg(args, 0).to_number() as usize)
+        .map(String::from)
+        .unwrap_or_default(),
+    ),
+    "concat" => Value::string(args.iter().fold(this.to_string(), |result, value| {
+      result + value.to_js_string().as_str()
+    })),
+    "endsWith" => Value::Bool(this.ends_with(&string_arg(0))),
+    "includes" => Value::Bool(this.contains(&string_arg(0))),
+    "indexOf" => Value::Number(
+      this
+        .find(&string_arg(0))
+        .map_or(-1.0, |index| char_index(this, index)),
+    ),
+    "lastIndexOf" => Value::Number(
+      this
+        .rfind(&string_arg(0))
+        .map_or(-1.0, |index| char_index(this, index)),
+    ),
+    "padEnd" | "padStart" => {
+      let len = arg(args, 0).to_number();
+      let fill = match arg(args, 1) {
+        Value::Undefined => " ".to_string(),
+        value => value.to_js_string(),
+      };
+      let current = this.chars().count();
+      if fill.is_empty() || len.is_nan() || len as usize <= current {
+        Value::string(this)
+      } else {
+        let padding = fill
+          .chars()
+          .cycle()
+          .take(len as usize - current)
+          .collect::<String>();
+        Value::string(if method == "padEnd" {
+          format!("{this}{padding}")
+        } else {
+          format!("{padding}{this}")
+        })
+      }
+    }
+    "repeat" => {
+      let count = arg(args, 0).to_number();
+      if count < 0.0 || count.is_infinite() {
+        return Err(format!(
+          "RangeError: Invalid count value: {}",
+          string_arg(0)
+        ));
+      }
+      Value::string(this.repeat(if count.is_nan() { 0 } else { count as usize }))
+    }
+    "replace" => {
+      let pattern = string_arg(0);
+      let replacement = string_arg(1);
+      match this.find(&pattern) {
+        Some(index) => Value::string(format!(
+          "{}{replacement}{}",
+          &this[..index],
+          &this[index + pattern.len()..]
+        )),
+        None => Value::string(this),
+      }
+    }
+    "replaceAll" => Value::string(
+      this
+        .cow_replace(string_arg(0).as_str(), string_arg(1).as_str())
+        .as_ref(),
+    ),
+    "slice" => {
+      let chars = this.chars().collect::<Vec<_>>();
+      let start = relative_index(&arg(args, 0), chars.len(), 0);
+      let end = relative_index(&arg(args, 1), chars.len(), chars.len());
+      Value::string(chars[start..end.max(start)].iter().collect::<String>())
+    }
+    "split" => {
+      let parts = match arg(args, 0) {
+        Value::Undefined => vec![Value::string(this)],
+        separator => {
+          let separator = separator.to_js_string();
+          if separator.is_empty() {
+            this.chars().map(|c| Value::string(c.to_string())).collect()
+          } else {
+            this.split(separator.as_str()).map(Value::string).collect()
+          }
+        }
+      };
+      let limit = arg(args, 1);
+      Value::array(if limit.is_nullish() {
+        parts
+      } else {
+        parts
+          .into_iter()
+          .take(limit.to_number().max(0.0) as usize)
+          .collect()
+      })
+    }
+    "startsWith" => Value::Bool(this.starts_with(&string_arg(0))),
+    "substring" => {
+      let chars = this.chars().collect::<Vec<_>>();
+      let clamp = |value: Value, default: usize| {
+        if value.is_undefined() {
+          return default;
+        }
+        let index = value.to_number();
+        if index.is_nan() {
+          0
+        } else {
+          index.clamp(0.0, chars.len() as f64) as usize
+        }
+      };
+      let start = clamp(arg(args, 0), 0);
+      let end = clamp(arg(args, 1), chars.len());
+      Value::string(
+        chars[start.min(end)..start.max(end)]
+          .iter()
+          .collect::<String>(),
+      )
+    }
+    "toLowerCase" => Value::string(this.cow_to_lowercase().as_ref()),
+    "toUpperCase" => Value::string(this.cow_to_uppercase().as_ref()),
+    "toString" => Value::string(this),
+    "trim" => Value::string(this.trim()),
+    "trimEnd" => Value::string(this.trim_end()),
+    "trimStart" => Value::string(this.trim_start()),
+    _ => Value::Undefined,
+  })
+}
+
+fn array_method(
+  interpreter: &mut Interpreter,
+  method: &str,
+  items: &Rc<std::cell::RefCell<Vec<Value>>>,
+  args: Vec<Value>,
+) -> Result<Value, String> {
+  let this = Value::Array(items.clone());
+  let callback = arg(&args, 0);
+  // Iterates over a snapshot, so that the callback can mutate the array.
+  let snapshot = || items.borrow().clone();
+  let call = |interpreter: &mut Interpreter, item: &Value, index: usize| {
+    if !callback.is_function() {
+      return Err(format!(
+        "TypeError: {} is not a function",
+        callback.to_js_string()
+      ));
+    }
+    interpreter.call(
+      &callback,
+      Value::Undefined,
+      vec![item.clone(), Value::Number(index as f64), this.clone()],
+    )
+  };
+  Ok(match method {
+    "at" => {
+      let items = items.borrow();
+      let index = arg(&args, 0).to_number();
+      let index = if index < 0.0 {
+        items.len() as f64 + index
+      } else {
+        index
+      };
+      items
+        .get(index as usize)
+        .filter(|_| index >= 0.0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+    }
+    "concat" => {
+      let mut result = snapshot();
+      for value in args {
+        match value {
+          Value::Array(other) => result.extend(other.borrow().iter().cloned()),
+          value => result.push(value),
+        }
+      }
+      Value::array(result)
+    }
+    "every" => {
+      for (index, item) in snapshot().iter().enumerate() {
+        if !call(interpreter, item, index)?.truthy() {
+          return Ok(Value::Bool(false));
+        }
+      }
+      Value::Bool(true)
+    }
+    "filter" => {
+      let mut result = vec![];
+      for (index, item) in snapshot().into_iter().enumerate() {
+        if call(interpreter, &item, index)?.truthy() {
+          result.push(item);
+        }
+      }
+      Value::array(result)
+    }
+    "find" => {
+      for (index, item) in snapshot().into_iter().enumerate() {
+        if call(interpreter, &item, index)?.truthy() {
+          return Ok(item);
+        }
+      }
+      Value::Undefined
+    }
+    "findIndex" => {
+      for (index, item) in snapshot().iter().enumerate() {
+        if call(interpreter, item, index)?.truthy() {
+          return Ok(Value::Number(index as f64));
+        }
+      }
+      Value::Number(-1.0)
+    }
+    "flat" => Value::array(
+      snapshot()
+        .into_iter()
+        .flat_map(|item| match item {
+          Value::Array(inner) => inner.borrow().clone(),
+          item => vec![item],
+        })
+        .collect(),
+    ),
+    "forEach" => {
+      for (index, item) in snapshot().iter().enumerate() {
+        call(interpreter, item, index)?;
+      }
+      Value::Undefined
+    }
+    "includes" => Value::Bool(items.borrow().iter().any(|item| {
+      item.strict_equals(&callback)
+        || matches!((item, &callback), (Value::Number(a), Value::Number(b)) if a.is_nan() && b.is_nan())
+    })),
+    "indexOf" => Value::Number(
+      items
+        .borrow()
+        .iter()
+        .position(|item| item.strict_equals(&callback))
+        .map_or(-1.0, |index| index as f64),
+    ),
+    "join" | "toString" => {
+      let separator = match (method, &callback) {
+        ("join", value) if !value.is_undefined() => value.to_js_string(),
+        _ => ",".to_string(),
+      };
+      Value::string(
+        items
+          .borrow()
+          .iter()
+          .map(|item| {
+            if item.is_nullish() {
+              String::new()
+            } else {
+              item.to_js_string()
+            }
+          })
+          .collect::<Vec<_>>()
+          .join(&separator),
+      )
+    }
+    "map" => {
+      let mut result = vec![];
+      for (index, item) in snapshot().iter().enumerate() {
+        result.push(call(interpreter, item, index)?);
+      }
+      Value::array(result)
+    }
+    "pop" => items.borrow_mut().pop().unwrap_or(Value::Undefined),
+    "push" => {
+      let mut items = items.borrow_mut();
+      items.extend(args);
+      Value::Number(items.len() as f64)
+    }
+    "reduce" => {
+      if !callback.is_function() {
+        return Err(format!(
+          "TypeError: {} is not a function",
+          callback.to_js_string()
+        ));
+      }
+      let snapshot = snapshot();
+      let mut entries = snapshot.iter().enumerate();
+      let mut accumulator = match args.get(1) {
+        Some(initial) => initial.clone(),
+        None => match entries.next() {
+          Some((_, first)) => first.clone(),
+          None => return Err("TypeError: Reduce of empty array with no initial value".to_string()),
+        },
+      };
+      for (index, item) in entries {
+        accumulator = interpreter.call(
+          &callback,
+          Value::Undefined,
+          vec![
+            accumulator,
+            item.clone(),
+            Value::Number(index as f64),
+            this.clone(),
+          ],
+        )?;
+      }
+      accumulator
+    }
+    "reverse" => {
+      items.borrow_mut().reverse();
+      this
+    }
+    "shift" => {
+      let mut items = items.borrow_mut();
+      if items.is_empty() {
+        Value::Undefined
+      } else {
+        items.remove(0)
+      }
+    }
+    "slice" => {
+      let items = items.borrow();
+      let start = relative_index(&arg(&args, 0), items.len(), 0);
+      let end = relative_index(&arg(&args, 1), items.len(), items.len());
+      Value::array(items[start..end.max(start)].to_vec())
+    }
+    "some" => {
+      for (index, item) in snapshot().iter().enumerate() {
+        if call(interpreter, item, index)?.truthy() {
+          return Ok(Value::Bool(true));
+        }
+      }
+      Value::Bool(false)
+    }
+    "sort" => {
+      let sorted = merge_sort(snapshot(), &mut |a, b| {
+        // `undefined` is always sorted to the end
+        match (a.is_undefined(), b.is_undefined()) {
+          (true, _) => return Ok(true),
+          (false, true) => return Ok(false),
+          _ => {}
+        }
+        if callback.is_undefined() {
+          Ok(a.to_js_string() > b.to_js_string())
+        } else {
+          let result = interpreter.call(&callback, Value::Undefined, vec![a.clone(), b.clone()])?;
+          Ok(result.to_number() > 0.0)
+        }
+      })?;
+      *items.borrow_mut() = sorted;
+      this
+    }
+    "unshift" => {
+      let mut items = items.borrow_mut();
+      items.splice(0..0, args);
+      Value::Number(items.len() as f64)
+    }
+    _ => Value::Undefined,
+  })
+}
+
+/// A stable merge sort, `greater` tells whether the first value should be placed after the second one.
+///
+/// The comparator is provided by the template, so it can fail and is not guaranteed to be a total order.
+fn merge_sort(
+  mut items: Vec<Value>,
+  greater: &mut impl FnMut(&Value, &Value) -> Result<bool, String>,
+) -> Result<Vec<Value>, String> {
+  if items.len() <= 1 {
+    return Ok(items);
+  }
+  let right = items.split_off(items.len() / 2);
+  let left = merge_sort(items, greater)?;
+  let right = merge_sort(right, greater)?;
+  let mut result = Vec::with_capacity(left.len() + right.len());
+  let mut left = left.into_iter().peekable();
+  let mut right = right.into_iter().peekable();
+  while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+    if greater(a, b)? {
+      result.extend(right.next());
+    } else {
+      result.extend(left.next());
+    }
+  }
+  result.extend(left);
+  result.extend(right);
+  Ok(result)
+}
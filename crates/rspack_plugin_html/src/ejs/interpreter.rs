@@ -0,0 +1,721 @@
+use std::{
+  cell::RefCell,
+  fs,
+  path::{Path, PathBuf},
+  rc::Rc,
+};
+
+use rustc_hash::FxHashMap;
+
+use super::{
+  builtins,
+  lexer::{tokenize, OutputKind},
+  parser::{BinaryOp, Expr, FunctionBody, FunctionDef, LogicalOp, Parser, Stmt, UnaryOp},
+  value::{escape_html, Value},
+};
+
+/// Limits the depth of nested function calls, so that a runaway recursion
+/// in a template fails with an error instead of overflowing the stack.
+const MAX_CALL_DEPTH: usize = 128;
+const MAX_INCLUDE_DEPTH: usize = 32;
+/// Limits the statements executed by a render, includes count as part of the template
+/// including them, so that an endless loop fails the build instead of hanging it.
+const MAX_STEPS: usize = 10_000_000;
+
+#[derive(Default)]
+pub struct Scope {
+  vars: RefCell<FxHashMap<String, Value>>,
+  parent: Option<Rc<Scope>>,
+}
+
+impl Scope {
+  fn new(parent: Option<Rc<Scope>>) -> Rc<Self> {
+    Rc::new(Self {
+      vars: Default::default(),
+      parent,
+    })
+  }
+
+  fn lookup(&self, name: &str) -> Option<Value> {
+    if let Some(value) = self.vars.borrow().get(name) {
+      return Some(value.clone());
+    }
+    self.parent.as_ref().and_then(|parent| parent.lookup(name))
+  }
+
+  fn assign(&self, name: &str, value: Value) -> Result<(), Value> {
+    if let Some(slot) = self.vars.borrow_mut().get_mut(name) {
+      *slot = value;
+      return Ok(());
+    }
+    match &self.parent {
+      Some(parent) => parent.assign(name, value),
+      None => Err(value),
+    }
+  }
+
+  fn declare(&self, name: &str, value: Value) {
+    self.vars.borrow_mut().insert(name.to_string(), value);
+  }
+
+  fn root(self: &Rc<Self>) -> Rc<Self> {
+    match &self.parent {
+      Some(parent) => parent.root(),
+      None => self.clone(),
+    }
+  }
+}
+
+enum Flow {
+  Normal,
+  Break,
+  Continue,
+  Return(Value),
+}
+
+/// The state of the template being rendered, an include pushes a new frame.
+struct Frame {
+  /// The data of the template, its properties can be referenced as variables like `with (data)`.
+  data: Value,
+  /// The directory that `include` resolves relative paths against.
+  dir: PathBuf,
+  output: String,
+}
+
+pub struct Interpreter {
+  globals: FxHashMap<String, Value>,
+  templates: FxHashMap<PathBuf, Rc<[Stmt]>>,
+  file_dependencies: Vec<PathBuf>,
+  frames: Vec<Frame>,
+  call_depth: usize,
+  steps: usize,
+}
+
+impl Default for Interpreter {
+  fn default() -> Self {
+    Self {
+      globals: builtins::globals(),
+      templates: Default::default(),
+      file_dependencies: Default::default(),
+      frames: Default::default(),
+      call_depth: 0,
+      steps: 0,
+    }
+  }
+}
+
+impl Interpreter {
+  pub fn define(&mut self, name: &str, value: Value) {
+    self.globals.insert(name.to_string(), value);
+  }
+
+  pub fn into_file_dependencies(self) -> Vec<PathBuf> {
+    self.file_dependencies
+  }
+
+  pub fn render(&mut self, body: &[Stmt], dir: &Path, data: Value) -> Result<String, String> {
+    if self.frames.is_empty() {
+      self.steps = 0;
+    }
+    self.frames.push(Frame {
+      data,
+      dir: dir.to_path_buf(),
+      output: String::new(),
+    });
+    let result = self.exec_block(body, &Scope::new(None));
+    let frame = self.frames.pop().expect("should have frame");
+    result.map(|_| frame.output)
+  }
+
+  pub fn write(&mut self, text: &str) {
+    self
+      .frames
+      .last_mut()
+      .expect("should have frame")
+      .output
+      .push_str(text);
+  }
+
+  /// Renders another template file with the data of the current template,
+  /// merged with `data` if provided, like `include` of EJS.
+  pub fn include(&mut self, request: &str, data: Option<&Value>) -> Result<Value, String> {
+    let frame = self.frames.last().expect("should have frame");
+    let mut path = frame.dir.join(request);
+    if path.extension().is_none() {
+      path.set_extension("ejs");
+    }
+    let path = path_clean::clean(path);
+    if self.frames.len() > MAX_INCLUDE_DEPTH {
+      return Err(format!(
+        "RangeError: Maximum include depth exceeded when including \"{}\", check for circular includes",
+        path.display()
+      ));
+    }
+
+    let merged = match &frame.data {
+      Value::Object(properties) => properties.borrow().clone(),
+      _ => Default::default(),
+    };
+    let merged = Value::object(merged);
+    if let (Value::Object(target), Some(Value::Object(source))) = (&merged, data) {
+      let source = source.borrow();
+      let mut target = target.borrow_mut();
+      for (key, value) in source.iter() {
+        target.insert(key.clone(), value.clone());
+      }
+    }
+
+    let body = self.load(&path, request)?;
+    let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let html = self.render(&body, &dir, merged)?;
+    Ok(Value::Html(html.into()))
+  }
+
+  fn load(&mut self, path: &Path, request: &str) -> Result<Rc<[Stmt]>, String> {
+    if let Some(body) = self.templates.get(path) {
+      return Ok(body.clone());
+    }
+    // watch the file before reading it, so that creating or fixing it triggers a rebuild
+    self.file_dependencies.push(path.to_path_buf());
+    let source = fs::read_to_string(path).map_err(|err| {
+      format!(
+        "Error: could not include \"{request}\" from \"{}\": {err}",
+        path.display()
+      )
+    })?;
+    let body = tokenize(&source)
+      .and_then(|tokens| Parser::new(tokens).parse_program())
+      .map_err(|err| format!("{err} in \"{}\"", path.display()))?;
+    let body: Rc<[Stmt]> = body.into();
+    self.templates.insert(path.to_path_buf(), body.clone());
+    Ok(body)
+  }
+
+  fn exec_block(&mut self, body: &[Stmt], scope: &Rc<Scope>) -> Result<Flow, String> {
+    // function declarations are hoisted
+    for stmt in body {
+      if let Stmt::Function(name, function) = stmt {
+        scope.declare(name, Value::Function(function.clone(), scope.clone()));
+      }
+    }
+    for stmt in body {
+      match self.exec(stmt, scope)? {
+        Flow::Normal => {}
+        flow => return Ok(flow),
+      }
+    }
+    Ok(Flow::Normal)
+  }
+
+  fn exec(&mut self, stmt: &Stmt, scope: &Rc<Scope>) -> Result<Flow, String> {
+    self.steps += 1;
+    if self.steps > MAX_STEPS {
+      return Err(format!(
+        "RangeError: Maximum number of {MAX_STEPS} steps exceeded, check for endless loops"
+      ));
+    }
+    match stmt {
+      Stmt::Text(text) => self.write(text),
+      Stmt::Output(kind, expr) => {
+        let value = self.eval(expr, scope)?;
+        match (&value, kind) {
+          (Value::Undefined | Value::Null, _) => {}
+          (Value::Html(html), _) => self.write(html),
+          (_, OutputKind::Raw) => self.write(&value.to_js_string()),
+          (_, OutputKind::Escaped) => self.write(&escape_html(&value.to_lodash_string())),
+        }
+      }
+      Stmt::Expr(expr) => {
+        self.eval(expr, scope)?;
+      }
+      Stmt::Var(declarations) => {
+        for (name, init) in declarations {
+          let value = match init {
+            Some(init) => self.eval(init, scope)?,
+            None => Value::Undefined,
+          };
+          scope.declare(name, value);
+        }
+      }
+      Stmt::Function(..) | Stmt::Empty => {}
+      Stmt::Block(body) => return self.exec_block(body, scope),
+      Stmt::If(test, consequent, alternate) => {
+        if self.eval(test, scope)?.truthy() {
+          return self.exec(consequent, scope);
+        } else if let Some(alternate) = alternate {
+          return self.exec(alternate, scope);
+        }
+      }
+      Stmt::While(test, body) => {
+        while self.eval(test, scope)?.truthy() {
+          match self.exec(body, scope)? {
+            Flow::Break => break,
+            Flow::Return(value) => return Ok(Flow::Return(value)),
+            Flow::Normal | Flow::Continue => {}
+          }
+        }
+      }
+      Stmt::For {
+        init,
+        test,
+        update,
+        body,
+      } => {
+        if let Some(init) = init {
+          self.exec(init, scope)?;
+        }
+        loop {
+          if let Some(test) = test
+            && !self.eval(test, scope)?.truthy()
+          {
+            break;
+          }
+          match self.exec(body, scope)? {
+            Flow::Break => break,
+            Flow::Return(value) => return Ok(Flow::Return(value)),
+            Flow::Normal | Flow::Continue => {}
+          }
+          if let Some(update) = update {
+            self.eval(update, scope)?;
+          }
+        }
+      }
+      Stmt::ForOf(name, iterable, body) => {
+        let items = match self.eval(iterable, scope)? {
+          Value::Array(items) => items.borrow().clone(),
+          Value::String(s) | Value::Html(s) => {
+            s.chars().map(|c| Value::string(c.to_string())).collect()
+          }
+          value => {
+            return Err(format!(
+              "TypeError: {} is not iterable",
+              describe(iterable, &value)
+            ))
+          }
+        };
+        for item in items {
+          scope.declare(name, item);
+          match self.exec(body, scope)? {
+            Flow::Break => break,
+            Flow::Return(value) => return Ok(Flow::Return(value)),
+            Flow::Normal | Flow::Continue => {}
+          }
+        }
+      }
+      Stmt::ForIn(name, object, body) => {
+        let keys: Vec<Value> = match self.eval(object, scope)? {
+          Value::Object(properties) => properties
+            .borrow()
+            .keys()
+            .map(|key| Value::string(key.as_str()))
+            .collect(),
+          Value::Array(items) => (0..items.borrow().len())
+            .map(|index| Value::string(index.to_string()))
+            .collect(),
+          Value::String(s) | Value::Html(s) => (0..s.chars().count())
+            .map(|index| Value::string(index.to_string()))
+            .collect(),
+          _ => vec![],
+        };
+        for key in keys {
+          scope.declare(name, key);
+          match self.exec(body, scope)? {
+            Flow::Break => break,
+            Flow::Return(value) => return Ok(Flow::Return(value)),
+            Flow::Normal | Flow::Continue => {}
+          }
+        }
+      }
+      Stmt::Break => return Ok(Flow::Break),
+      Stmt::Continue => return Ok(Flow::Continue),
+      Stmt::Return(argument) => {
+        let value = match argument {
+          Some(argument) => self.eval(argument, scope)?,
+          None => Value::Undefined,
+        };
+        return Ok(Flow::Return(value));
+      }
+    }
+    Ok(Flow::Normal)
+  }
+
+  fn lookup(&self, name: &str, scope: &Scope) -> Option<Value> {
+    if let Some(value) = scope.lookup(name) {
+      return Some(value);
+    }
+    if let Some(Frame {
+      data: Value::Object(data),
+      ..
+    }) = self.frames.last()
+      && let Some(value) = data.borrow().get(name)
+    {
+      return Some(value.clone());
+    }
+    self.globals.get(name).cloned()
+  }
+
+  fn eval(&mut self, expr: &Expr, scope: &Rc<Scope>) -> Result<Value, String> {
+    Ok(match expr {
+      Expr::Undefined => Value::Undefined,
+      Expr::Null => Value::Null,
+      Expr::Bool(b) => Value::Bool(*b),
+      Expr::Number(n) => Value::Number(*n),
+      Expr::String(s) => Value::string(s.as_str()),
+      Expr::Template(quasis, exprs) => {
+        let mut result = String::new();
+        for (index, quasi) in quasis.iter().enumerate() {
+          result.push_str(quasi);
+          if let Some(expr) = exprs.get(index) {
+            result.push_str(&self.eval(expr, scope)?.to_js_string());
+          }
+        }
+        Value::string(result)
+      }
+      Expr::Ident(name) => self
+        .lookup(name, scope)
+        .ok_or_else(|| format!("ReferenceError: {name} is not defined"))?,
+      Expr::Array(elements) => Value::array(
+        elements
+          .iter()
+          .map(|element| self.eval(element, scope))
+          .collect::<Result<_, _>>()?,
+      ),
+      Expr::Object(properties) => Value::object(
+        properties
+          .iter()
+          .map(|(key, value)| Ok((key.clone(), self.eval(value, scope)?)))
+          .collect::<Result<_, String>>()?,
+      ),
+      Expr::Member { .. } | Expr::Call { .. } => {
+        self.eval_chain(expr, scope)?.unwrap_or(Value::Undefined)
+      }
+      Expr::Unary(UnaryOp::Typeof, box Expr::Ident(name)) => Value::string(
+        self
+          .lookup(name, scope)
+          .map_or("undefined", |value| value.type_of()),
+      ),
+      Expr::Unary(op, argument) => {
+        let value = self.eval(argument, scope)?;
+        match op {
+          UnaryOp::Not => Value::Bool(!value.truthy()),
+          UnaryOp::Neg => Value::Number(-value.to_number()),
+          UnaryOp::Plus => Value::Number(value.to_number()),
+          UnaryOp::Typeof => Value::string(value.type_of()),
+        }
+      }
+      Expr::Update {
+        increment,
+        prefix,
+        target,
+      } => {
+        let old = self.eval(target, scope)?.to_number();
+        let new = if *increment { old + 1.0 } else { old - 1.0 };
+        self.assign(target, Value::Number(new), scope)?;
+        Value::Number(if *prefix { new } else { old })
+      }
+      Expr::Binary(op, left, right) => {
+        let left = self.eval(left, scope)?;
+        let right = self.eval(right, scope)?;
+        binary(*op, &left, &right)?
+      }
+      Expr::Logical(op, left, right) => {
+        let left = self.eval(left, scope)?;
+        let short_circuit = match op {
+          LogicalOp::And => !left.truthy(),
+          LogicalOp::Or => left.truthy(),
+          LogicalOp::Nullish => !left.is_nullish(),
+        };
+        if short_circuit {
+          left
+        } else {
+          self.eval(right, scope)?
+        }
+      }
+      Expr::Conditional(test, consequent, alternate) => {
+        if self.eval(test, scope)?.truthy() {
+          self.eval(consequent, scope)?
+        } else {
+          self.eval(alternate, scope)?
+        }
+      }
+      Expr::Assign(op, target, value) => {
+        let value = self.eval(value, scope)?;
+        let value = match op {
+          Some(op) => binary(*op, &self.eval(target, scope)?, &value)?,
+          None => value,
+        };
+        self.assign(target, value.clone(), scope)?;
+        value
+      }
+      Expr::Function(function) => Value::Function(function.clone(), scope.clone()),
+      Expr::Sequence(exprs) => {
+        let mut value = Value::Undefined;
+        for expr in exprs {
+          value = self.eval(expr, scope)?;
+        }
+        value
+      }
+    })
+  }
+
+  /// Evaluates member expressions and calls, `None` means an optional chain is short-circuited.
+  fn eval_chain(&mut self, expr: &Expr, scope: &Rc<Scope>) -> Result<Option<Value>, String> {
+    match expr {
+      Expr::Member {
+        object: object_expr,
+        property,
+        optional,
+      } => {
+        let Some(object) = self.eval_object(object_expr, scope)? else {
+          return Ok(None);
+        };
+        if *optional && object.is_nullish() {
+          return Ok(None);
+        }
+        let key = self.eval(property, scope)?;
+        get(&object, &key).map(Some)
+      }
+      Expr::Call {
+        callee,
+        args,
+        optional,
+      } => {
+        let (this, function) = match &**callee {
+          Expr::Member {
+            object: object_expr,
+            property,
+            optional: optional_member,
+          } => {
+            let Some(object) = self.eval_object(object_expr, scope)? else {
+              return Ok(None);
+            };
+            if *optional_member && object.is_nullish() {
+              return Ok(None);
+            }
+            let key = self.eval(property, scope)?;
+            let function = get(&object, &key)?;
+            (object, function)
+          }
+          callee => match self.eval_object(callee, scope)? {
+            Some(function) => (Value::Undefined, function),
+            None => return Ok(None),
+          },
+        };
+        if *optional && function.is_nullish() {
+          return Ok(None);
+        }
+        if !function.is_function() {
+          return Err(format!(
+            "TypeError: {} is not a function",
+            describe(callee, &function)
+          ));
+        }
+        let args = args
+          .iter()
+          .map(|arg| self.eval(arg, scope))
+          .collect::<Result<Vec<_>, _>>()?;
+        self.call(&function, this, args).map(Some)
+      }
+      expr => self.eval(expr, scope).map(Some),
+    }
+  }
+
+  fn eval_object(&mut self, expr: &Expr, scope: &Rc<Scope>) -> Result<Option<Value>, String> {
+    match expr {
+      Expr::Member { .. } | Expr::Call { .. } => self.eval_chain(expr, scope),
+      expr => self.eval(expr, scope).map(Some),
+    }
+  }
+
+  pub fn call(&mut self, function: &Value, this: Value, args: Vec<Value>) -> Result<Value, String> {
+    match function {
+      Value::Native(native) => native(self, this, args),
+      Value::Function(function, closure) => {
+        if self.call_depth >= MAX_CALL_DEPTH {
+          return Err("RangeError: Maximum call stack size exceeded".to_string());
+        }
+        self.call_depth += 1;
+        let result = self.call_function(function, closure, args);
+        self.call_depth -= 1;
+        result
+      }
+      value => Err(format!(
+        "TypeError: {} is not a function",
+        value.to_js_string()
+      )),
+    }
+  }
+
+  fn call_function(
+    &mut self,
+    function: &FunctionDef,
+    closure: &Rc<Scope>,
+    args: Vec<Value>,
+  ) -> Result<Value, String> {
+    let scope = Scope::new(Some(closure.clone()));
+    let mut args = args.into_iter();
+    for param in &function.params {
+      scope.declare(param, args.next().unwrap_or(Value::Undefined));
+    }
+    match &function.body {
+      FunctionBody::Expr(expr) => self.eval(expr, &scope),
+      FunctionBody::Block(body) => match self.exec_block(body, &scope)? {
+        Flow::Return(value) => Ok(value),
+        _ => Ok(Value::Undefined),
+      },
+    }
+  }
+
+  fn assign(&mut self, target: &Expr, value: Value, scope: &Rc<Scope>) -> Result<(), String> {
+    match target {
+      Expr::Ident(name) => {
+        let Err(value) = scope.assign(name, value) else {
+          return Ok(());
+        };
+        if let Some(Frame {
+          data: Value::Object(data),
+          ..
+        }) = self.frames.last()
+          && let Some(slot) = data.borrow_mut().get_mut(name)
+        {
+          *slot = value;
+          return Ok(());
+        }
+        // assigning an undeclared variable creates a global one
+        scope.root().declare(name, value);
+        Ok(())
+      }
+      Expr::Member {
+        object: object_expr,
+        property,
+        ..
+      } => {
+        let object = self.eval(object_expr, scope)?;
+        let key = self.eval(property, scope)?.to_js_string();
+        match object {
+          Value::Object(properties) => {
+            properties.borrow_mut().insert(key, value);
+          }
+          Value::Array(items) => {
+            let mut items = items.borrow_mut();
+            if key == "length" {
+              items.resize(value.to_number() as usize, Value::Undefined);
+            } else if let Ok(index) = key.parse::<usize>() {
+              if index >= items.len() {
+                items.resize(index + 1, Value::Undefined);
+              }
+              items[index] = value;
+            }
+          }
+          Value::Undefined | Value::Null => {
+            return Err(format!(
+              "TypeError: Cannot set properties of {} (setting '{key}')",
+              object.to_js_string()
+            ));
+          }
+          _ => {}
+        }
+        Ok(())
+      }
+      _ => Err("SyntaxError: Invalid left-hand side in assignment".to_string()),
+    }
+  }
+}
+
+fn get(object: &Value, key: &Value) -> Result<Value, String> {
+  let key = key.to_js_string();
+  if object.is_nullish() {
+    return Err(format!(
+      "TypeError: Cannot read properties of {} (reading '{key}')",
+      object.to_js_string()
+    ));
+  }
+  Ok(builtins::get_property(object, &key))
+}
+
+fn binary(op: BinaryOp, left: &Value, right: &Value) -> Result<Value, String> {
+  Ok(match op {
+    BinaryOp::Add => {
+      let is_string = |value: &Value| {
+        matches!(
+          value,
+          Value::String(_) | Value::Html(_) | Value::Array(_) | Value::Object(_)
+        )
+      };
+      if is_string(left) || is_string(right) {
+        Value::string(left.to_js_string() + right.to_js_string().as_str())
+      } else {
+        Value::Number(left.to_number() + right.to_number())
+      }
+    }
+    BinaryOp::Sub => Value::Number(left.to_number() - right.to_number()),
+    BinaryOp::Mul => Value::Number(left.to_number() * right.to_number()),
+    BinaryOp::Div => Value::Number(left.to_number() / right.to_number()),
+    BinaryOp::Rem => Value::Number(left.to_number() % right.to_number()),
+    BinaryOp::Pow => Value::Number(left.to_number().powf(right.to_number())),
+    BinaryOp::Eq => Value::Bool(left.loose_equals(right)),
+    BinaryOp::NotEq => Value::Bool(!left.loose_equals(right)),
+    BinaryOp::StrictEq => Value::Bool(left.strict_equals(right)),
+    BinaryOp::StrictNotEq => Value::Bool(!left.strict_equals(right)),
+    BinaryOp::Lt | BinaryOp::Gt | BinaryOp::LtEq | BinaryOp::GtEq => {
+      let ordering = match (left.as_str(), right.as_str()) {
+        (Some(left), Some(right)) => Some(left.cmp(right)),
+        _ => left.to_number().partial_cmp(&right.to_number()),
+      };
+      Value::Bool(ordering.is_some_and(|ordering| match op {
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::Gt => ordering.is_gt(),
+        BinaryOp::LtEq => ordering.is_le(),
+        _ => ordering.is_ge(),
+      }))
+    }
+    BinaryOp::In => {
+      let key = left.to_js_string();
+      match right {
+        Value::Object(properties) => Value::Bool(properties.borrow().contains_key(&key)),
+        Value::Array(items) => Value::Bool(
+          key == "length"
+            || key
+              .parse::<usize>()
+              .is_ok_and(|index| index < items.borrow().len()),
+        ),
+        value => {
+          return Err(format!(
+            "TypeError: Cannot use 'in' operator to search for '{key}' in {}",
+            value.to_js_string()
+          ))
+        }
+      }
+    }
+  })
+}
+
+/// Describes an expression for error messages, e.g. `htmlRspackPlugin.files is not iterable`.
+fn describe(expr: &Expr, value: &Value) -> String {
+  expr_source(expr).unwrap_or_else(|| value.to_js_string())
+}
+
+fn expr_source(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Ident(name) => Some(name.clone()),
+    Expr::Member {
+      object,
+      property: box Expr::String(name),
+      optional,
+    } => Some(format!(
+      "{}{}{name}",
+      expr_source(object)?,
+      if *optional { "?." } else { "." }
+    )),
+    Expr::Member {
+      object,
+      property: box Expr::Number(index),
+      ..
+    } => Some(format!(
+      "{}[{}]",
+      expr_source(object)?,
+      super::value::number_to_string(*index)
+    )),
+    _ => None,
+  }
+}
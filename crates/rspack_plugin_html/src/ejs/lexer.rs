@@ -0,0 +1,433 @@
+use std::{iter::Peekable, str::CharIndices};
+
+/// The kind of an output tag.
+///
+/// Follows lodash.template, which is what html-webpack-plugin renders templates with:
+/// `<%= %>` interpolates as is and `<%- %>` escapes html.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+  Raw,
+  Escaped,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+  /// Plain text between tags.
+  Text(String),
+  /// Start of an output tag, the expression tokens follow until [TokenKind::OutputEnd].
+  OutputStart(OutputKind),
+  OutputEnd,
+  Ident(String),
+  Number(f64),
+  String(String),
+  /// A template literal, the source of each `${}` is lexed again by the parser.
+  Template {
+    quasis: Vec<String>,
+    exprs: Vec<String>,
+  },
+  Punct(&'static str),
+  Eof,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+  pub kind: TokenKind,
+  /// Whether a line break or a tag boundary precedes the token, used for automatic semicolon insertion.
+  pub newline_before: bool,
+  /// Line of the token in the template, starting from 1.
+  pub line: usize,
+}
+
+const PUNCTUATORS: &[&str] = &[
+  "===", "!==", "...", "**", "=>", "==", "!=", "<=", ">=", "&&", "||", "??", "?.", "++", "--",
+  "+=", "-=", "*=", "/=", "%=", "{", "}", "(", ")", "[", "]", ";", ",", ".", "?", ":", "+", "-",
+  "*", "/", "%", "<", ">", "=", "!",
+];
+
+/// Splits a template into text and tags, and lexes the code inside the tags.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+  let mut tokens = vec![];
+  let mut rest = source;
+  let mut line = 1;
+  let mut text = String::new();
+  let mut trim_leading_whitespace = false;
+
+  while !rest.is_empty() {
+    let Some(start) = rest.find("<%") else {
+      text.push_str(rest);
+      break;
+    };
+    text.push_str(&rest[..start]);
+    let after_open = &rest[start + 2..];
+
+    // `<%%` renders a literal `<%`
+    if let Some(after) = after_open.strip_prefix('%') {
+      text.push_str("<%");
+      rest = after;
+      continue;
+    }
+
+    let (marker, code_start) = match after_open.chars().next() {
+      Some(c @ ('=' | '-' | '_' | '#')) => (Some(c), 1),
+      _ => (None, 0),
+    };
+    if marker == Some('_') {
+      // `<%_` strips all the whitespace before it
+      let trimmed_len = text.trim_end_matches([' ', '\t']).len();
+      text.truncate(trimmed_len);
+    }
+
+    if trim_leading_whitespace {
+      text = text.trim_start().to_string();
+      trim_leading_whitespace = false;
+    }
+    line += flush_text(&mut tokens, &mut text, line);
+
+    let after_marker = &after_open[code_start..];
+    let Some(end) = after_marker.find("%>") else {
+      return Err(format!(
+        "Could not find matching close tag for \"<%\" at line {line}"
+      ));
+    };
+    let mut code = &after_marker[..end];
+    rest = &after_marker[end + 2..];
+
+    // `-%>` trims the following newline and `_%>` trims all the following whitespace
+    if let Some(stripped) = code.strip_suffix('-') {
+      code = stripped;
+      rest = rest
+        .strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))
+        .unwrap_or(rest);
+    } else if let Some(stripped) = code.strip_suffix('_') {
+      code = stripped;
+      trim_leading_whitespace = true;
+    }
+
+    match marker {
+      Some('#') => {}
+      Some('=') => {
+        push_output(&mut tokens, OutputKind::Raw, code, line)?;
+      }
+      Some('-') => {
+        push_output(&mut tokens, OutputKind::Escaped, code, line)?;
+      }
+      _ => {
+        let start = tokens.len();
+        lex_code(code, line, &mut tokens)?;
+        mark_boundary(&mut tokens, start);
+      }
+    }
+    line += code.matches('\n').count();
+  }
+
+  if trim_leading_whitespace {
+    text = text.trim_start().to_string();
+  }
+  flush_text(&mut tokens, &mut text, line);
+  tokens.push(Token {
+    kind: TokenKind::Eof,
+    newline_before: true,
+    line,
+  });
+  Ok(tokens)
+}
+
+fn flush_text(tokens: &mut Vec<Token>, text: &mut String, line: usize) -> usize {
+  if text.is_empty() {
+    return 0;
+  }
+  let lines = text.matches('\n').count();
+  tokens.push(Token {
+    kind: TokenKind::Text(std::mem::take(text)),
+    newline_before: true,
+    line,
+  });
+  lines
+}
+
+fn push_output(
+  tokens: &mut Vec<Token>,
+  kind: OutputKind,
+  code: &str,
+  line: usize,
+) -> Result<(), String> {
+  tokens.push(Token {
+    kind: TokenKind::OutputStart(kind),
+    newline_before: true,
+    line,
+  });
+  lex_code(code, line, tokens)?;
+  tokens.push(Token {
+    kind: TokenKind::OutputEnd,
+    newline_before: true,
+    line: line + code.matches('\n').count(),
+  });
+  Ok(())
+}
+
+/// The boundary of a tag terminates a statement just like a line break does.
+fn mark_boundary(tokens: &mut [Token], start: usize) {
+  if let Some(first) = tokens.get_mut(start) {
+    first.newline_before = true;
+  }
+}
+
+/// Lexes javascript code, used for tags and for the expressions of template literals.
+pub fn lex_code(code: &str, mut line: usize, tokens: &mut Vec<Token>) -> Result<(), String> {
+  let mut chars = code.char_indices().peekable();
+  let mut newline_before = false;
+
+  while let Some(&(index, c)) = chars.peek() {
+    if c == '\n' {
+      line += 1;
+      newline_before = true;
+      chars.next();
+      continue;
+    }
+    if c.is_whitespace() {
+      chars.next();
+      continue;
+    }
+
+    let rest = &code[index..];
+    if rest.starts_with("//") {
+      while let Some(&(_, c)) = chars.peek() {
+        if c == '\n' {
+          break;
+        }
+        chars.next();
+      }
+      continue;
+    }
+    if rest.starts_with("/*") {
+      let Some(end) = rest.find("*/") else {
+        return Err(format!("Unterminated comment at line {line}"));
+      };
+      line += rest[..end].matches('\n').count();
+      advance(&mut chars, index + end + 2);
+      continue;
+    }
+
+    let kind = if c.is_ascii_digit() || (c == '.' && next_is_digit(rest)) {
+      let len = number_len(rest);
+      let literal = &rest[..len];
+      advance(&mut chars, index + len);
+      TokenKind::Number(
+        parse_number(literal)
+          .ok_or_else(|| format!("SyntaxError: Invalid number \"{literal}\" at line {line}"))?,
+      )
+    } else if c.is_alphabetic() || c == '_' || c == '$' {
+      let len = rest
+        .char_indices()
+        .find(|&(_, c)| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .map(|(i, _)| i)
+        .unwrap_or(rest.len());
+      advance(&mut chars, index + len);
+      TokenKind::Ident(rest[..len].to_string())
+    } else if c == '"' || c == '\'' {
+      chars.next();
+      TokenKind::String(lex_string(&mut chars, c, &mut line)?)
+    } else if c == '`' {
+      chars.next();
+      lex_template(code, &mut chars, &mut line)?
+    } else if let Some(punct) = PUNCTUATORS.iter().find(|p| rest.starts_with(**p)) {
+      advance(&mut chars, index + punct.len());
+      TokenKind::Punct(punct)
+    } else {
+      return Err(format!(
+        "SyntaxError: Unexpected character \"{c}\" at line {line}"
+      ));
+    };
+
+    tokens.push(Token {
+      kind,
+      newline_before,
+      line,
+    });
+    newline_before = false;
+  }
+  Ok(())
+}
+
+fn next_is_digit(rest: &str) -> bool {
+  rest[1..].chars().next().is_some_and(|c| c.is_ascii_digit())
+}
+
+fn advance(chars: &mut Peekable<CharIndices>, to: usize) {
+  while let Some(&(i, _)) = chars.peek() {
+    if i >= to {
+      break;
+    }
+    chars.next();
+  }
+}
+
+fn number_len(rest: &str) -> usize {
+  let bytes = rest.as_bytes();
+  let digits = |mut i: usize, hex: bool| {
+    while i < bytes.len()
+      && (bytes[i] == b'_'
+        || if hex {
+          bytes[i].is_ascii_hexdigit()
+        } else {
+          bytes[i].is_ascii_digit()
+        })
+    {
+      i += 1;
+    }
+    i
+  };
+  if bytes.len() > 1 && bytes[0] == b'0' && matches!(bytes[1], b'x' | b'X') {
+    return digits(2, true);
+  }
+  let mut i = digits(0, false);
+  if i < bytes.len() && bytes[i] == b'.' {
+    i = digits(i + 1, false);
+  }
+  if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+    let mut j = i + 1;
+    if j < bytes.len() && matches!(bytes[j], b'+' | b'-') {
+      j += 1;
+    }
+    if j < bytes.len() && bytes[j].is_ascii_digit() {
+      i = digits(j, false);
+    }
+  }
+  i
+}
+
+fn parse_number(literal: &str) -> Option<f64> {
+  let literal = literal.chars().filter(|c| *c != '_').collect::<String>();
+  if let Some(hex) = literal
+    .strip_prefix("0x")
+    .or_else(|| literal.strip_prefix("0X"))
+  {
+    return u64::from_str_radix(hex, 16).ok().map(|n| n as f64);
+  }
+  literal.parse::<f64>().ok()
+}
+
+fn lex_escape(chars: &mut Peekable<CharIndices>, out: &mut String, line: &mut usize) {
+  let Some((_, c)) = chars.next() else {
+    return;
+  };
+  match c {
+    'n' => out.push('\n'),
+    't' => out.push('\t'),
+    'r' => out.push('\r'),
+    'b' => out.push('\u{8}'),
+    'f' => out.push('\u{c}'),
+    'v' => out.push('\u{b}'),
+    '0' => out.push('\0'),
+    'u' => {
+      let mut hex = String::new();
+      if chars.peek().is_some_and(|&(_, c)| c == '{') {
+        chars.next();
+        for (_, c) in chars.by_ref() {
+          if c == '}' {
+            break;
+          }
+          hex.push(c);
+        }
+      } else {
+        for _ in 0..4 {
+          if let Some((_, c)) = chars.next() {
+            hex.push(c);
+          }
+        }
+      }
+      if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+        out.push(c);
+      }
+    }
+    'x' => {
+      let hex: String = (0..2)
+        .filter_map(|_| chars.next().map(|(_, c)| c))
+        .collect();
+      if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+        out.push(c);
+      }
+    }
+    '\n' => *line += 1,
+    c => out.push(c),
+  }
+}
+
+fn lex_string(
+  chars: &mut Peekable<CharIndices>,
+  quote: char,
+  line: &mut usize,
+) -> Result<String, String> {
+  let mut value = String::new();
+  loop {
+    match chars.next() {
+      Some((_, '\\')) => lex_escape(chars, &mut value, line),
+      Some((_, c)) if c == quote => return Ok(value),
+      Some((_, '\n')) | None => {
+        return Err(format!("SyntaxError: Unterminated string at line {line}"));
+      }
+      Some((_, c)) => value.push(c),
+    }
+  }
+}
+
+fn lex_template(
+  code: &str,
+  chars: &mut Peekable<CharIndices>,
+  line: &mut usize,
+) -> Result<TokenKind, String> {
+  let mut quasis = vec![];
+  let mut exprs = vec![];
+  let mut current = String::new();
+  loop {
+    match chars.next() {
+      Some((_, '\\')) => lex_escape(chars, &mut current, line),
+      Some((_, '`')) => {
+        quasis.push(current);
+        return Ok(TokenKind::Template { quasis, exprs });
+      }
+      Some((start, '$')) if chars.peek().is_some_and(|&(_, c)| c == '{') => {
+        chars.next();
+        let expr_start = start + 2;
+        let mut depth = 1;
+        let mut expr_end = None;
+        while let Some((i, c)) = chars.next() {
+          match c {
+            '{' => depth += 1,
+            '}' => {
+              depth -= 1;
+              if depth == 0 {
+                expr_end = Some(i);
+                break;
+              }
+            }
+            '"' | '\'' => {
+              lex_string(chars, c, line)?;
+            }
+            '\n' => *line += 1,
+            _ => {}
+          }
+        }
+        let Some(expr_end) = expr_end else {
+          return Err(format!(
+            "SyntaxError: Unterminated template literal at line {line}"
+          ));
+        };
+        quasis.push(std::mem::take(&mut current));
+        exprs.push(code[expr_start..expr_end].to_string());
+      }
+      Some((_, c)) => {
+        if c == '\n' {
+          *line += 1;
+        }
+        current.push(c);
+      }
+      None => {
+        return Err(format!(
+          "SyntaxError: Unterminated template literal at line {line}"
+        ));
+      }
+    }
+  }
+}
@@ -0,0 +1,249 @@
+//! A native renderer of html templates, compatible with the templates of html-webpack-plugin.
+//!
+//! Templates use the tags of lodash.template and EJS:
+//! - `<%= value %>` interpolates a value as is and `<%- value %>` escapes it
+//! - `<% code %>` runs javascript, e.g. loops and conditionals
+//! - `<%# comment %>`, `<%%` and the whitespace trimming tags `<%_`, `_%>` and `-%>`
+//! - `include(path, data)` renders another template, paths are relative to the current template
+//!
+//! The javascript inside the tags is interpreted natively and covers the subset of the language
+//! used by templates, the `for item in list {` syntax of the previous renderer is also supported.
+
+mod builtins;
+mod interpreter;
+mod lexer;
+mod parser;
+mod value;
+
+use std::path::{Path, PathBuf};
+
+use interpreter::Interpreter;
+use parser::{Parser, Stmt};
+pub use value::Value;
+
+/// A parsed template, which can be rendered multiple times.
+pub struct Template {
+  body: Vec<Stmt>,
+}
+
+impl Template {
+  pub fn parse(source: &str) -> Result<Self, String> {
+    let tokens = lexer::tokenize(source)?;
+    let body = Parser::new(tokens).parse_program()?;
+    Ok(Self { body })
+  }
+}
+
+#[derive(Default)]
+pub struct Renderer {
+  interpreter: Interpreter,
+}
+
+impl Renderer {
+  /// Registers a function that can be called in templates.
+  pub fn register_function(&mut self, name: &str, f: impl Fn(&[Value]) -> Value + 'static) {
+    self
+      .interpreter
+      .define(name, Value::native(move |_, _, args| Ok(f(&args))));
+  }
+
+  /// Renders the template with the properties of `data` as variables,
+  /// templates included by relative paths are resolved from `context`.
+  pub fn render(
+    &mut self,
+    template: &Template,
+    context: &Path,
+    data: &serde_json::Value,
+  ) -> Result<String, String> {
+    self
+      .interpreter
+      .render(&template.body, context, Value::from_json(data))
+  }
+
+  /// The files included by the rendered templates.
+  pub fn file_dependencies(self) -> Vec<PathBuf> {
+    self.interpreter.into_file_dependencies()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use serde_json::json;
+
+  use super::*;
+
+  fn render(source: &str, data: serde_json::Value) -> Result<String, String> {
+    let template = Template::parse(source)?;
+    Renderer::default().render(&template, Path::new("/"), &data)
+  }
+
+  #[test]
+  fn interpolate_and_escape() {
+    let data = json!({ "title": "<b>rspack</b>", "count": 3 });
+    assert_eq!(
+      render("<%= title %>|<%- title %>|<%= count * 2 %>", data).unwrap(),
+      "<b>rspack</b>|&lt;b&gt;rspack&lt;/b&gt;|6"
+    );
+    assert_eq!(
+      render("<%= missing %>", json!({})).unwrap_err(),
+      "ReferenceError: missing is not defined"
+    );
+    assert_eq!(
+      render(
+        "[<%= typeof missing %>][<%= null %>][<%- undefined %>]",
+        json!({})
+      )
+      .unwrap(),
+      "[undefined][][]"
+    );
+  }
+
+  #[test]
+  fn lodash_parity() {
+    // the expected output is rendered by `_.template` of lodash, which html-webpack-plugin uses
+    let data = json!({
+      "s": "<a href=\"x\">Tom & 'Jerry'</a>",
+      "n": 1.5,
+      "b": false,
+      "nil": null,
+      "zero": -0.0,
+      "list": ["<", null, 3],
+      "nested": [[1, 2], [3]],
+      "obj": { "a": 1 }
+    });
+    let source = r#"<%- s %>|<%= s %>|<%- n %>|<%= n %>|<%- b %>|<%= b %>|<%- nil %>|<%= nil %>|<%- zero %>|<%= zero %>|<%- list %>|<%= list %>|<%= nested %>|<%- obj %>|<%= `${s.length}:${n * 2}` %>|<% list.forEach(function (item, i) { %>[<%= i %>=<%- item %>]<% }) %>|<%= list.length > 2 ? 'many' : 'few' %>|<%- s.toUpperCase() %>"#;
+    assert_eq!(
+      render(source, data).unwrap(),
+      "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;|<a href=\"x\">Tom & 'Jerry'</a>|1.5|1.5|false|false|||-0|0|&lt;,null,3|<,,3|1,2,3|[object Object]|29:3|[0=&lt;][1=][2=3]|many|&lt;A HREF=&quot;X&quot;&gt;TOM &amp; &#39;JERRY&#39;&lt;/A&gt;"
+    );
+  }
+
+  #[test]
+  fn loops_and_conditionals() {
+    let data = json!({
+      "htmlRspackPlugin": { "files": { "js": ["a.js", "b.js"], "css": [] } }
+    });
+    let source = r#"<% htmlRspackPlugin.files.js.forEach(function (file, i) { -%>
+<script src="<%= file %>" data-index="<%= i %>"></script>
+<% }) -%>
+<% for (const file of htmlRspackPlugin.files.css) { %><link href="<%= file %>"><% } %>
+<% if (htmlRspackPlugin.files.css.length === 0) { %>no css<% } else { %>css<% } %>"#;
+    assert_eq!(
+      render(source, data.clone()).unwrap(),
+      "<script src=\"a.js\" data-index=\"0\"></script>\n<script src=\"b.js\" data-index=\"1\"></script>\n\nno css"
+    );
+
+    let source = r#"<% for (var i = 0; i < 5; i++) { if (i == 1) continue; if (i > 3) break; %><%= i %><% } %>"#;
+    assert_eq!(render(source, json!({})).unwrap(), "023");
+
+    let source = r#"<%= htmlRspackPlugin.files.js.map((file) => `<${file}>`).join(", ") %>"#;
+    assert_eq!(render(source, data).unwrap(), "<a.js>, <b.js>");
+  }
+
+  #[test]
+  fn legacy_syntax() {
+    let data = json!({ "tags": [{ "tagName": "script" }, { "tagName": "link" }] });
+    let source =
+      r#"<% for tag in tags { %><% if tag.tagName=="script" { %><%= tag.tagName %><% } %><% } %>"#;
+    assert_eq!(render(source, data).unwrap(), "script");
+  }
+
+  #[test]
+  fn whitespace_control() {
+    let source = "<ul>\n  <%_ for (const item of items) { _%>\n  <li><%= item %></li>\n  <%_ } _%>\n</ul><%# comment %><%%";
+    assert_eq!(
+      render(source, json!({ "items": [1, 2] })).unwrap(),
+      "<ul>\n<li>1</li>\n<li>2</li>\n</ul><%"
+    );
+  }
+
+  #[test]
+  fn functions_and_errors() {
+    let source = r#"<% function item(name) { %><li><%- name %></li><% } %><% item("a&b") %><%= JSON.stringify({ a: [1, "2"] }) %>"#;
+    assert_eq!(
+      render(source, json!({})).unwrap(),
+      "<li>a&amp;b</li>{\"a\":[1,\"2\"]}"
+    );
+    assert_eq!(
+      render("<%= options.title %>", json!({ "options": null })).unwrap_err(),
+      "TypeError: Cannot read properties of null (reading 'title')"
+    );
+    assert_eq!(
+      render(
+        "<%= options?.title ?? 'default' %>",
+        json!({ "options": null })
+      )
+      .unwrap(),
+      "default"
+    );
+    assert!(render("<% if (true) { %>", json!({})).is_err());
+  }
+
+  #[test]
+  fn endless_loops() {
+    assert_eq!(
+      render("<% while (true) {} %>", json!({})).unwrap_err(),
+      "RangeError: Maximum number of 10000000 steps exceeded, check for endless loops"
+    );
+    assert!(render("<% for (;;); %>", json!({})).is_err());
+    // the budget is reset for each render
+    let template = Template::parse("<% for (var i = 0; i < 4000000; i++) {} %>done").unwrap();
+    let mut renderer = Renderer::default();
+    for _ in 0..3 {
+      assert_eq!(
+        renderer
+          .render(&template, Path::new("/"), &json!({}))
+          .unwrap(),
+        "done"
+      );
+    }
+  }
+
+  #[test]
+  fn include() {
+    let dir = std::env::temp_dir().join(format!("rspack_html_ejs_{}", std::process::id()));
+    fs::create_dir_all(dir.join("partials")).unwrap();
+    fs::write(
+      dir.join("partials/header.ejs"),
+      "<h1><%- title %></h1><%- include('./nav', { active: 'home' }) %>",
+    )
+    .unwrap();
+    fs::write(dir.join("partials/nav.ejs"), "<nav><%= active %></nav>").unwrap();
+
+    let template = Template::parse("<%- include('partials/header') %><%= title %>").unwrap();
+    let mut renderer = Renderer::default();
+    let html = renderer
+      .render(&template, &dir, &json!({ "title": "a<b" }))
+      .unwrap();
+    assert_eq!(html, "<h1>a&lt;b</h1><nav>home</nav>a<b");
+    assert_eq!(
+      renderer.file_dependencies(),
+      vec![
+        dir.join("partials/header.ejs"),
+        dir.join("partials/nav.ejs")
+      ]
+    );
+
+    // the files failed to include are still dependencies, so that fixing them triggers a rebuild
+    let template = Template::parse("<%- include('missing.ejs') %>").unwrap();
+    let mut renderer = Renderer::default();
+    assert!(renderer
+      .render(&template, &dir, &json!({}))
+      .unwrap_err()
+      .contains("could not include \"missing.ejs\""));
+    assert_eq!(renderer.file_dependencies(), vec![dir.join("missing.ejs")]);
+
+    fs::write(dir.join("partials/broken.ejs"), "<% if (true) { %>").unwrap();
+    let template = Template::parse("<%- include('partials/broken') %>").unwrap();
+    let mut renderer = Renderer::default();
+    assert!(renderer.render(&template, &dir, &json!({})).is_err());
+    assert_eq!(
+      renderer.file_dependencies(),
+      vec![dir.join("partials/broken.ejs")]
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+  }
+}
@@ -0,0 +1,776 @@
+use std::rc::Rc;
+
+use super::lexer::{lex_code, OutputKind, Token, TokenKind};
+
+#[derive(Debug)]
+pub enum Stmt {
+  Text(String),
+  Output(OutputKind, Expr),
+  Expr(Expr),
+  Var(Vec<(String, Option<Expr>)>),
+  Function(String, Rc<FunctionDef>),
+  Block(Vec<Stmt>),
+  If(Expr, Box<Stmt>, Option<Box<Stmt>>),
+  While(Expr, Box<Stmt>),
+  For {
+    init: Option<Box<Stmt>>,
+    test: Option<Expr>,
+    update: Option<Expr>,
+    body: Box<Stmt>,
+  },
+  /// `for (x of list)`, also the `for x in list` form of the previous renderer.
+  ForOf(String, Expr, Box<Stmt>),
+  /// `for (key in object)`
+  ForIn(String, Expr, Box<Stmt>),
+  Break,
+  Continue,
+  Return(Option<Expr>),
+  Empty,
+}
+
+#[derive(Debug)]
+pub struct FunctionDef {
+  pub params: Vec<String>,
+  pub body: FunctionBody,
+}
+
+#[derive(Debug)]
+pub enum FunctionBody {
+  Block(Vec<Stmt>),
+  Expr(Expr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+  Add,
+  Sub,
+  Mul,
+  Div,
+  Rem,
+  Pow,
+  Eq,
+  NotEq,
+  StrictEq,
+  StrictNotEq,
+  Lt,
+  Gt,
+  LtEq,
+  GtEq,
+  In,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalOp {
+  And,
+  Or,
+  Nullish,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+  Not,
+  Neg,
+  Plus,
+  Typeof,
+}
+
+#[derive(Debug)]
+pub enum Expr {
+  Undefined,
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Template(Vec<String>, Vec<Expr>),
+  Ident(String),
+  Array(Vec<Expr>),
+  Object(Vec<(String, Expr)>),
+  Member {
+    object: Box<Expr>,
+    property: Box<Expr>,
+    optional: bool,
+  },
+  Call {
+    callee: Box<Expr>,
+    args: Vec<Expr>,
+    optional: bool,
+  },
+  Unary(UnaryOp, Box<Expr>),
+  Update {
+    increment: bool,
+    prefix: bool,
+    target: Box<Expr>,
+  },
+  Binary(BinaryOp, Box<Expr>, Box<Expr>),
+  Logical(LogicalOp, Box<Expr>, Box<Expr>),
+  Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+  Assign(Option<BinaryOp>, Box<Expr>, Box<Expr>),
+  Function(Rc<FunctionDef>),
+  /// The comma operator, evaluates to its last expression.
+  Sequence(Vec<Expr>),
+}
+
+pub struct Parser {
+  tokens: Vec<Token>,
+  pos: usize,
+}
+
+impl Parser {
+  pub fn new(tokens: Vec<Token>) -> Self {
+    Self { tokens, pos: 0 }
+  }
+
+  pub fn parse_program(mut self) -> Result<Vec<Stmt>, String> {
+    let mut body = vec![];
+    while !self.is(&TokenKind::Eof) {
+      body.push(self.parse_stmt()?);
+    }
+    Ok(body)
+  }
+
+  fn peek(&self) -> &Token {
+    &self.tokens[self.pos.min(self.tokens.len() - 1)]
+  }
+
+  fn peek_at(&self, offset: usize) -> &TokenKind {
+    &self.tokens[(self.pos + offset).min(self.tokens.len() - 1)].kind
+  }
+
+  fn next(&mut self) -> Token {
+    let token = self.peek().clone();
+    if self.pos < self.tokens.len() {
+      self.pos += 1;
+    }
+    token
+  }
+
+  fn is(&self, kind: &TokenKind) -> bool {
+    &self.peek().kind == kind
+  }
+
+  fn is_punct(&self, punct: &str) -> bool {
+    matches!(&self.peek().kind, TokenKind::Punct(p) if *p == punct)
+  }
+
+  fn is_keyword(&self, keyword: &str) -> bool {
+    matches!(&self.peek().kind, TokenKind::Ident(name) if name == keyword)
+  }
+
+  fn eat_punct(&mut self, punct: &str) -> bool {
+    if self.is_punct(punct) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn eat_keyword(&mut self, keyword: &str) -> bool {
+    if self.is_keyword(keyword) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn unexpected(&self) -> String {
+    let token = self.peek();
+    let found = match &token.kind {
+      TokenKind::Text(_) => "template text".to_string(),
+      TokenKind::OutputStart(_) => "output tag".to_string(),
+      TokenKind::OutputEnd => "end of output tag".to_string(),
+      TokenKind::Ident(name) => format!("identifier \"{name}\""),
+      TokenKind::Number(n) => format!("number \"{n}\""),
+      TokenKind::String(s) => format!("string \"{s}\""),
+      TokenKind::Template { .. } => "template literal".to_string(),
+      TokenKind::Punct(p) => format!("token \"{p}\""),
+      TokenKind::Eof => "end of template".to_string(),
+    };
+    format!("SyntaxError: Unexpected {found} at line {}", token.line)
+  }
+
+  fn expect_punct(&mut self, punct: &str) -> Result<(), String> {
+    if self.eat_punct(punct) {
+      Ok(())
+    } else {
+      Err(self.unexpected())
+    }
+  }
+
+  fn expect_ident(&mut self) -> Result<String, String> {
+    match &self.peek().kind {
+      TokenKind::Ident(name) => {
+        let name = name.clone();
+        self.pos += 1;
+        Ok(name)
+      }
+      _ => Err(self.unexpected()),
+    }
+  }
+
+  /// Consumes the end of a statement, either a semicolon or a position where one would be inserted.
+  fn consume_semicolon(&mut self) -> Result<(), String> {
+    if self.eat_punct(";") || self.is_punct("}") {
+      return Ok(());
+    }
+    let token = self.peek();
+    if token.newline_before
+      || matches!(
+        token.kind,
+        TokenKind::Eof | TokenKind::Text(_) | TokenKind::OutputStart(_) | TokenKind::OutputEnd
+      )
+    {
+      return Ok(());
+    }
+    Err(self.unexpected())
+  }
+
+  fn parse_stmt(&mut self) -> Result<Stmt, String> {
+    let token = self.peek().clone();
+    match &token.kind {
+      TokenKind::Text(text) => {
+        self.pos += 1;
+        Ok(Stmt::Text(text.clone()))
+      }
+      TokenKind::OutputStart(kind) => {
+        self.pos += 1;
+        if self.eat_output_end() {
+          return Ok(Stmt::Empty);
+        }
+        let expr = self.parse_expr()?;
+        self.eat_punct(";");
+        if !self.eat_output_end() {
+          return Err(self.unexpected());
+        }
+        Ok(Stmt::Output(*kind, expr))
+      }
+      TokenKind::Punct("{") => {
+        self.pos += 1;
+        Ok(Stmt::Block(self.parse_block_body()?))
+      }
+      TokenKind::Punct(";") => {
+        self.pos += 1;
+        Ok(Stmt::Empty)
+      }
+      TokenKind::Ident(keyword) => match keyword.as_str() {
+        "var" | "let" | "const" => {
+          self.pos += 1;
+          let declarations = self.parse_var_declarations()?;
+          self.consume_semicolon()?;
+          Ok(Stmt::Var(declarations))
+        }
+        "function" => {
+          self.pos += 1;
+          let name = self.expect_ident()?;
+          let function = self.parse_function_rest()?;
+          Ok(Stmt::Function(name, function))
+        }
+        "if" => {
+          self.pos += 1;
+          let test = self.parse_condition()?;
+          let consequent = Box::new(self.parse_stmt()?);
+          let alternate = if self.eat_keyword("else") {
+            Some(Box::new(self.parse_stmt()?))
+          } else {
+            None
+          };
+          Ok(Stmt::If(test, consequent, alternate))
+        }
+        "while" => {
+          self.pos += 1;
+          let test = self.parse_condition()?;
+          Ok(Stmt::While(test, Box::new(self.parse_stmt()?)))
+        }
+        "for" => {
+          self.pos += 1;
+          self.parse_for()
+        }
+        "break" => {
+          self.pos += 1;
+          self.consume_semicolon()?;
+          Ok(Stmt::Break)
+        }
+        "continue" => {
+          self.pos += 1;
+          self.consume_semicolon()?;
+          Ok(Stmt::Continue)
+        }
+        "return" => {
+          self.pos += 1;
+          let token = self.peek();
+          let argument = if token.newline_before
+            || matches!(
+              token.kind,
+              TokenKind::Punct(";" | "}") | TokenKind::Eof | TokenKind::Text(_)
+            ) {
+            None
+          } else {
+            Some(self.parse_expr()?)
+          };
+          self.consume_semicolon()?;
+          Ok(Stmt::Return(argument))
+        }
+        _ => self.parse_expr_stmt(),
+      },
+      TokenKind::OutputEnd | TokenKind::Eof => Err(self.unexpected()),
+      _ => self.parse_expr_stmt(),
+    }
+  }
+
+  fn eat_output_end(&mut self) -> bool {
+    if self.is(&TokenKind::OutputEnd) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn parse_expr_stmt(&mut self) -> Result<Stmt, String> {
+    let expr = self.parse_expr()?;
+    self.consume_semicolon()?;
+    Ok(Stmt::Expr(expr))
+  }
+
+  fn parse_block_body(&mut self) -> Result<Vec<Stmt>, String> {
+    let mut body = vec![];
+    while !self.eat_punct("}") {
+      if self.is(&TokenKind::Eof) {
+        return Err(format!(
+          "SyntaxError: Missing \"}}\" before the end of template at line {}",
+          self.peek().line
+        ));
+      }
+      body.push(self.parse_stmt()?);
+    }
+    Ok(body)
+  }
+
+  /// Parses the condition of `if` and `while`, the parentheses are optional
+  /// to keep the templates of the previous renderer working.
+  fn parse_condition(&mut self) -> Result<Expr, String> {
+    if self.is_punct("(") {
+      let start = self.pos;
+      self.pos += 1;
+      let expr = self.parse_expr()?;
+      self.expect_punct(")")?;
+      // `if (a || b) && c {` is a condition without parentheses starting with a parenthesized expression
+      let continues_expression = self.binary_operator().is_some()
+        || self.is_punct(".")
+        || self.is_punct("?.")
+        || self.is_punct("?");
+      if !continues_expression {
+        return Ok(expr);
+      }
+      self.pos = start;
+    }
+    self.parse_expr()
+  }
+
+  fn parse_var_declarations(&mut self) -> Result<Vec<(String, Option<Expr>)>, String> {
+    let mut declarations = vec![];
+    loop {
+      let name = self.expect_ident()?;
+      let init = if self.eat_punct("=") {
+        Some(self.parse_assign()?)
+      } else {
+        None
+      };
+      declarations.push((name, init));
+      if !self.eat_punct(",") {
+        return Ok(declarations);
+      }
+    }
+  }
+
+  fn parse_for(&mut self) -> Result<Stmt, String> {
+    if !self.eat_punct("(") {
+      // `for item in list { ... }`
+      let name = self.expect_ident()?;
+      if !self.eat_keyword("in") && !self.eat_keyword("of") {
+        return Err(self.unexpected());
+      }
+      let iterable = self.parse_expr()?;
+      let body = Box::new(self.parse_stmt()?);
+      return Ok(Stmt::ForOf(name, iterable, body));
+    }
+
+    let declaration =
+      matches!(self.peek_at(0), TokenKind::Ident(k) if k == "var" || k == "let" || k == "const");
+    let binding_offset = if declaration { 1 } else { 0 };
+    if let TokenKind::Ident(name) = self.peek_at(binding_offset).clone()
+      && let TokenKind::Ident(keyword) = self.peek_at(binding_offset + 1)
+      && (keyword == "of" || keyword == "in")
+    {
+      let is_of = keyword == "of";
+      self.pos += binding_offset + 2;
+      let iterable = self.parse_expr()?;
+      self.expect_punct(")")?;
+      let body = Box::new(self.parse_stmt()?);
+      return Ok(if is_of {
+        Stmt::ForOf(name, iterable, body)
+      } else {
+        Stmt::ForIn(name, iterable, body)
+      });
+    }
+
+    let init = if self.eat_punct(";") {
+      None
+    } else {
+      let init = if self.eat_keyword("var") || self.eat_keyword("let") || self.eat_keyword("const")
+      {
+        Stmt::Var(self.parse_var_declarations()?)
+      } else {
+        Stmt::Expr(self.parse_expr()?)
+      };
+      self.expect_punct(";")?;
+      Some(Box::new(init))
+    };
+    let test = if self.is_punct(";") {
+      None
+    } else {
+      Some(self.parse_expr()?)
+    };
+    self.expect_punct(";")?;
+    let update = if self.is_punct(")") {
+      None
+    } else {
+      Some(self.parse_expr()?)
+    };
+    self.expect_punct(")")?;
+    let body = Box::new(self.parse_stmt()?);
+    Ok(Stmt::For {
+      init,
+      test,
+      update,
+      body,
+    })
+  }
+
+  fn parse_function_rest(&mut self) -> Result<Rc<FunctionDef>, String> {
+    self.expect_punct("(")?;
+    let mut params = vec![];
+    while !self.eat_punct(")") {
+      params.push(self.expect_ident()?);
+      if !self.eat_punct(",") {
+        self.expect_punct(")")?;
+        break;
+      }
+    }
+    self.expect_punct("{")?;
+    Ok(Rc::new(FunctionDef {
+      params,
+      body: FunctionBody::Block(self.parse_block_body()?),
+    }))
+  }
+
+  pub fn parse_expr(&mut self) -> Result<Expr, String> {
+    let expr = self.parse_assign()?;
+    if !self.is_punct(",") {
+      return Ok(expr);
+    }
+    let mut exprs = vec![expr];
+    while self.eat_punct(",") {
+      exprs.push(self.parse_assign()?);
+    }
+    Ok(Expr::Sequence(exprs))
+  }
+
+  fn parse_assign(&mut self) -> Result<Expr, String> {
+    if let Some(arrow) = self.try_parse_arrow()? {
+      return Ok(arrow);
+    }
+    let target = self.parse_conditional()?;
+    let op = match &self.peek().kind {
+      TokenKind::Punct("=") => None,
+      TokenKind::Punct("+=") => Some(BinaryOp::Add),
+      TokenKind::Punct("-=") => Some(BinaryOp::Sub),
+      TokenKind::Punct("*=") => Some(BinaryOp::Mul),
+      TokenKind::Punct("/=") => Some(BinaryOp::Div),
+      TokenKind::Punct("%=") => Some(BinaryOp::Rem),
+      _ => return Ok(target),
+    };
+    if !matches!(target, Expr::Ident(_) | Expr::Member { .. }) {
+      return Err(format!(
+        "SyntaxError: Invalid left-hand side in assignment at line {}",
+        self.peek().line
+      ));
+    }
+    self.pos += 1;
+    let value = self.parse_assign()?;
+    Ok(Expr::Assign(op, Box::new(target), Box::new(value)))
+  }
+
+  fn try_parse_arrow(&mut self) -> Result<Option<Expr>, String> {
+    let params = match self.peek_at(0) {
+      TokenKind::Ident(name) if matches!(self.peek_at(1), TokenKind::Punct("=>")) => {
+        let params = vec![name.clone()];
+        self.pos += 1;
+        params
+      }
+      TokenKind::Punct("(") => {
+        let mut offset = 1;
+        let mut params = vec![];
+        loop {
+          match self.peek_at(offset) {
+            TokenKind::Punct(")") => break,
+            TokenKind::Ident(name) => {
+              params.push(name.clone());
+              offset += 1;
+              match self.peek_at(offset) {
+                TokenKind::Punct(",") => offset += 1,
+                TokenKind::Punct(")") => break,
+                _ => return Ok(None),
+              }
+            }
+            _ => return Ok(None),
+          }
+        }
+        if !matches!(self.peek_at(offset + 1), TokenKind::Punct("=>")) {
+          return Ok(None);
+        }
+        self.pos += offset + 1;
+        params
+      }
+      _ => return Ok(None),
+    };
+    self.expect_punct("=>")?;
+    let body = if self.eat_punct("{") {
+      FunctionBody::Block(self.parse_block_body()?)
+    } else {
+      FunctionBody::Expr(self.parse_assign()?)
+    };
+    Ok(Some(Expr::Function(Rc::new(FunctionDef { params, body }))))
+  }
+
+  fn parse_conditional(&mut self) -> Result<Expr, String> {
+    let test = self.parse_binary(0)?;
+    if !self.eat_punct("?") {
+      return Ok(test);
+    }
+    let consequent = self.parse_assign()?;
+    self.expect_punct(":")?;
+    let alternate = self.parse_assign()?;
+    Ok(Expr::Conditional(
+      Box::new(test),
+      Box::new(consequent),
+      Box::new(alternate),
+    ))
+  }
+
+  fn binary_operator(&self) -> Option<(u8, Result<BinaryOp, LogicalOp>)> {
+    Some(match &self.peek().kind {
+      TokenKind::Punct("??") => (1, Err(LogicalOp::Nullish)),
+      TokenKind::Punct("||") => (2, Err(LogicalOp::Or)),
+      TokenKind::Punct("&&") => (3, Err(LogicalOp::And)),
+      TokenKind::Punct("==") => (4, Ok(BinaryOp::Eq)),
+      TokenKind::Punct("!=") => (4, Ok(BinaryOp::NotEq)),
+      TokenKind::Punct("===") => (4, Ok(BinaryOp::StrictEq)),
+      TokenKind::Punct("!==") => (4, Ok(BinaryOp::StrictNotEq)),
+      TokenKind::Punct("<") => (5, Ok(BinaryOp::Lt)),
+      TokenKind::Punct(">") => (5, Ok(BinaryOp::Gt)),
+      TokenKind::Punct("<=") => (5, Ok(BinaryOp::LtEq)),
+      TokenKind::Punct(">=") => (5, Ok(BinaryOp::GtEq)),
+      TokenKind::Ident(keyword) if keyword == "in" => (5, Ok(BinaryOp::In)),
+      TokenKind::Punct("+") => (6, Ok(BinaryOp::Add)),
+      TokenKind::Punct("-") => (6, Ok(BinaryOp::Sub)),
+      TokenKind::Punct("*") => (7, Ok(BinaryOp::Mul)),
+      TokenKind::Punct("/") => (7, Ok(BinaryOp::Div)),
+      TokenKind::Punct("%") => (7, Ok(BinaryOp::Rem)),
+      TokenKind::Punct("**") => (8, Ok(BinaryOp::Pow)),
+      _ => return None,
+    })
+  }
+
+  fn parse_binary(&mut self, min_precedence: u8) -> Result<Expr, String> {
+    let mut left = self.parse_unary()?;
+    while let Some((precedence, op)) = self.binary_operator() {
+      if precedence <= min_precedence {
+        break;
+      }
+      self.pos += 1;
+      // `**` is right associative
+      let right = if op == Ok(BinaryOp::Pow) {
+        self.parse_binary(precedence - 1)?
+      } else {
+        self.parse_binary(precedence)?
+      };
+      left = match op {
+        Ok(op) => Expr::Binary(op, Box::new(left), Box::new(right)),
+        Err(op) => Expr::Logical(op, Box::new(left), Box::new(right)),
+      };
+    }
+    Ok(left)
+  }
+
+  fn parse_unary(&mut self) -> Result<Expr, String> {
+    let op = match &self.peek().kind {
+      TokenKind::Punct("!") => UnaryOp::Not,
+      TokenKind::Punct("-") => UnaryOp::Neg,
+      TokenKind::Punct("+") => UnaryOp::Plus,
+      TokenKind::Ident(keyword) if keyword == "typeof" => UnaryOp::Typeof,
+      TokenKind::Punct(p @ ("++" | "--")) => {
+        let increment = *p == "++";
+        self.pos += 1;
+        let target = self.parse_unary()?;
+        return Ok(Expr::Update {
+          increment,
+          prefix: true,
+          target: Box::new(target),
+        });
+      }
+      _ => return self.parse_postfix(),
+    };
+    self.pos += 1;
+    Ok(Expr::Unary(op, Box::new(self.parse_unary()?)))
+  }
+
+  fn parse_postfix(&mut self) -> Result<Expr, String> {
+    let expr = self.parse_call()?;
+    let token = self.peek();
+    if !token.newline_before
+      && let TokenKind::Punct(p @ ("++" | "--")) = &token.kind
+    {
+      let increment = *p == "++";
+      self.pos += 1;
+      return Ok(Expr::Update {
+        increment,
+        prefix: false,
+        target: Box::new(expr),
+      });
+    }
+    Ok(expr)
+  }
+
+  fn parse_call(&mut self) -> Result<Expr, String> {
+    let mut expr = self.parse_primary()?;
+    loop {
+      let optional = self.eat_punct("?.");
+      if self.eat_punct("(") {
+        let args = self.parse_arguments()?;
+        expr = Expr::Call {
+          callee: Box::new(expr),
+          args,
+          optional,
+        };
+      } else if self.eat_punct("[") {
+        let property = self.parse_expr()?;
+        self.expect_punct("]")?;
+        expr = Expr::Member {
+          object: Box::new(expr),
+          property: Box::new(property),
+          optional,
+        };
+      } else if optional || self.eat_punct(".") {
+        let name = self.expect_ident()?;
+        expr = Expr::Member {
+          object: Box::new(expr),
+          property: Box::new(Expr::String(name)),
+          optional,
+        };
+      } else {
+        return Ok(expr);
+      }
+    }
+  }
+
+  fn parse_arguments(&mut self) -> Result<Vec<Expr>, String> {
+    let mut args = vec![];
+    while !self.eat_punct(")") {
+      args.push(self.parse_assign()?);
+      if !self.eat_punct(",") {
+        self.expect_punct(")")?;
+        break;
+      }
+    }
+    Ok(args)
+  }
+
+  fn parse_primary(&mut self) -> Result<Expr, String> {
+    let token = self.next();
+    Ok(match token.kind {
+      TokenKind::Number(n) => Expr::Number(n),
+      TokenKind::String(s) => Expr::String(s),
+      TokenKind::Template { quasis, exprs } => {
+        let exprs = exprs
+          .iter()
+          .map(|source| {
+            let mut tokens = vec![];
+            lex_code(source, token.line, &mut tokens)?;
+            tokens.push(Token {
+              kind: TokenKind::Eof,
+              newline_before: true,
+              line: token.line,
+            });
+            let mut parser = Parser::new(tokens);
+            let expr = parser.parse_expr()?;
+            if !parser.is(&TokenKind::Eof) {
+              return Err(parser.unexpected());
+            }
+            Ok(expr)
+          })
+          .collect::<Result<Vec<_>, String>>()?;
+        Expr::Template(quasis, exprs)
+      }
+      TokenKind::Ident(name) => match name.as_str() {
+        "true" => Expr::Bool(true),
+        "false" => Expr::Bool(false),
+        "null" => Expr::Null,
+        "undefined" => Expr::Undefined,
+        "function" => {
+          if matches!(self.peek().kind, TokenKind::Ident(_)) {
+            self.pos += 1;
+          }
+          Expr::Function(self.parse_function_rest()?)
+        }
+        _ => Expr::Ident(name),
+      },
+      TokenKind::Punct("(") => {
+        let expr = self.parse_expr()?;
+        self.expect_punct(")")?;
+        expr
+      }
+      TokenKind::Punct("[") => {
+        let mut elements = vec![];
+        while !self.eat_punct("]") {
+          elements.push(self.parse_assign()?);
+          if !self.eat_punct(",") {
+            self.expect_punct("]")?;
+            break;
+          }
+        }
+        Expr::Array(elements)
+      }
+      TokenKind::Punct("{") => {
+        let mut properties = vec![];
+        while !self.eat_punct("}") {
+          let key = match self.next().kind {
+            TokenKind::Ident(name) | TokenKind::String(name) => name,
+            TokenKind::Number(n) => super::value::number_to_string(n),
+            _ => {
+              self.pos -= 1;
+              return Err(self.unexpected());
+            }
+          };
+          let value = if self.eat_punct(":") {
+            self.parse_assign()?
+          } else {
+            // shorthand property
+            Expr::Ident(key.clone())
+          };
+          properties.push((key, value));
+          if !self.eat_punct(",") {
+            self.expect_punct("}")?;
+            break;
+          }
+        }
+        Expr::Object(properties)
+      }
+      _ => {
+        self.pos -= 1;
+        return Err(self.unexpected());
+      }
+    })
+  }
+}
@@ -0,0 +1,288 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use rspack_util::fx_hash::FxIndexMap;
+
+use super::{
+  interpreter::{Interpreter, Scope},
+  parser::FunctionDef,
+};
+
+pub type NativeFunction = dyn Fn(&mut Interpreter, Value, Vec<Value>) -> Result<Value, String>;
+
+#[derive(Clone)]
+pub enum Value {
+  Undefined,
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(Rc<str>),
+  /// Html which is never escaped, e.g. the result of `include`.
+  Html(Rc<str>),
+  Array(Rc<RefCell<Vec<Value>>>),
+  Object(Rc<RefCell<FxIndexMap<String, Value>>>),
+  Function(Rc<FunctionDef>, Rc<Scope>),
+  Native(Rc<NativeFunction>),
+}
+
+impl fmt::Debug for Value {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Value::Function(..) | Value::Native(_) => write!(f, "[Function]"),
+      value => write!(f, "{}", value.to_js_string()),
+    }
+  }
+}
+
+impl Value {
+  pub fn string(value: impl Into<Rc<str>>) -> Self {
+    Value::String(value.into())
+  }
+
+  pub fn array(items: Vec<Value>) -> Self {
+    Value::Array(Rc::new(RefCell::new(items)))
+  }
+
+  pub fn object(properties: FxIndexMap<String, Value>) -> Self {
+    Value::Object(Rc::new(RefCell::new(properties)))
+  }
+
+  pub fn native(
+    f: impl Fn(&mut Interpreter, Value, Vec<Value>) -> Result<Value, String> + 'static,
+  ) -> Self {
+    Value::Native(Rc::new(f))
+  }
+
+  pub fn is_nullish(&self) -> bool {
+    matches!(self, Value::Undefined | Value::Null)
+  }
+
+  pub fn is_undefined(&self) -> bool {
+    matches!(self, Value::Undefined)
+  }
+
+  pub fn is_function(&self) -> bool {
+    matches!(self, Value::Function(..) | Value::Native(_))
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      Value::String(s) | Value::Html(s) => Some(s),
+      _ => None,
+    }
+  }
+
+  pub fn truthy(&self) -> bool {
+    match self {
+      Value::Undefined | Value::Null => false,
+      Value::Bool(b) => *b,
+      Value::Number(n) => *n != 0.0 && !n.is_nan(),
+      Value::String(s) | Value::Html(s) => !s.is_empty(),
+      _ => true,
+    }
+  }
+
+  pub fn type_of(&self) -> &'static str {
+    match self {
+      Value::Undefined => "undefined",
+      Value::Bool(_) => "boolean",
+      Value::Number(_) => "number",
+      Value::String(_) | Value::Html(_) => "string",
+      Value::Function(..) | Value::Native(_) => "function",
+      Value::Null | Value::Array(_) | Value::Object(_) => "object",
+    }
+  }
+
+  pub fn to_number(&self) -> f64 {
+    match self {
+      Value::Undefined => f64::NAN,
+      Value::Null => 0.0,
+      Value::Bool(b) => f64::from(u8::from(*b)),
+      Value::Number(n) => *n,
+      Value::String(s) | Value::Html(s) => {
+        let s = s.trim();
+        if s.is_empty() {
+          0.0
+        } else if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+          u64::from_str_radix(hex, 16)
+            .map(|n| n as f64)
+            .unwrap_or(f64::NAN)
+        } else {
+          match s {
+            "Infinity" | "+Infinity" => f64::INFINITY,
+            "-Infinity" => f64::NEG_INFINITY,
+            _ => s.parse().unwrap_or(f64::NAN),
+          }
+        }
+      }
+      Value::Array(items) => {
+        let items = items.borrow();
+        match items.as_slice() {
+          [] => 0.0,
+          [item] => item.to_number(),
+          _ => f64::NAN,
+        }
+      }
+      _ => f64::NAN,
+    }
+  }
+
+  pub fn to_js_string(&self) -> String {
+    match self {
+      Value::Undefined => "undefined".to_string(),
+      Value::Null => "null".to_string(),
+      Value::Bool(b) => b.to_string(),
+      Value::Number(n) => number_to_string(*n),
+      Value::String(s) | Value::Html(s) => s.to_string(),
+      Value::Array(items) => items
+        .borrow()
+        .iter()
+        .map(|item| {
+          if item.is_nullish() {
+            String::new()
+          } else {
+            item.to_js_string()
+          }
+        })
+        .collect::<Vec<_>>()
+        .join(","),
+      Value::Object(_) => "[object Object]".to_string(),
+      Value::Function(..) | Value::Native(_) => "function () { [native code] }".to_string(),
+    }
+  }
+
+  /// Converts the value to a string like `_.toString` of lodash, which `<%-` escapes.
+  /// Unlike `String(value)`, nullish items of arrays are kept and `-0` is `"-0"`.
+  pub fn to_lodash_string(&self) -> String {
+    match self {
+      Value::Number(n) if *n == 0.0 && n.is_sign_negative() => "-0".to_string(),
+      Value::Array(items) => items
+        .borrow()
+        .iter()
+        .map(Value::to_lodash_string)
+        .collect::<Vec<_>>()
+        .join(","),
+      _ => self.to_js_string(),
+    }
+  }
+
+  pub fn strict_equals(&self, other: &Value) -> bool {
+    match (self, other) {
+      (Value::Undefined, Value::Undefined) | (Value::Null, Value::Null) => true,
+      (Value::Bool(a), Value::Bool(b)) => a == b,
+      (Value::Number(a), Value::Number(b)) => a == b,
+      (Value::String(a) | Value::Html(a), Value::String(b) | Value::Html(b)) => a == b,
+      (Value::Array(a), Value::Array(b)) => Rc::ptr_eq(a, b),
+      (Value::Object(a), Value::Object(b)) => Rc::ptr_eq(a, b),
+      (Value::Function(a, _), Value::Function(b, _)) => Rc::ptr_eq(a, b),
+      (Value::Native(a), Value::Native(b)) => Rc::ptr_eq(a, b),
+      _ => false,
+    }
+  }
+
+  pub fn loose_equals(&self, other: &Value) -> bool {
+    match (self, other) {
+      (a, b) if a.is_nullish() && b.is_nullish() => true,
+      (a, b) if a.is_nullish() || b.is_nullish() => false,
+      (Value::Number(_), Value::String(_) | Value::Html(_) | Value::Bool(_))
+      | (Value::String(_) | Value::Html(_) | Value::Bool(_), Value::Number(_))
+      | (Value::Bool(_), Value::String(_) | Value::Html(_))
+      | (Value::String(_) | Value::Html(_), Value::Bool(_)) => {
+        self.to_number() == other.to_number()
+      }
+      (
+        Value::Array(_) | Value::Object(_),
+        Value::String(_) | Value::Html(_) | Value::Number(_),
+      ) => Value::string(self.to_js_string()).loose_equals(other),
+      (
+        Value::String(_) | Value::Html(_) | Value::Number(_),
+        Value::Array(_) | Value::Object(_),
+      ) => self.loose_equals(&Value::string(other.to_js_string())),
+      _ => self.strict_equals(other),
+    }
+  }
+
+  pub fn from_json(value: &serde_json::Value) -> Self {
+    match value {
+      serde_json::Value::Null => Value::Null,
+      serde_json::Value::Bool(b) => Value::Bool(*b),
+      serde_json::Value::Number(n) => Value::Number(n.as_f64().unwrap_or(f64::NAN)),
+      serde_json::Value::String(s) => Value::string(s.as_str()),
+      serde_json::Value::Array(items) => Value::array(items.iter().map(Value::from_json).collect()),
+      serde_json::Value::Object(properties) => Value::object(
+        properties
+          .iter()
+          .map(|(key, value)| (key.clone(), Value::from_json(value)))
+          .collect(),
+      ),
+    }
+  }
+
+  /// Converts to json like `JSON.stringify`, functions and `undefined` are skipped.
+  pub fn to_json(&self) -> Option<serde_json::Value> {
+    Some(match self {
+      Value::Undefined | Value::Function(..) | Value::Native(_) => return None,
+      Value::Null => serde_json::Value::Null,
+      Value::Bool(b) => serde_json::Value::Bool(*b),
+      Value::Number(n) => {
+        if n.fract() == 0.0 && n.abs() < 9007199254740992.0 {
+          serde_json::Value::from(*n as i64)
+        } else {
+          serde_json::Number::from_f64(*n)
+            .map_or(serde_json::Value::Null, serde_json::Value::Number)
+        }
+      }
+      Value::String(s) | Value::Html(s) => serde_json::Value::String(s.to_string()),
+      Value::Array(items) => serde_json::Value::Array(
+        items
+          .borrow()
+          .iter()
+          .map(|item| item.to_json().unwrap_or(serde_json::Value::Null))
+          .collect(),
+      ),
+      Value::Object(properties) => serde_json::Value::Object(
+        properties
+          .borrow()
+          .iter()
+          .filter_map(|(key, value)| value.to_json().map(|value| (key.clone(), value)))
+          .collect(),
+      ),
+    })
+  }
+}
+
+pub fn number_to_string(n: f64) -> String {
+  if n.is_nan() {
+    "NaN".to_string()
+  } else if n.is_infinite() {
+    if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+  } else if n == 0.0 {
+    "0".to_string()
+  } else if n.abs() >= 1e21 || n.abs() < 1e-6 {
+    // exponential notation like `1e+21` and `1e-7`
+    let s = format!("{n:e}");
+    match s.split_once('e') {
+      Some((mantissa, exponent)) if !exponent.starts_with('-') => format!("{mantissa}e+{exponent}"),
+      _ => s,
+    }
+  } else if n.fract() == 0.0 {
+    format!("{}", n as i128)
+  } else {
+    format!("{n}")
+  }
+}
+
+/// Escapes html like lodash `_.escape`.
+pub fn escape_html(input: &str) -> String {
+  let mut escaped = String::with_capacity(input.len());
+  for c in input.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
@@ -3,6 +3,7 @@
 
 pub mod asset;
 pub mod config;
+pub mod ejs;
 pub mod injector;
 pub mod parser;
 pub mod sri;
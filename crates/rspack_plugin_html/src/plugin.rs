@@ -1,18 +1,18 @@
-use std::{path::PathBuf, sync::LazyLock};
+use std::sync::LazyLock;
 
 use cow_utils::CowUtils;
 use rspack_core::{
   Compilation, CompilationId, CompilationProcessAssets, Filename, FilenameTemplate, NoFilenameFn,
   Plugin,
 };
-use rspack_error::{miette, Diagnostic, Result};
+use rspack_error::{code::HTML_SCRIPT_LOADING_CONFLICT, miette, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::fx_hash::FxDashMap;
 use swc_html::visit::VisitMutWith;
 
 use crate::{
   asset::{create_favicon_asset, create_html_asset, HtmlPluginAssetTags, HtmlPluginAssets},
-  config::{HtmlInject, HtmlRspackPluginOptions},
+  config::{HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
   injector::AssetInjector,
   parser::HtmlCompiler,
   template::HtmlTemplate,
@@ -58,7 +58,7 @@ async fn generate_html(
   config: &HtmlRspackPluginOptions,
   compilation: &mut Compilation,
   hooks: &HtmlPluginHooks,
-) -> Result<(String, String, Vec<PathBuf>), miette::Error> {
+) -> Result<(String, String), miette::Error> {
   let public_path = config.get_public_path(compilation, filename);
 
   let mut template = HtmlTemplate::new(config, compilation)?;
@@ -85,8 +85,12 @@ async fn generate_html(
     })
     .await?;
 
-  let asset_tags: HtmlPluginAssetTags =
-    HtmlPluginAssetTags::from_assets(config, &before_generation_data.assets, &assets_info.1);
+  let asset_tags: HtmlPluginAssetTags = HtmlPluginAssetTags::from_assets(
+    config,
+    config.get_script_loading(compilation),
+    &before_generation_data.assets,
+    &assets_info.1,
+  );
 
   let alter_asset_tags_data = hooks
     .alter_asset_tags
@@ -121,7 +125,13 @@ async fn generate_html(
     )
     .await?;
 
-  let template_execution_result = template.render(config).await?;
+  let template_execution_result = template.render(config).await;
+  // watch the template and the included templates before reporting the render error,
+  // so that fixing them triggers a rebuild
+  compilation
+    .file_dependencies
+    .extend(template.file_dependencies.into_iter().map(Into::into));
+  let template_execution_result = template_execution_result?;
 
   let mut after_template_execution_data = hooks
     .after_template_execution
@@ -167,11 +177,7 @@ async fn generate_html(
     html.cow_replace("<!DOCTYPE html>", "")
   };
 
-  Ok((
-    template_file_name.to_string(),
-    html.into_owned(),
-    template.file_dependencies,
-  ))
+  Ok((template_file_name.to_string(), html.into_owned()))
 }
 
 #[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
@@ -179,6 +185,21 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let config: &HtmlRspackPluginOptions = &self.config;
   let hooks = HtmlRspackPlugin::get_compilation_hooks(compilation.id());
 
+  if compilation.options.output.script_type == "module"
+    && let Some(script_loading @ (HtmlScriptLoading::Blocking | HtmlScriptLoading::Defer)) =
+      config.script_loading
+  {
+    compilation.push_diagnostic(
+      Diagnostic::warn(
+        "HtmlRspackPlugin".to_string(),
+        format!(
+          "`scriptLoading: '{script_loading}'` conflicts with `output.scriptType: 'module'`, the chunks are injected as classic scripts but loaded as module scripts."
+        ),
+      )
+      .with_diagnostic_code(&HTML_SCRIPT_LOADING_CONFLICT),
+    );
+  }
+
   // TODO: parallel generate html
   for filename in &config.filename {
     let output_file_name = FilenameTemplate::from(
@@ -189,12 +210,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
 
     let (template_file_name, html) =
       match generate_html(filename, &output_file_name, config, compilation, &hooks).await {
-        Ok(content) => {
-          compilation
-            .file_dependencies
-            .extend(content.2.into_iter().map(Into::into));
-          (content.0, content.1)
-        }
+        Ok(content) => content,
         Err(err) => {
           let error_msg = err.to_string();
           compilation.push_diagnostic(Diagnostic::from(err));
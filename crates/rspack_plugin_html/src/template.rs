@@ -1,9 +1,9 @@
 use std::{fs, path::PathBuf};
 
 use anyhow::Context;
+use cow_utils::CowUtils;
 use itertools::Itertools;
 use rspack_core::{parse_to_url, Compilation, CrossOriginLoading, Mode};
-use rspack_dojang::{dojang::DojangOptions, Dojang, Operand};
 use rspack_error::{miette, AnyhowError};
 use rspack_paths::AssertUtf8;
 use serde_json::Value;
@@ -11,6 +11,7 @@ use serde_json::Value;
 use crate::{
   asset::HtmlPluginAssets,
   config::{HtmlRspackPluginOptions, TemplateParameters},
+  ejs::{self, Renderer, Template},
   tag::HtmlPluginTag,
 };
 
@@ -25,7 +26,11 @@ pub struct HtmlTemplate {
   pub render: TemplateRender,
   pub url: String,
   pub filename: String,
+  /// The directory that templates included by relative paths are resolved from.
+  pub context: PathBuf,
   pub file_dependencies: Vec<PathBuf>,
+  /// The data passed by the query of the template, such as `./index.ejs?title=foo`.
+  pub query: Option<Value>,
   pub parameters: Option<Value>,
 }
 
@@ -43,27 +48,40 @@ impl HtmlTemplate {
         },
         url: parse_to_url("template_content.html").path().to_string(),
         filename: "template_content.html".to_string(),
+        context: compilation.options.context.as_path().into(),
         file_dependencies: vec![],
+        query: None,
         parameters: None,
       })
     } else if let Some(template) = &config.template {
-      // TODO: support loader query form
-      let resolved_template = path_clean::clean(
-        compilation
-          .options
-          .context
-          .as_path()
-          .join(template.as_str()),
-      )
-      .assert_utf8();
+      // the query of the loader query form is passed to the template as data
+      let (template, query) = match template.split_once('?') {
+        Some((path, query)) => (
+          path,
+          Some(parse_query(query).map_err(|err| {
+            miette::miette!(
+              "HtmlRspackPlugin: failed to parse the query of template `{template}`: {err}"
+            )
+          })?),
+        ),
+        None => (template.as_str(), None),
+      };
+      let resolved_template =
+        path_clean::clean(compilation.options.context.as_path().join(template)).assert_utf8();
       let url = resolved_template.as_str().to_string();
+      let context = resolved_template
+        .parent()
+        .map(|dir| dir.as_std_path().to_path_buf())
+        .unwrap_or_else(|| compilation.options.context.as_path().into());
 
       if config.template_fn.is_some() {
         Ok(Self {
           render: TemplateRender::Function,
           url,
-          filename: template.clone(),
+          filename: template.to_string(),
+          context,
           file_dependencies: vec![],
+          query,
           parameters: None,
         })
       } else {
@@ -75,8 +93,10 @@ impl HtmlTemplate {
           .map(|content| Self {
             render: TemplateRender::Template(content),
             url,
-            filename: template.clone(),
+            filename: template.to_string(),
+            context,
             file_dependencies: vec![resolved_template.into_std_path_buf()],
+            query,
             parameters: None,
           })
           .map_err(|err| miette::Error::from(AnyhowError::from(err)))
@@ -91,7 +111,9 @@ impl HtmlTemplate {
           render: TemplateRender::Template(content),
           url: default_src_template.as_str().to_string(),
           filename: "src/index.ejs".to_string(),
+          context: compilation.options.context.as_path().join("src").into(),
           file_dependencies: vec![default_src_template.into_std_path_buf()],
+          query: None,
           parameters: None,
         })
       } else {
@@ -99,7 +121,9 @@ impl HtmlTemplate {
           render: TemplateRender::Template(default_template().to_owned()),
           url: parse_to_url("default.html").path().to_string(),
           filename: "default.html".to_string(),
+          context: compilation.options.context.as_path().into(),
           file_dependencies: vec![],
+          query: None,
           parameters: None,
         })
       }
@@ -156,6 +180,10 @@ impl HtmlTemplate {
         }),
       );
 
+      if let Some(query) = &self.query {
+        merge_json(&mut res, query.clone());
+      }
+
       match &config.template_parameters {
         TemplateParameters::Map(data) => {
           merge_json(&mut res, serde_json::json!(&data));
@@ -196,25 +224,19 @@ impl HtmlTemplate {
     let parameters = self.parameters.to_owned().expect("should have parameters");
     match &self.render {
       TemplateRender::Template(content) => {
-        // process with template parameters
-        let mut dj = Dojang::new();
-        // align escape | unescape with lodash.template syntax https://lodash.com/docs/4.17.15#template which is html-webpack-plugin's default behavior
-        dj.with_options(DojangOptions {
-          escape: "-".to_string(),
-          unescape: "=".to_string(),
-        });
-
-        dj.add_function_1("toHtml".into(), render_tag)
-          .expect("failed to add template function `renderTag`");
-
-        dj.add_with_option(self.url.clone(), content.clone())
-          .expect("failed to add template");
-
-        dj.render(&self.url, parameters).map_err(|err| {
+        let render_error = |err: String| {
           miette::Error::msg(format!(
             "HtmlRspackPlugin: failed to render template from string: {err}"
           ))
-        })
+        };
+        // process with template parameters, the syntax is aligned with lodash.template https://lodash.com/docs/4.17.15#template which is html-webpack-plugin's default behavior
+        let template = Template::parse(content).map_err(render_error)?;
+        let mut renderer = Renderer::default();
+        renderer.register_function("toHtml", render_tag);
+        let html = renderer.render(&template, &self.context, &parameters);
+        // rebuild when the included templates change, even if they failed to render
+        self.file_dependencies.extend(renderer.file_dependencies());
+        html.map_err(render_error)
       }
       TemplateRender::Function => (config
         .template_fn
@@ -245,6 +267,50 @@ fn default_template() -> &'static str {
 </html>"#
 }
 
+/// Parse the query of the template like the options of a loader, which is either a JSON
+/// object such as `{"title":"foo"}`, or pairs such as `title=foo&list[]=a&list[]=b&flag`.
+fn parse_query(query: &str) -> Result<Value, String> {
+  if query.starts_with('{') {
+    return match serde_json::from_str(query) {
+      Ok(value @ Value::Object(_)) => Ok(value),
+      Ok(_) => Err("should be a JSON object".to_string()),
+      Err(err) => Err(err.to_string()),
+    };
+  }
+  let decode = |s: &str| {
+    urlencoding::decode(&s.cow_replace('+', " "))
+      .map(|s| s.into_owned())
+      .map_err(|err| err.to_string())
+  };
+  let mut res = serde_json::Map::new();
+  for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+    let (key, value) = match pair.split_once('=') {
+      Some((key, value)) => (decode(key)?, Value::String(decode(value)?)),
+      None => match pair.strip_prefix('-') {
+        Some(key) => (decode(key)?, Value::Bool(false)),
+        None => (
+          decode(pair.strip_prefix('+').unwrap_or(pair))?,
+          Value::Bool(true),
+        ),
+      },
+    };
+    if let Some(key) = key.strip_suffix("[]") {
+      match res.entry(key) {
+        serde_json::map::Entry::Occupied(mut entry) => match entry.get_mut() {
+          Value::Array(list) => list.push(value),
+          other => *other = Value::Array(vec![value]),
+        },
+        serde_json::map::Entry::Vacant(entry) => {
+          entry.insert(Value::Array(vec![value]));
+        }
+      }
+    } else {
+      res.insert(key, value);
+    }
+  }
+  Ok(Value::Object(res))
+}
+
 pub fn merge_json(a: &mut Value, b: Value) {
   match (a, b) {
     (a @ &mut Value::Object(_), Value::Object(b)) => {
@@ -259,21 +325,43 @@ pub fn merge_json(a: &mut Value, b: Value) {
   }
 }
 
-pub fn render_tag(op: Operand) -> Operand {
-  match op {
-    Operand::Value(obj) => match serde_json::from_value::<HtmlPluginTag>(obj) {
-      Ok(tag) => Operand::Value(Value::from(tag.to_string().as_str())),
-      Err(_) => Operand::Value(Value::from("")),
-    },
-    Operand::Array(obj) => Operand::Value(Value::from(
-      obj
-        .iter()
-        .map(|val| match render_tag(val.to_owned()) {
-          Operand::Value(val) => val.as_str().unwrap_or_default().to_string(),
-          _ => "".to_string(),
-        })
-        .join(""),
-    )),
-    _ => Operand::Value(Value::from("")),
+pub fn render_tag(args: &[ejs::Value]) -> ejs::Value {
+  let to_html = |tag: Value| {
+    serde_json::from_value::<HtmlPluginTag>(tag)
+      .map(|tag| tag.to_string())
+      .unwrap_or_default()
+  };
+  ejs::Value::string(match args.first().and_then(ejs::Value::to_json) {
+    Some(Value::Array(tags)) => tags.into_iter().map(to_html).join(""),
+    Some(tag) => to_html(tag),
+    None => String::new(),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::parse_query;
+
+  #[test]
+  fn should_parse_query() {
+    assert_eq!(
+      parse_query("title=Hello+World%21&list[]=a&list[]=b&minify&+cache&-inject").unwrap(),
+      json!({
+        "title": "Hello World!",
+        "list": ["a", "b"],
+        "minify": true,
+        "cache": true,
+        "inject": false,
+      })
+    );
+    assert_eq!(
+      parse_query(r#"{"title":"json","count":1}"#).unwrap(),
+      json!({ "title": "json", "count": 1 })
+    );
+    assert_eq!(parse_query("").unwrap(), json!({}));
+    assert!(parse_query(r#"{"title":"json""#).is_err());
+    assert!(parse_query("title=%E0%A4%A").is_err());
   }
 }
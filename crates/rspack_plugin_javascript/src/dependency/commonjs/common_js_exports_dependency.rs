@@ -1,12 +1,17 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, AsVec},
+};
 use rspack_core::{
-  property_access, AsContextDependency, AsModuleDependency, Compilation, Dependency,
-  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ExportNameOrSpec,
-  ExportSpec, ExportsOfExportsSpec, ExportsSpec, InitFragmentExt, InitFragmentKey,
-  InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource, UsedName,
+  property_access, AsContextDependency, AsModuleDependency, CacheableDependency, Compilation,
+  Dependency, DependencyCategory, DependencyId, DependencyTemplate, DependencyType,
+  ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource, UsedName,
 };
 use swc_core::atoms::Atom;
 
+#[cacheable]
 #[derive(Debug, Clone, Copy)]
 pub enum ExportsBase {
   Exports,
@@ -45,12 +50,14 @@ impl ExportsBase {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct CommonJsExportsDependency {
   id: DependencyId,
   range: (u32, u32),
   value_range: Option<(u32, u32)>,
   base: ExportsBase,
+  #[cacheable(with=AsVec<AsPreset>)]
   names: Vec<Atom>,
 }
 
@@ -99,8 +106,15 @@ impl Dependency for CommonJsExportsDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for CommonJsExportsDependency {}
+
 impl AsModuleDependency for CommonJsExportsDependency {}
 
 impl DependencyTemplate for CommonJsExportsDependency {
@@ -1,11 +1,14 @@
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{
-  module_id, Compilation, DependencyLocation, DependencyRange, RuntimeSpec, SharedSourceMap,
+  module_id, CacheableDependency, Compilation, DependencyLocation, DependencyRange, RuntimeSpec,
+  SharedSourceMap,
 };
 use rspack_core::{AsContextDependency, Dependency, DependencyCategory};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ModuleDependency};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct CommonJsRequireDependency {
   id: DependencyId,
@@ -13,6 +16,7 @@ pub struct CommonJsRequireDependency {
   optional: bool,
   range: DependencyRange,
   range_expr: Option<DependencyRange>,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
 
@@ -59,8 +63,15 @@ impl Dependency for CommonJsRequireDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for CommonJsRequireDependency {}
+
 impl ModuleDependency for CommonJsRequireDependency {
   fn request(&self) -> &str {
     &self.request
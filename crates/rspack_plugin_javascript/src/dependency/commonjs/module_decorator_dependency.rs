@@ -1,11 +1,13 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
-  create_exports_object_referenced, create_no_exports_referenced, AsContextDependency, ChunkGraph,
-  Compilation, Dependency, DependencyId, DependencyTemplate, DependencyType, InitFragmentKey,
-  InitFragmentStage, ModuleDependency, NormalInitFragment, RuntimeGlobals, RuntimeSpec,
-  TemplateContext, TemplateReplaceSource,
+  create_exports_object_referenced, create_no_exports_referenced, AsContextDependency,
+  CacheableDependency, ChunkGraph, Compilation, Dependency, DependencyId, DependencyTemplate,
+  DependencyType, InitFragmentKey, InitFragmentStage, ModuleDependency, NormalInitFragment,
+  RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use rspack_util::ext::DynHash;
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ModuleDecoratorDependency {
   decorator: RuntimeGlobals,
@@ -118,4 +120,11 @@ impl Dependency for ModuleDecoratorDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
+
+#[cacheable_dyn]
+impl CacheableDependency for ModuleDecoratorDependency {}
@@ -1,14 +1,17 @@
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{
-  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyLocation,
-  DependencyRange, RuntimeSpec,
+  AsContextDependency, AsModuleDependency, CacheableDependency, Compilation, Dependency,
+  DependencyLocation, DependencyRange, RuntimeSpec,
 };
 use rspack_core::{DependencyId, SharedSourceMap};
 use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext};
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct RequireHeaderDependency {
   id: DependencyId,
   range: DependencyRange,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
 
@@ -34,8 +37,15 @@ impl Dependency for RequireHeaderDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for RequireHeaderDependency {}
+
 impl AsModuleDependency for RequireHeaderDependency {}
 impl AsContextDependency for RequireHeaderDependency {}
 
@@ -1,12 +1,14 @@
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
-  AsDependency, Compilation, DependencyTemplate, InitFragmentKey, InitFragmentStage, ModuleGraph,
-  NormalInitFragment, RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource,
-  UsageState,
+  AsDependency, CacheableDependencyTemplate, Compilation, DependencyTemplate, InitFragmentKey,
+  InitFragmentStage, ModuleGraph, NormalInitFragment, RuntimeGlobals, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource, UsageState,
 };
 use swc_core::atoms::Atom;
 
 // Mark module `__esModule`.
 // Add `__webpack_require__.r(__webpack_exports__);`.
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMCompatibilityDependency;
 
@@ -85,5 +87,14 @@ impl DependencyTemplate for ESMCompatibilityDependency {
     _runtime: Option<&RuntimeSpec>,
   ) {
   }
+
+  fn as_cacheable_dependency_template(
+    &self,
+  ) -> Option<&(dyn CacheableDependencyTemplate + 'static)> {
+    Some(self)
+  }
 }
+
+#[cacheable_dyn]
+impl CacheableDependencyTemplate for ESMCompatibilityDependency {}
 impl AsDependency for ESMCompatibilityDependency {}
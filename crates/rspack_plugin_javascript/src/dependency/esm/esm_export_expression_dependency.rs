@@ -1,22 +1,26 @@
 use itertools::Itertools;
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_collections::{Identifier, IdentifierSet};
 use rspack_core::rspack_sources::ReplacementEnforce;
 use rspack_core::{
-  property_access, AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyId,
-  DependencyLocation, DependencyRange, DependencyTemplate, DependencyType, ESMExportInitFragment,
-  ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph, RuntimeGlobals, RuntimeSpec,
-  SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedName, DEFAULT_EXPORT,
+  property_access, AsContextDependency, AsModuleDependency, CacheableDependency, Compilation,
+  Dependency, DependencyId, DependencyLocation, DependencyRange, DependencyTemplate,
+  DependencyType, ESMExportInitFragment, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
+  ModuleGraph, RuntimeGlobals, RuntimeSpec, SharedSourceMap, TemplateContext,
+  TemplateReplaceSource, UsedName, DEFAULT_EXPORT,
 };
 use swc_core::atoms::Atom;
 
 use crate::parser_plugin::JS_DEFAULT_KEYWORD;
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub enum DeclarationId {
   Id(String),
   Func(DeclarationInfo),
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct DeclarationInfo {
   range: DependencyRange,
@@ -34,6 +38,7 @@ impl DeclarationInfo {
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMExportExpressionDependency {
   id: DependencyId,
@@ -41,6 +46,7 @@ pub struct ESMExportExpressionDependency {
   range_stmt: DependencyRange,
   prefix: String,
   declaration: Option<DeclarationId>,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
 
@@ -102,8 +108,15 @@ impl Dependency for ESMExportExpressionDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ESMExportExpressionDependency {}
+
 impl AsModuleDependency for ESMExportExpressionDependency {}
 impl AsContextDependency for ESMExportExpressionDependency {}
 
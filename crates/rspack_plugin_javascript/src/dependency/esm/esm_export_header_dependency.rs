@@ -1,17 +1,20 @@
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{
-  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyId,
-  DependencyLocation, DependencyRange, DependencyTemplate, DependencyType, RuntimeSpec,
-  SharedSourceMap, TemplateContext, TemplateReplaceSource,
+  AsContextDependency, AsModuleDependency, CacheableDependency, Compilation, Dependency,
+  DependencyId, DependencyLocation, DependencyRange, DependencyTemplate, DependencyType,
+  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource,
 };
 
 // Remove `export` label.
 // Before: `export const a = 1`
 // After: `const a = 1`
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMExportHeaderDependency {
   id: DependencyId,
   range: DependencyRange,
   range_decl: Option<DependencyRange>,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
 
@@ -46,8 +49,15 @@ impl Dependency for ESMExportHeaderDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ESMExportHeaderDependency {}
+
 impl DependencyTemplate for ESMExportHeaderDependency {
   fn apply(
     &self,
@@ -16,6 +16,7 @@ use rspack_core::{
   TemplateContext, TemplateReplaceSource, UsageState, UsedName,
 };
 use rspack_error::{
+  code::CONFLICTING_STAR_EXPORTS,
   miette::{MietteDiagnostic, Severity},
   Diagnostic, DiagnosticExt, TraceableError,
 };
@@ -883,7 +884,9 @@ impl ESMExportImportedSpecifierDependency {
         )
         .with_hide_stack(Some(true))
       };
-      diagnostic = diagnostic.with_module_identifier(Some(*parent_module_identifier));
+      diagnostic = diagnostic
+        .with_module_identifier(Some(*parent_module_identifier))
+        .with_diagnostic_code(&CONFLICTING_STAR_EXPORTS);
       diagnostic
     };
 
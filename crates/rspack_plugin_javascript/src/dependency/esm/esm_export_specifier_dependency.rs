@@ -1,19 +1,27 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, Skip},
+};
 use rspack_collections::IdentifierSet;
 use rspack_core::{
-  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
-  DependencyId, DependencyLocation, DependencyRange, DependencyTemplate, DependencyType,
-  ESMExportInitFragment, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
-  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedName,
+  AsContextDependency, AsModuleDependency, CacheableDependency, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyLocation, DependencyRange, DependencyTemplate,
+  DependencyType, ESMExportInitFragment, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
+  ModuleGraph, RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedName,
 };
 use swc_core::ecma::atoms::Atom;
 
 // Create _webpack_require__.d(__webpack_exports__, {}) for each export.
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMExportSpecifierDependency {
   id: DependencyId,
   range: DependencyRange,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
+  #[cacheable(with=AsPreset)]
   pub name: Atom,
+  #[cacheable(with=AsPreset)]
   pub value: Atom, // id
 }
 
@@ -75,8 +83,15 @@ impl Dependency for ESMExportSpecifierDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ESMExportSpecifierDependency {}
+
 impl AsModuleDependency for ESMExportSpecifierDependency {}
 
 impl DependencyTemplate for ESMExportSpecifierDependency {
@@ -1,5 +1,9 @@
 use std::sync::Arc;
 
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsPreset, Skip},
+};
 use rspack_collections::IdentifierSet;
 use rspack_core::Compilation;
 use rspack_core::DependencyConditionFn;
@@ -8,15 +12,15 @@ use rspack_core::DependencyRange;
 use rspack_core::SharedSourceMap;
 use rspack_core::{
   filter_runtime, import_statement, merge_runtime, AsContextDependency,
-  AwaitDependenciesInitFragment, BuildMetaDefaultObject, ConditionalInitFragment, ConnectionState,
-  Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate,
-  DependencyType, ErrorSpan, ExportInfoProvided, ExportsType, ExtendedReferencedExport,
-  ImportAttributes, InitFragmentExt, InitFragmentKey, InitFragmentStage, ModuleDependency,
-  ProvidedExports, RuntimeCondition, TemplateContext, TemplateReplaceSource,
+  AwaitDependenciesInitFragment, BuildMetaDefaultObject, CacheableDependency,
+  ConditionalInitFragment, ConnectionState, Dependency, DependencyCategory, DependencyCondition,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExportInfoProvided, ExportsType,
+  ExtendedReferencedExport, ImportAttributes, InitFragmentExt, InitFragmentKey, InitFragmentStage,
+  ModuleDependency, ProvidedExports, RuntimeCondition, TemplateContext, TemplateReplaceSource,
 };
 use rspack_core::{ModuleGraph, RuntimeSpec};
 use rspack_error::miette::{MietteDiagnostic, Severity};
-use rspack_error::DiagnosticExt;
+use rspack_error::{code::EXPORT_NOT_FOUND, DiagnosticExt};
 use rspack_error::{Diagnostic, TraceableError};
 use swc_core::ecma::atoms::Atom;
 
@@ -58,8 +62,10 @@ pub mod import_emitted_runtime {
 }
 
 // ESMImportDependency is merged ESMImportSideEffectDependency.
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMImportSideEffectDependency {
+  #[cacheable(with=AsPreset)]
   pub request: Atom,
   pub source_order: i32,
   pub id: DependencyId,
@@ -69,6 +75,7 @@ pub struct ESMImportSideEffectDependency {
   pub export_all: bool,
   attributes: Option<ImportAttributes>,
   resource_identifier: String,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
 }
 
@@ -273,7 +280,9 @@ pub fn esm_import_dependency_get_linking_error<T: ModuleDependency>(
       )
       .with_hide_stack(Some(true))
     };
-    diagnostic = diagnostic.with_module_identifier(Some(*parent_module_identifier));
+    diagnostic = diagnostic
+      .with_module_identifier(Some(*parent_module_identifier))
+      .with_diagnostic_code(&EXPORT_NOT_FOUND);
     diagnostic
   };
   if matches!(
@@ -449,8 +458,15 @@ impl Dependency for ESMImportSideEffectDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ESMImportSideEffectDependency {}
+
 struct ESMImportSideEffectDependencyCondition;
 
 impl DependencyConditionFn for ESMImportSideEffectDependencyCondition {
@@ -1,11 +1,16 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset, AsVec, Skip},
+};
 use rspack_collections::IdentifierSet;
 use rspack_core::{
   create_exports_object_referenced, export_from_import, get_dependency_used_by_exports_condition,
-  get_exports_type, AsContextDependency, Compilation, ConnectionState, Dependency,
-  DependencyCategory, DependencyCondition, DependencyId, DependencyLocation, DependencyRange,
-  DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType, ExtendedReferencedExport,
-  ImportAttributes, JavascriptParserOptions, ModuleDependency, ModuleGraph, ReferencedExport,
-  RuntimeSpec, SharedSourceMap, TemplateContext, TemplateReplaceSource, UsedByExports,
+  get_exports_type, AsContextDependency, CacheableDependency, Compilation, ConnectionState,
+  Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyLocation,
+  DependencyRange, DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType,
+  ExtendedReferencedExport, ImportAttributes, JavascriptParserOptions, ModuleDependency,
+  ModuleGraph, ReferencedExport, RuntimeSpec, SharedSourceMap, TemplateContext,
+  TemplateReplaceSource, UsedByExports,
 };
 use rspack_core::{property_access, ModuleReferenceOptions};
 use rspack_error::Diagnostic;
@@ -15,23 +20,29 @@ use swc_core::ecma::atoms::Atom;
 use super::esm_import_dependency::esm_import_dependency_get_linking_error;
 use super::{create_resource_identifier_for_esm_dependency, esm_import_dependency_apply};
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ESMImportSpecifierDependency {
   id: DependencyId,
+  #[cacheable(with=AsPreset)]
   request: Atom,
+  #[cacheable(with=AsPreset)]
   name: Atom,
   source_order: i32,
   shorthand: bool,
   asi_safe: bool,
   range: DependencyRange,
+  #[cacheable(with=AsVec<AsPreset>)]
   ids: Vec<Atom>,
   call: bool,
   direct_import: bool,
   used_by_exports: Option<UsedByExports>,
+  #[cacheable(with=AsOption<AsVec<AsPreset>>)]
   referenced_properties_in_destructuring: Option<HashSet<Atom>>,
   resource_identifier: String,
   export_presence_mode: ExportPresenceMode,
   attributes: Option<ImportAttributes>,
+  #[cacheable(with=Skip)]
   source_map: Option<SharedSourceMap>,
   pub namespace_object_as_context: bool,
 }
@@ -340,8 +351,15 @@ impl Dependency for ESMImportSpecifierDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ESMImportSpecifierDependency {}
+
 impl ModuleDependency for ESMImportSpecifierDependency {
   fn request(&self) -> &str {
     &self.request
@@ -1,7 +1,11 @@
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset, AsVec},
+};
 use rspack_core::{
-  create_exports_object_referenced, module_namespace_promise, Compilation, DependencyRange,
-  DependencyType, ExportsType, ExtendedReferencedExport, ImportAttributes, ModuleGraph,
-  ReferencedExport, RuntimeSpec,
+  create_exports_object_referenced, module_namespace_promise, CacheableDependency, Compilation,
+  DependencyRange, DependencyType, ExportsType, ExtendedReferencedExport, ImportAttributes,
+  ModuleGraph, ReferencedExport, RuntimeSpec,
 };
 use rspack_core::{AsContextDependency, Dependency};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
@@ -52,11 +56,14 @@ pub fn create_import_dependency_referenced_exports(
   }
 }
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct ImportDependency {
   id: DependencyId,
+  #[cacheable(with=AsPreset)]
   pub request: Atom,
   pub range: DependencyRange,
+  #[cacheable(with=AsOption<AsVec<AsPreset>>)]
   referenced_exports: Option<Vec<Atom>>,
   attributes: Option<ImportAttributes>,
   resource_identifier: String,
@@ -118,8 +125,15 @@ impl Dependency for ImportDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for ImportDependency {}
+
 impl ModuleDependency for ImportDependency {
   fn request(&self) -> &str {
     &self.request
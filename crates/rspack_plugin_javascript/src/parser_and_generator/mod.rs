@@ -2,13 +2,14 @@ use std::borrow::Cow;
 use std::sync::Arc;
 
 use itertools::Itertools;
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::diagnostics::map_box_diagnostics_to_module_parse_diagnostics;
 use rspack_core::rspack_sources::{BoxSource, ReplaceSource, Source, SourceExt};
 use rspack_core::{
-  render_init_fragments, AsyncDependenciesBlockIdentifier, BuildMetaExportsType, ChunkGraph,
-  Compilation, DependenciesBlock, DependencyId, GenerateContext, Module, ModuleGraph, ModuleType,
-  ParseContext, ParseResult, ParserAndGenerator, SideEffectsBailoutItem, SourceType, SpanExt,
-  TemplateContext, TemplateReplaceSource,
+  render_init_fragments, AsyncDependenciesBlockIdentifier, BuildMetaExportsType,
+  CacheableParserAndGenerator, ChunkGraph, Compilation, DependenciesBlock, DependencyId,
+  GenerateContext, Module, ModuleGraph, ModuleType, ParseContext, ParseResult, ParserAndGenerator,
+  SideEffectsBailoutItem, SourceType, SpanExt, TemplateContext, TemplateReplaceSource,
 };
 use rspack_error::miette::Diagnostic;
 use rspack_error::{DiagnosticExt, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
@@ -25,8 +26,10 @@ use crate::visitors::{scan_dependencies, swc_visitor::resolver};
 use crate::visitors::{semicolon, ScanDependenciesResult};
 use crate::{BoxJavascriptParserPlugin, SideEffectsFlagPluginVisitor, SyntaxContextInfo};
 
+#[cacheable]
 #[derive(Default)]
 pub struct JavaScriptParserAndGenerator {
+  #[cacheable(with=Skip)]
   parser_plugins: Vec<BoxJavascriptParserPlugin>,
 }
 
@@ -368,8 +371,17 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
     }
     None
   }
+
+  fn as_cacheable_parser_and_generator(
+    &self,
+  ) -> Option<&(dyn CacheableParserAndGenerator + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableParserAndGenerator for JavaScriptParserAndGenerator {}
+
 // Todo(shulaoda): check if this can be removed
 fn span_to_location(span: Span, source: &str) -> Option<String> {
   let r = ropey::Rope::from_str(source);
@@ -6,7 +6,9 @@ use rspack_core::{
   AsyncDependenciesBlock, BoxDependency, ConstDependency, DependencyRange, RuntimeGlobals,
   SharedSourceMap, SpanExt,
 };
+use rspack_error::code::UNSUPPORTED_FEATURE;
 use rspack_error::miette::Severity;
+use rspack_error::{DiagnosticExt, MietteExt};
 use rspack_util::atom::Atom;
 use swc_core::{
   common::Spanned,
@@ -297,7 +299,7 @@ impl AMDRequireDependenciesBlockParserPlugin {
           (call_expr.span.real_lo(), call_expr.span.real_hi()),
         ));
         parser.presentational_dependencies.push(dep);
-        parser.warning_diagnostics.push(Box::new(
+        parser.warning_diagnostics.push(
           create_traceable_error(
             "UnsupportedFeatureWarning".into(),
             "Cannot statically analyse 'require(…, …)'".into(),
@@ -305,8 +307,10 @@ impl AMDRequireDependenciesBlockParserPlugin {
             call_expr.span.into(),
           )
           .with_severity(Severity::Warning)
-          .with_hide_stack(Some(true)),
-        ));
+          .with_hide_stack(Some(true))
+          .boxed()
+          .with_diagnostic_code(&UNSUPPORTED_FEATURE),
+        );
         return Some(true);
       }
 
@@ -2,7 +2,7 @@ use rspack_core::{
   ConstDependency, ContextDependency, ContextMode, DependencyCategory, DependencyRange, SpanExt,
 };
 use rspack_core::{ContextNameSpaceObject, ContextOptions};
-use rspack_error::{DiagnosticExt, Severity};
+use rspack_error::{code::CRITICAL_DEPENDENCY, DiagnosticExt, MietteExt, Severity};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{CallExpr, Expr, Ident, MemberExpr, UnaryExpr};
 
@@ -340,6 +340,7 @@ impl CommonJsImportsParserPlugin {
       )
       .with_severity(Severity::Warn)
       .boxed()
+      .with_diagnostic_code(&CRITICAL_DEPENDENCY)
       .into(),
     );
     parser.dependencies.push(Box::new(dep));
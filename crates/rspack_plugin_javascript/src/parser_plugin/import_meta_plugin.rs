@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use rspack_core::{property_access, ConstDependency, SpanExt};
+use rspack_error::code::CRITICAL_DEPENDENCY;
 use rspack_error::miette::Severity;
+use rspack_error::{DiagnosticExt, MietteExt};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::MemberProp;
 use url::Url;
@@ -164,12 +166,12 @@ impl JavascriptParserPlugin for ImportMetaPlugin {
       } else {
         // import.meta
         // warn when access import.meta directly
-        parser.warning_diagnostics.push(Box::new(create_traceable_error(
+        parser.warning_diagnostics.push(create_traceable_error(
       "Critical dependency".into(),
       "Accessing import.meta directly is unsupported (only property access or destructuring is supported)".into(),
       parser.source_file,
       span.into()
-    ).with_severity(Severity::Warning)));
+    ).with_severity(Severity::Warning).boxed().with_diagnostic_code(&CRITICAL_DEPENDENCY));
 
         let content = if parser.is_asi_position(span.lo()) {
           ";({})"
@@ -5,7 +5,9 @@ use rspack_core::{
 };
 use rspack_core::{ChunkGroupOptions, DynamicImportFetchPriority};
 use rspack_core::{ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt};
+use rspack_error::code::MAGIC_COMMENTS;
 use rspack_error::miette::Severity;
+use rspack_error::{DiagnosticExt, MietteExt};
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::{CallExpr, Callee};
 use swc_core::ecma::atoms::Atom;
@@ -88,7 +90,7 @@ impl JavascriptParserPlugin for ImportParserPlugin {
       parser.destructuring_assignment_properties_for(&node.span())
     {
       if exports.is_some() {
-        parser.warning_diagnostics.push(Box::new(
+        parser.warning_diagnostics.push(
           create_traceable_error(
             "Magic comments parse failed".into(),
             "`webpackExports` could not be used with destructuring assignment.".into(),
@@ -96,8 +98,10 @@ impl JavascriptParserPlugin for ImportParserPlugin {
             node.span().into(),
           )
           .with_severity(Severity::Warning)
-          .with_hide_stack(Some(true)),
-        ));
+          .with_hide_stack(Some(true))
+          .boxed()
+          .with_diagnostic_code(&MAGIC_COMMENTS),
+        );
       }
       exports = Some(
         referenced_properties_in_destructuring
@@ -114,7 +114,8 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
     ))];
     /* TODO:
      * 1. Webpack calls `parser.in_scope`.
-     * 2. Webpack sets `parser.state.current = depBlock`, but rspack doesn't support nested block yet.
+     * 2. Webpack sets `parser.state.current = depBlock`, only the blocks created in the callback
+     *    are nested in the block for now, the dependencies are still added to the module.
      */
     for item in dependencies_items.iter() {
       if let Some(item) = item.as_string() {
@@ -126,6 +127,7 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
         return None;
       }
     }
+    let nested_blocks_start = parser.blocks.len();
     if let Some(success_expr) = &success_expr {
       match success_expr.func {
         Either::Left(func) => {
@@ -150,6 +152,9 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
     block.set_group_options(GroupOptions::ChunkGroup(
       ChunkGroupOptions::default().name_optional(chunk_name),
     ));
+    for nested_block in parser.blocks.split_off(nested_blocks_start) {
+      block.add_block(*nested_block);
+    }
     parser.blocks.push(Box::new(block));
 
     if success_expr.is_none() {
@@ -4,7 +4,7 @@ use std::sync::LazyLock;
 use itertools::Itertools;
 use regex::Regex;
 use rspack_core::parse_resource;
-use rspack_error::{Diagnostic, DiagnosticExt, Severity};
+use rspack_error::{code::CRITICAL_DEPENDENCY, Diagnostic, DiagnosticExt, Severity};
 use rspack_util::json_stringify;
 use swc_core::ecma::ast::Expr;
 
@@ -98,7 +98,9 @@ pub fn create_context_dependency(
       .with_severity(Severity::Warn)
       .boxed()
       .into();
-      let warn = warn.with_module_identifier(Some(*parser.module_identifier));
+      let warn = warn
+        .with_module_identifier(Some(*parser.module_identifier))
+        .with_diagnostic_code(&CRITICAL_DEPENDENCY);
       critical = Some(warn);
     }
 
@@ -175,7 +177,9 @@ pub fn create_context_dependency(
       .with_severity(Severity::Warn)
       .boxed()
       .into();
-      let warn = warn.with_module_identifier(Some(*parser.module_identifier));
+      let warn = warn
+        .with_module_identifier(Some(*parser.module_identifier))
+        .with_diagnostic_code(&CRITICAL_DEPENDENCY);
       critical = Some(warn);
     }
 
@@ -205,7 +209,9 @@ pub fn create_context_dependency(
       .with_severity(Severity::Warn)
       .boxed()
       .into();
-      let warn = warn.with_module_identifier(Some(*parser.module_identifier));
+      let warn = warn
+        .with_module_identifier(Some(*parser.module_identifier))
+        .with_diagnostic_code(&CRITICAL_DEPENDENCY);
       critical = Some(warn);
     }
 
@@ -1,6 +1,7 @@
 use rspack_core::{ConstDependency, ErrorSpan, SpanExt};
-use rspack_error::miette::diagnostic;
-use rspack_error::{miette::Severity, DiagnosticKind, TraceableError};
+use rspack_error::code::{CRITICAL_DEPENDENCY, UNSUPPORTED_FEATURE};
+use rspack_error::miette::{diagnostic, Diagnostic};
+use rspack_error::{miette::Severity, DiagnosticExt, DiagnosticKind, MietteExt, TraceableError};
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::common::{SourceFile, Spanned};
@@ -298,18 +299,18 @@ pub fn expression_not_supported(
   file: &SourceFile,
   name: &str,
   expr: &Expr,
-) -> (Box<TraceableError>, Box<ConstDependency>) {
+) -> (Box<dyn Diagnostic + Send + Sync>, Box<ConstDependency>) {
   (
-    Box::new(
-      create_traceable_error(
-        "Unsupported feature".into(),
-        format!("{name} is not supported by Rspack."),
-        file,
-        expr.span().into(),
-      )
-      .with_severity(Severity::Warning)
-      .with_hide_stack(Some(true)),
-    ),
+    create_traceable_error(
+      "Unsupported feature".into(),
+      format!("{name} is not supported by Rspack."),
+      file,
+      expr.span().into(),
+    )
+    .with_severity(Severity::Warning)
+    .with_hide_stack(Some(true))
+    .boxed()
+    .with_diagnostic_code(&UNSUPPORTED_FEATURE),
     Box::new(ConstDependency::new(
       expr.span().real_lo(),
       expr.span().real_hi(),
@@ -359,15 +360,17 @@ pub fn clean_regexp_in_context_module(
 ) -> Option<RspackRegex> {
   if regexp.sticky() || regexp.global() {
     if let Some(error_span) = error_span {
-      parser.warning_diagnostics.push(Box::new(
+      parser.warning_diagnostics.push(
         create_traceable_error(
           "Critical dependency".into(),
           "Contexts can't use RegExps with the 'g' or 'y' flags".to_string(),
           parser.source_file,
           error_span,
         )
-        .with_severity(rspack_error::RspackSeverity::Warn),
-      ));
+        .with_severity(rspack_error::RspackSeverity::Warn)
+        .boxed()
+        .with_diagnostic_code(&CRITICAL_DEPENDENCY),
+      );
     } else {
       parser.warning_diagnostics.push(
         diagnostic!(
@@ -375,7 +378,8 @@ pub fn clean_regexp_in_context_module(
           code = "Critical dependency",
           "Contexts can't use RegExps with the 'g' or 'y' flags"
         )
-        .into(),
+        .boxed()
+        .with_diagnostic_code(&CRITICAL_DEPENDENCY),
       );
     }
     None
@@ -3,7 +3,9 @@ use std::sync::LazyLock;
 use itertools::Itertools;
 use regex::Captures;
 use rspack_core::{ErrorSpan, SpanExt};
+use rspack_error::code::MAGIC_COMMENTS;
 use rspack_error::miette::{Diagnostic, Severity};
+use rspack_error::{DiagnosticExt, MietteExt};
 use rspack_regex::RspackRegex;
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_core::common::comments::{Comment, CommentKind, Comments};
@@ -124,7 +126,7 @@ fn add_magic_comment_warning(
   warning_diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   span: impl Into<ErrorSpan>,
 ) {
-  warning_diagnostics.push(Box::new(
+  warning_diagnostics.push(
     create_traceable_error(
       "Magic comments parse failed".into(),
       format!(
@@ -135,8 +137,10 @@ fn add_magic_comment_warning(
       span.into(),
     )
     .with_severity(Severity::Warning)
-    .with_hide_stack(Some(true)),
-  ))
+    .with_hide_stack(Some(true))
+    .boxed()
+    .with_diagnostic_code(&MAGIC_COMMENTS),
+  )
 }
 
 // Using vm.runInNewContext in webpack
@@ -1,14 +1,17 @@
 use json::JsonValue;
+use rspack_cacheable::{cacheable, cacheable_dyn, with::AsPreset};
 use rspack_core::{
-  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyId,
-  DependencyTemplate, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
-  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+  AsContextDependency, AsModuleDependency, CacheableDependency, Compilation, Dependency,
+  DependencyId, DependencyTemplate, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec,
+  ExportsSpec, ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use rspack_util::{ext::DynHash, itoa};
 
+#[cacheable]
 #[derive(Debug, Clone)]
 pub struct JsonExportsDependency {
   id: DependencyId,
+  #[cacheable(with=AsPreset)]
   data: JsonValue,
 }
 
@@ -36,8 +39,15 @@ impl Dependency for JsonExportsDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::False
   }
+
+  fn as_cacheable_dependency(&self) -> Option<&(dyn CacheableDependency + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableDependency for JsonExportsDependency {}
+
 impl AsModuleDependency for JsonExportsDependency {}
 impl AsContextDependency for JsonExportsDependency {}
 
@@ -11,24 +11,102 @@ use json::{
   },
   JsonValue,
 };
+use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
   diagnostics::ModuleParseError,
   rspack_sources::{BoxSource, RawStringSource, Source, SourceExt},
-  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, CompilerOptions, ExportsInfo,
-  GenerateContext, Module, ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals, RuntimeSpec,
-  SourceType, UsageState, NAMESPACE_OBJECT_EXPORT,
+  BuildMetaDefaultObject, BuildMetaExportsType, CacheableParserAndGenerator, ChunkGraph,
+  CompilerOptions, ConcatenationScope, ExportsInfo, GenerateContext, JsonParse, Module,
+  ModuleGraph, ParserAndGenerator, Plugin, RuntimeGlobals, RuntimeSpec, SourceType, UsageState,
+  NAMESPACE_OBJECT_EXPORT, RESERVED_IDENTIFIER, SAFE_IDENTIFIER,
 };
 use rspack_error::{
-  miette::diagnostic, DiagnosticExt, DiagnosticKind, IntoTWithDiagnosticArray, Result,
-  TWithDiagnosticArray, TraceableError,
+  miette::{diagnostic, Diagnostic},
+  DiagnosticExt, DiagnosticKind, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+  TraceableError,
 };
 use rspack_util::itoa;
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::json_exports_dependency::JsonExportsDependency;
 
 mod json_exports_dependency;
+mod parse;
 mod utils;
 
+fn parse_json(
+  source: &str,
+  need_strip_bom: bool,
+) -> std::result::Result<JsonValue, Box<dyn Diagnostic + Send + Sync>> {
+  let strip_bom_source = if need_strip_bom {
+    &source['\u{feff}'.len_utf8()..]
+  } else {
+    source
+  };
+  json::parse(strip_bom_source).map_err(|e| match e {
+    UnexpectedCharacter { ch, line, column } => {
+      let rope = ropey::Rope::from_str(source);
+      let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+      let start_offset = source[line_offset..]
+        .chars()
+        .take(column)
+        .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+      let start_offset = if need_strip_bom {
+        start_offset + 1
+      } else {
+        start_offset
+      };
+      TraceableError::from_file(
+        source.to_string(),
+        // one character offset
+        start_offset,
+        start_offset + 1,
+        "Json parsing error".to_string(),
+        format!("Unexpected character {ch}"),
+      )
+      .with_kind(DiagnosticKind::Json)
+      .boxed()
+    }
+    ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => diagnostic!("{e}").boxed(),
+    UnexpectedEndOfJson => {
+      // End offset of json file
+      let length = source.len();
+      let offset = if length > 0 { length - 1 } else { length };
+      TraceableError::from_file(
+        source.to_string(),
+        offset,
+        offset,
+        "Json parsing error".to_string(),
+        format!("{e}"),
+      )
+      .with_kind(DiagnosticKind::Json)
+      .boxed()
+    }
+  })
+}
+
+fn parse_error_to_diagnostic(
+  source: &str,
+  format: &str,
+  error: parse::ParseError,
+) -> Box<dyn Diagnostic + Send + Sync> {
+  let Some(span) = error.span else {
+    return diagnostic!("{}", error.message).boxed();
+  };
+  let start = span.start.min(source.len());
+  let end = span.end.clamp(start, source.len());
+  TraceableError::from_file(
+    source.to_string(),
+    start,
+    end,
+    format!("{format} parsing error"),
+    error.message,
+  )
+  .with_kind(DiagnosticKind::Json)
+  .boxed()
+}
+
+#[cacheable]
 #[derive(Debug)]
 struct JsonParserAndGenerator;
 
@@ -50,61 +128,38 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       build_info,
       build_meta,
       loaders,
+      module_parser_options,
       ..
     } = parse_context;
     let source = box_source.source();
     let strip_bom_source = source.strip_prefix('\u{feff}');
     let need_strip_bom = strip_bom_source.is_some();
 
-    let parse_result = json::parse(strip_bom_source.unwrap_or(&source)).map_err(|e| {
-      match e {
-        UnexpectedCharacter { ch, line, column } => {
-          let rope = ropey::Rope::from_str(&source);
-          let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-          let start_offset = source[line_offset..]
-            .chars()
-            .take(column)
-            .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-          let start_offset = if need_strip_bom {
-            start_offset + 1
-          } else {
-            start_offset
-          };
-          TraceableError::from_file(
-            source.into_owned(),
-            // one character offset
-            start_offset,
-            start_offset + 1,
-            "Json parsing error".to_string(),
-            format!("Unexpected character {ch}"),
-          )
-          .with_kind(DiagnosticKind::Json)
-          .boxed()
-        }
-        ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => diagnostic!("{e}").boxed(),
-        UnexpectedEndOfJson => {
-          // End offset of json file
-          let length = source.len();
-          let offset = if length > 0 { length - 1 } else { length };
-          TraceableError::from_file(
-            source.into_owned(),
-            offset,
-            offset,
-            "Json parsing error".to_string(),
-            format!("{e}"),
-          )
-          .with_kind(DiagnosticKind::Json)
-          .boxed()
-        }
+    let parse = module_parser_options
+      .and_then(|p| p.get_json())
+      .and_then(|p| p.parse.as_ref());
+    let parse_result = match parse {
+      None | Some(JsonParse::Json) => parse_json(&source, need_strip_bom),
+      Some(JsonParse::Json5) => {
+        let source = strip_bom_source.unwrap_or(&source);
+        parse::parse_json5(source).map_err(|e| parse_error_to_diagnostic(source, "Json5", e))
       }
-    });
+      Some(JsonParse::Toml) => {
+        let source = strip_bom_source.unwrap_or(&source);
+        parse::parse_toml(source).map_err(|e| parse_error_to_diagnostic(source, "Toml", e))
+      }
+      Some(JsonParse::Yaml) => {
+        let source = strip_bom_source.unwrap_or(&source);
+        parse::parse_yaml(source).map_err(|e| parse_error_to_diagnostic(source, "Yaml", e))
+      }
+      Some(JsonParse::Func(func)) => {
+        func(strip_bom_source.unwrap_or(&source).to_string()).map_err(|e| e.into())
+      }
+    };
 
     let (diagnostics, data) = match parse_result {
       Ok(data) => (vec![], Some(data)),
-      Err(err) => (
-        vec![ModuleParseError::new(err, loaders).boxed().into()],
-        None,
-      ),
+      Err(err) => (vec![ModuleParseError::new(err, loaders).into()], None),
     };
     build_info.json_data = data.clone();
     build_info.strict = true;
@@ -156,36 +211,38 @@ impl ParserAndGenerator for JsonParserAndGenerator {
           .expect("should have json data");
         let exports_info = module_graph.get_exports_info(&module.identifier());
 
-        let final_json = match json_data {
-          json::JsonValue::Object(_) | json::JsonValue::Array(_)
-            if exports_info
-              .other_exports_info(&module_graph)
-              .get_used(&module_graph, *runtime)
-              == UsageState::Unused =>
-          {
-            create_object_for_exports_info(json_data.clone(), exports_info, *runtime, &module_graph)
-          }
-          _ => json_data.clone(),
-        };
-        let is_js_object = final_json.is_object() || final_json.is_array();
-        let final_json_string = stringify(final_json);
-        let json_str = utils::escape_json(&final_json_string);
-        let json_expr = if is_js_object && json_str.len() > 20 {
-          Cow::Owned(format!(
-            "JSON.parse('{}')",
-            json_str.cow_replace('\\', r"\\").cow_replace('\'', r"\'")
-          ))
+        let is_pruned = matches!(json_data, JsonValue::Object(_) | JsonValue::Array(_))
+          && exports_info
+            .other_exports_info(&module_graph)
+            .get_used(&module_graph, *runtime)
+            == UsageState::Unused;
+        let final_json = if is_pruned {
+          create_object_for_exports_info(json_data.clone(), exports_info, *runtime, &module_graph)
         } else {
-          json_str
+          json_data.clone()
         };
         let content = if let Some(ref mut scope) = concatenation_scope {
           scope.register_namespace_export(NAMESPACE_OBJECT_EXPORT);
-          format!("var {NAMESPACE_OBJECT_EXPORT} = {json_expr}")
+          let supports_const = compilation.options.output.environment.supports_const();
+          let declaration_kind = if supports_const { "const" } else { "var" };
+          match final_json {
+            JsonValue::Object(final_obj)
+              if !final_obj.iter().any(|(key, _)| key == "__proto__") =>
+            {
+              let export_names =
+                get_export_names(json_data, is_pruned, exports_info, *runtime, &module_graph);
+              render_concatenated_object(scope, declaration_kind, final_obj, &export_names)
+            }
+            final_json => format!(
+              "{declaration_kind} {NAMESPACE_OBJECT_EXPORT} = {};",
+              to_json_expr(final_json)
+            ),
+          }
         } else {
           generate_context
             .runtime_requirements
             .insert(RuntimeGlobals::MODULE);
-          format!(r#"module.exports = {}"#, json_expr)
+          format!(r#"module.exports = {}"#, to_json_expr(final_json))
         };
         Ok(RawStringSource::from(content).boxed())
       }
@@ -204,8 +261,17 @@ impl ParserAndGenerator for JsonParserAndGenerator {
   ) -> Option<Cow<'static, str>> {
     None
   }
+
+  fn as_cacheable_parser_and_generator(
+    &self,
+  ) -> Option<&(dyn CacheableParserAndGenerator + 'static)> {
+    Some(self)
+  }
 }
 
+#[cacheable_dyn]
+impl CacheableParserAndGenerator for JsonParserAndGenerator {}
+
 #[derive(Debug)]
 pub struct JsonPlugin;
 
@@ -228,6 +294,86 @@ impl Plugin for JsonPlugin {
   }
 }
 
+/// Maps the keys of the generated json object to the export names of the json module
+fn get_export_names<'a>(
+  json_data: &'a JsonValue,
+  is_pruned: bool,
+  exports_info: ExportsInfo,
+  runtime: Option<&RuntimeSpec>,
+  mg: &ModuleGraph,
+) -> HashMap<String, &'a str> {
+  let mut export_names = HashMap::default();
+  let JsonValue::Object(obj) = json_data else {
+    return export_names;
+  };
+  for (key, _) in obj.iter() {
+    if !is_pruned {
+      export_names.insert(key.to_string(), key);
+      continue;
+    }
+    let export_info = exports_info.get_read_only_export_info(mg, &key.into());
+    if let Some(used_name) = export_info.get_used_name(mg, Some(&key.into()), runtime) {
+      export_names.insert(used_name.to_string(), key);
+    }
+  }
+  export_names
+}
+
+/// Declares the top level properties of the json object as bindings of the concatenated scope,
+/// so the named imports reference them directly instead of accessing the whole object.
+fn render_concatenated_object(
+  scope: &mut ConcatenationScope,
+  declaration_kind: &str,
+  obj: Object,
+  export_names: &HashMap<String, &str>,
+) -> String {
+  let mut declarations = String::new();
+  let mut properties = vec![];
+  for (key, value) in obj.iter() {
+    let property = utils::escape_json(&stringify(key)).into_owned();
+    let binding = export_names
+      .get(key)
+      .copied()
+      .filter(|name| is_binding_name(name));
+    if let Some(binding) = binding {
+      scope.register_export(binding.into(), binding.to_string());
+      declarations.push_str(&format!(
+        "{declaration_kind} {binding} = {};\n",
+        to_json_expr(value.clone())
+      ));
+      properties.push(format!("{property}: {binding}"));
+    } else {
+      properties.push(format!("{property}: {}", to_json_expr(value.clone())));
+    }
+  }
+  format!(
+    "{declarations}{declaration_kind} {NAMESPACE_OBJECT_EXPORT} = {{{}}};",
+    properties.join(", ")
+  )
+}
+
+fn to_json_expr(value: JsonValue) -> String {
+  let is_js_object = value.is_object() || value.is_array();
+  let json_string = stringify(value);
+  let json_str = utils::escape_json(&json_string);
+  if is_js_object && json_str.len() > 20 {
+    format!(
+      "JSON.parse('{}')",
+      json_str.cow_replace('\\', r"\\").cow_replace('\'', r"\'")
+    )
+  } else {
+    json_str.into_owned()
+  }
+}
+
+/// Whether the json key can be declared as a top level binding of the concatenated module
+fn is_binding_name(name: &str) -> bool {
+  SAFE_IDENTIFIER.is_match(name)
+    && !RESERVED_IDENTIFIER.contains(name)
+    && !matches!(name, "arguments" | "eval")
+    && name != NAMESPACE_OBJECT_EXPORT
+}
+
 fn create_object_for_exports_info(
   data: JsonValue,
   exports_info: ExportsInfo,
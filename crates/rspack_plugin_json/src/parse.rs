@@ -0,0 +1,188 @@
+use std::{fmt, ops::Range};
+
+use json::{object::Object, JsonValue};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// Deserializes any self-describing format into a [JsonValue],
+/// the order of the object keys is kept as they appear in the source.
+pub(crate) struct DeJsonValue(pub JsonValue);
+
+impl<'de> Deserialize<'de> for DeJsonValue {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(JsonValueVisitor).map(Self)
+  }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+  type Value = JsonValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a value which can be represented in json")
+  }
+
+  fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+    Ok(JsonValue::Boolean(v))
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(v.into()))
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(v.into()))
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+    Ok(JsonValue::Number(v.into()))
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+    Ok(JsonValue::String(v.to_string()))
+  }
+
+  fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+    Ok(JsonValue::String(v))
+  }
+
+  fn visit_none<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_unit<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    DeJsonValue::deserialize(deserializer).map(|v| v.0)
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+  where
+    A: SeqAccess<'de>,
+  {
+    let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+    while let Some(DeJsonValue(item)) = seq.next_element()? {
+      items.push(item);
+    }
+    Ok(JsonValue::Array(items))
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    let mut object = Object::with_capacity(map.size_hint().unwrap_or_default());
+    while let Some((key, DeJsonValue(value))) = map.next_entry::<String, DeJsonValue>()? {
+      object.insert(&key, value);
+    }
+    Ok(JsonValue::Object(object))
+  }
+}
+
+/// The error of parsing a source with a builtin front end
+#[derive(Debug)]
+pub(crate) struct ParseError {
+  pub message: String,
+  /// The byte range of the error in the source, if the front end reports one
+  pub span: Option<Range<usize>>,
+}
+
+pub(crate) fn parse_json5(source: &str) -> Result<JsonValue, ParseError> {
+  json5::from_str::<DeJsonValue>(source)
+    .map(|v| v.0)
+    .map_err(|e| {
+      let json5::Error::Message { msg, location } = e;
+      let span = location
+        .and_then(|location| line_column_to_offset(source, location.line, location.column))
+        .map(|offset| offset..offset + 1);
+      ParseError { message: msg, span }
+    })
+}
+
+pub(crate) fn parse_toml(source: &str) -> Result<JsonValue, ParseError> {
+  toml::from_str::<DeJsonValue>(source)
+    .map(|v| v.0)
+    .map_err(|e| ParseError {
+      message: e.message().to_string(),
+      span: e.span(),
+    })
+}
+
+pub(crate) fn parse_yaml(source: &str) -> Result<JsonValue, ParseError> {
+  serde_yaml::from_str::<DeJsonValue>(source)
+    .map(|v| v.0)
+    .map_err(|e| {
+      let span = e.location().map(|location| {
+        let start = location.index();
+        start..start + 1
+      });
+      ParseError {
+        message: e.to_string(),
+        span,
+      }
+    })
+}
+
+/// Converts the one-based line and the one-based column in characters into a byte offset
+fn line_column_to_offset(source: &str, line: usize, column: usize) -> Option<usize> {
+  let rope = ropey::Rope::from_str(source);
+  let line_offset = rope.try_line_to_byte(line.checked_sub(1)?).ok()?;
+  let offset = source[line_offset..]
+    .chars()
+    .take(column.saturating_sub(1))
+    .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+  Some(offset)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_parse_front_ends() {
+    let expected =
+      json::parse(r#"{"name":"rspack","list":[1,2.5,true,null],"nested":{"b":"b","a":"a"}}"#)
+        .unwrap();
+
+    let yaml = "name: rspack\nlist:\n  - 1\n  - 2.5\n  - true\n  - null\nnested:\n  b: b\n  a: a\n";
+    assert_eq!(parse_yaml(yaml).unwrap(), expected);
+
+    let json5 =
+      "// comment\n{name: 'rspack', list: [1, 2.5, true, null,], nested: {b: 'b', a: 'a'}}";
+    assert_eq!(parse_json5(json5).unwrap(), expected);
+
+    let toml = "name = \"rspack\"\n[nested]\nb = \"b\"\na = \"a\"\n";
+    let expected = json::parse(r#"{"name":"rspack","nested":{"b":"b","a":"a"}}"#).unwrap();
+    assert_eq!(parse_toml(toml).unwrap(), expected);
+
+    assert!(parse_yaml("a: [").is_err());
+    assert!(parse_toml("a = ").is_err());
+    assert!(parse_json5("{a: }").is_err());
+  }
+
+  #[test]
+  fn should_report_error_location() {
+    let source = "name = \"rspack\"\nvalue = ?\n";
+    let error = parse_toml(source).unwrap_err();
+    let span = error.span.expect("should have span");
+    assert_eq!(&source[span], "?");
+
+    let source = "name: rspäck\nvalue: @\n";
+    let error = parse_yaml(source).unwrap_err();
+    let span = error.span.expect("should have span");
+    assert_eq!(&source[span], "@");
+
+    let source = "{\n  name: 'rspäck',\n  value: ?\n}";
+    let error = parse_json5(source).unwrap_err();
+    let span = error.span.expect("should have span");
+    assert_eq!(&source[span], "?");
+  }
+}
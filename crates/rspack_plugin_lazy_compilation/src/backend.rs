@@ -12,3 +12,14 @@ pub trait Backend: std::fmt::Debug + Send + Sync {
   async fn module(&mut self, original_module: ModuleIdentifier, path: String)
     -> Result<ModuleInfo>;
 }
+
+#[async_trait::async_trait]
+impl Backend for Box<dyn Backend> {
+  async fn module(
+    &mut self,
+    original_module: ModuleIdentifier,
+    path: String,
+  ) -> Result<ModuleInfo> {
+    self.as_mut().module(original_module, path).await
+  }
+}
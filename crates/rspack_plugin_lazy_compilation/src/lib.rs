@@ -4,3 +4,4 @@ mod dependency;
 mod factory;
 mod module;
 pub mod plugin;
+pub mod server;
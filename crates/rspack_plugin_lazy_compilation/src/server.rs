@@ -0,0 +1,434 @@
+use std::{
+  net::{IpAddr, Ipv4Addr, SocketAddr},
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use cow_utils::CowUtils;
+use rspack_core::ModuleIdentifier;
+use rspack_error::{error, Result};
+use rustc_hash::FxHashMap as HashMap;
+use tokio::{
+  io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+  net::TcpListener,
+  task::JoinHandle,
+};
+
+use crate::backend::{Backend, ModuleInfo};
+
+const PREFIX: &str = "/lazy-compilation-using-";
+const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+/// Called with the resource paths of the modules that are activated,
+/// the compiler should rebuild these modules to compile them for real.
+pub type ActivateFn = Box<dyn Fn(Vec<String>) + Send + Sync>;
+
+pub struct ServerBackendOptions {
+  /// The client that connects to the server, the url of the server is passed as the query.
+  pub client: String,
+  pub listen: SocketAddr,
+  /// How long a module is kept active after it's no longer requested.
+  pub deactivate_timeout: Duration,
+  pub on_activate: Option<ActivateFn>,
+}
+
+impl ServerBackendOptions {
+  pub fn new(client: String) -> Self {
+    Self {
+      client,
+      listen: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+      deactivate_timeout: Duration::from_secs(120),
+      on_activate: None,
+    }
+  }
+}
+
+impl std::fmt::Debug for ServerBackendOptions {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ServerBackendOptions")
+      .field("client", &self.client)
+      .field("listen", &self.listen)
+      .field("deactivate_timeout", &self.deactivate_timeout)
+      .finish()
+  }
+}
+
+#[derive(Default)]
+struct ActiveModules {
+  /// The count of the requests that keep the module active.
+  ref_counts: HashMap<String, usize>,
+  files_by_key: HashMap<String, String>,
+}
+
+struct ServerState {
+  modules: Mutex<ActiveModules>,
+  deactivate_timeout: Duration,
+  on_activate: Option<ActivateFn>,
+}
+
+impl ServerState {
+  fn activate(&self, keys: &[&str]) {
+    let activated = {
+      let mut modules = self.modules.lock().expect("should lock active modules");
+      let mut activated = vec![];
+      for key in keys {
+        let count = modules.ref_counts.entry(key.to_string()).or_default();
+        *count += 1;
+        if *count == 1 {
+          tracing::debug!("{key} is now in use and will be compiled.");
+          if let Some(file) = modules.files_by_key.get(*key) {
+            activated.push(file.clone());
+          }
+        }
+      }
+      activated
+    };
+    if !activated.is_empty()
+      && let Some(on_activate) = &self.on_activate
+    {
+      on_activate(activated);
+    }
+  }
+
+  fn deactivate(&self, keys: &[String]) {
+    let mut modules = self.modules.lock().expect("should lock active modules");
+    for key in keys {
+      if let Some(count) = modules.ref_counts.get_mut(key) {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+          modules.ref_counts.remove(key);
+          tracing::debug!("{key} is no longer in use. Next compilation will skip this module.");
+        }
+      }
+    }
+  }
+
+  fn is_active(&self, key: &str) -> bool {
+    let modules = self.modules.lock().expect("should lock active modules");
+    modules.ref_counts.get(key).is_some_and(|count| *count > 0)
+  }
+
+  fn set_file(&self, key: String, file: String) {
+    let mut modules = self.modules.lock().expect("should lock active modules");
+    modules.files_by_key.insert(key, file);
+  }
+}
+
+/// A native backend serving the activation endpoint of lazy compilation.
+///
+/// Requests accepting `text/event-stream`, which are sent by the built-in clients, keep
+/// the modules active until the connection is closed, other requests activate the modules
+/// until the `deactivate_timeout` elapses. A module is deactivated `deactivate_timeout`
+/// after the last request of it is finished.
+///
+/// It can be passed to `LazyCompilationPlugin::new` as the backend, and the compiler
+/// should be invalidated in `on_activate` so the activated modules are compiled.
+pub struct ServerBackend {
+  state: Arc<ServerState>,
+  client: String,
+  url_base: String,
+  server: JoinHandle<()>,
+}
+
+impl std::fmt::Debug for ServerBackend {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ServerBackend")
+      .field("client", &self.client)
+      .field("url_base", &self.url_base)
+      .finish()
+  }
+}
+
+impl ServerBackend {
+  pub async fn listen(options: ServerBackendOptions) -> Result<Self> {
+    let listener = TcpListener::bind(options.listen).await.map_err(|e| {
+      error!(
+        "Failed to listen on {} for lazy compilation: {e}",
+        options.listen
+      )
+    })?;
+    let addr = listener.local_addr().map_err(|e| error!("{e}"))?;
+    let url_base = if addr.ip().is_unspecified() {
+      format!("http://localhost:{}", addr.port())
+    } else {
+      format!("http://{addr}")
+    };
+    tracing::debug!("Server-Sent-Events server for lazy compilation open at {url_base}.");
+
+    let state = Arc::new(ServerState {
+      modules: Default::default(),
+      deactivate_timeout: options.deactivate_timeout,
+      on_activate: options.on_activate,
+    });
+    let server = tokio::spawn(serve(listener, state.clone()));
+
+    Ok(Self {
+      state,
+      client: options.client,
+      url_base,
+      server,
+    })
+  }
+
+  pub fn url_base(&self) -> &str {
+    &self.url_base
+  }
+}
+
+impl Drop for ServerBackend {
+  fn drop(&mut self) {
+    self.server.abort();
+  }
+}
+
+#[async_trait::async_trait]
+impl Backend for ServerBackend {
+  async fn module(
+    &mut self,
+    original_module: ModuleIdentifier,
+    path: String,
+  ) -> Result<ModuleInfo> {
+    let key = module_key(&original_module);
+    self.state.set_file(key.clone(), path);
+    Ok(ModuleInfo {
+      active: self.state.is_active(&key),
+      client: format!(
+        "{}?{}",
+        self.client,
+        encode_uri_component(&format!("{}{PREFIX}", self.url_base), false)
+      ),
+      data: key,
+    })
+  }
+}
+
+async fn serve(listener: TcpListener, state: Arc<ServerState>) {
+  let mut connections = tokio::task::JoinSet::new();
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => match accepted {
+        Ok((stream, _)) => {
+          let _ = stream.set_nodelay(true);
+          connections.spawn(handle_connection(stream, state.clone()));
+        }
+        Err(e) => tracing::warn!("Failed to accept the lazy compilation request: {e}"),
+      },
+      Some(_) = connections.join_next() => {}
+    }
+  }
+}
+
+async fn handle_connection<S>(mut stream: S, state: Arc<ServerState>)
+where
+  S: AsyncRead + AsyncWrite + Unpin,
+{
+  let Some(head) = read_request_head(&mut stream).await else {
+    return;
+  };
+  let mut lines = head.lines();
+  let path = lines
+    .next()
+    .and_then(|line| line.split(' ').nth(1))
+    .unwrap_or_default();
+  let is_event_stream = lines.any(|line| {
+    line.split_once(':').is_some_and(|(name, value)| {
+      name.trim().eq_ignore_ascii_case("accept") && value.contains("text/event-stream")
+    })
+  });
+
+  let Some(keys) = path.strip_prefix(PREFIX) else {
+    let _ = stream
+      .write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+      .await;
+    return;
+  };
+  let keys = keys
+    .split('@')
+    .filter(|key| !key.is_empty())
+    .collect::<Vec<_>>();
+  state.activate(&keys);
+  let keys = keys.into_iter().map(String::from).collect::<Vec<_>>();
+
+  let cors = "Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: *\r\nAccess-Control-Allow-Headers: *\r\n";
+  if is_event_stream {
+    let response = format!("HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n{cors}\r\n");
+    if stream.write_all(response.as_bytes()).await.is_ok() {
+      // the modules are kept active until the client closes the connection
+      let mut buf = [0; 1024];
+      while matches!(stream.read(&mut buf).await, Ok(n) if n > 0) {}
+    }
+  } else {
+    let response =
+      format!("HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n{cors}\r\n");
+    let _ = stream.write_all(response.as_bytes()).await;
+  }
+  drop(stream);
+  tokio::time::sleep(state.deactivate_timeout).await;
+  state.deactivate(&keys);
+}
+
+async fn read_request_head<S: AsyncRead + Unpin>(stream: &mut S) -> Option<String> {
+  let mut head = Vec::new();
+  let mut buf = [0; 1024];
+  loop {
+    let n = stream.read(&mut buf).await.ok()?;
+    if n == 0 {
+      return None;
+    }
+    head.extend_from_slice(&buf[..n]);
+    if let Some(end) = head.windows(4).position(|w| w == b"\r\n\r\n") {
+      head.truncate(end);
+      return String::from_utf8(head).ok();
+    }
+    if head.len() > MAX_REQUEST_HEAD_SIZE {
+      return None;
+    }
+  }
+}
+
+/// The key of the module in the url, which is the same as the one of the js backend.
+fn module_key(original_module: &str) -> String {
+  let module = original_module.cow_replace('\\', "/");
+  let module = module.cow_replace('@', "_");
+  encode_uri_component(&module, true)
+}
+
+/// Like `encodeURIComponent`, the reserved characters that are
+/// allowed in paths are kept as is if `keep_path_chars` is `true`.
+fn encode_uri_component(s: &str, keep_path_chars: bool) -> String {
+  let mut encoded = String::with_capacity(s.len());
+  for byte in s.bytes() {
+    let c = byte as char;
+    if c.is_ascii_alphanumeric()
+      || matches!(c, '-' | '_' | '.' | '!' | '~' | '*' | '\'' | '(' | ')')
+      || (keep_path_chars && matches!(c, '/' | ':' | '$' | '&' | '+' | ',' | ';' | '='))
+    {
+      encoded.push(c);
+    } else {
+      encoded.push_str(&format!("%{byte:02X}"));
+    }
+  }
+  encoded
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::mpsc;
+
+  use tokio::io::DuplexStream;
+
+  use super::*;
+
+  fn state(deactivate_timeout: Duration) -> (Arc<ServerState>, mpsc::Receiver<Vec<String>>) {
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    let state = Arc::new(ServerState {
+      modules: Default::default(),
+      deactivate_timeout,
+      on_activate: Some(Box::new(move |files| {
+        tx.lock()
+          .expect("should lock")
+          .send(files)
+          .expect("should send");
+      })),
+    });
+    (state, rx)
+  }
+
+  const CORS: &str = "Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: *\r\nAccess-Control-Allow-Headers: *\r\n";
+
+  /// Sends a request to the handler through an in-memory stream, and waits for the response head.
+  async fn request(
+    state: &Arc<ServerState>,
+    key: &str,
+    accept: &str,
+  ) -> (DuplexStream, JoinHandle<()>, String) {
+    let (mut client, server) = tokio::io::duplex(1024);
+    let handler = tokio::spawn(handle_connection(server, state.clone()));
+    let request = format!("GET {PREFIX}{key} HTTP/1.1\r\naccept: {accept}\r\n\r\n");
+    client
+      .write_all(request.as_bytes())
+      .await
+      .expect("should write request");
+    let mut buf = [0; 1024];
+    let n = client.read(&mut buf).await.expect("should read response");
+    let response = String::from_utf8_lossy(&buf[..n]).into_owned();
+    (client, handler, response)
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn should_activate_modules_by_requests() {
+    let (state, rx) = state(Duration::from_secs(120));
+    let mut backend = ServerBackend {
+      state: state.clone(),
+      client: "/client.js".into(),
+      url_base: "http://127.0.0.1:8080".into(),
+      server: tokio::spawn(async {}),
+    };
+
+    let info = backend
+      .module("/src/@foo/a.js".into(), "/src/@foo/a.js".into())
+      .await
+      .expect("should get module info");
+    assert!(!info.active);
+    assert_eq!(info.data, "/src/_foo/a.js");
+    assert_eq!(
+      info.client,
+      "/client.js?http%3A%2F%2F127.0.0.1%3A8080%2Flazy-compilation-using-"
+    );
+
+    let (stream, event_stream, response) = request(&state, &info.data, "text/event-stream").await;
+    assert_eq!(
+      response,
+      format!("HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n{CORS}\r\n")
+    );
+    assert_eq!(
+      rx.try_recv().expect("should activate"),
+      vec!["/src/@foo/a.js".to_string()]
+    );
+    let info = backend
+      .module("/src/@foo/a.js".into(), "/src/@foo/a.js".into())
+      .await
+      .expect("should get module info");
+    assert!(info.active);
+
+    drop(stream);
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    assert!(state.is_active(&info.data));
+
+    // a plain http request refs the module until the timeout
+    let (_, plain, response) = request(&state, &info.data, "*/*").await;
+    assert_eq!(
+      response,
+      format!("HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n{CORS}\r\n")
+    );
+    assert!(rx.try_recv().is_err());
+    event_stream.await.expect("should finish the event stream");
+    assert!(state.is_active(&info.data));
+    plain.await.expect("should finish the plain request");
+    assert!(!state.is_active(&info.data));
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn should_reject_unknown_paths() {
+    let (state, rx) = state(Duration::from_secs(120));
+    let (mut client, server) = tokio::io::duplex(1024);
+    let handler = tokio::spawn(handle_connection(server, state));
+    client
+      .write_all(b"GET /other HTTP/1.1\r\n\r\n")
+      .await
+      .expect("should write request");
+    let mut response = String::new();
+    client
+      .read_to_string(&mut response)
+      .await
+      .expect("should read response");
+    assert_eq!(
+      response,
+      "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+    );
+    handler.await.expect("should finish the request");
+    assert!(rx.try_recv().is_err());
+  }
+}
@@ -0,0 +1,166 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawStringSource, SourceExt},
+  ApplyContext, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
+  CompilationParams, CompilerCompilation, CompilerOptions, FilenameTemplate, LibraryName,
+  LibraryNonUmdObject, LibraryOptions, LibraryType, PathData, Plugin, PluginContext,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{error_bail, Result};
+use rspack_hash::RspackHash;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_javascript::{
+  JavascriptModulesChunkHash, JavascriptModulesRender, JsPlugin, RenderSource,
+};
+use rspack_util::infallible::ResultInfallibleExt as _;
+
+use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
+
+const PLUGIN_NAME: &str = "rspack.JsonpLibraryPlugin";
+
+#[derive(Debug)]
+struct JsonpLibraryPluginParsed<'a> {
+  name: &'a str,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct JsonpLibraryPlugin {
+  library_type: LibraryType,
+}
+
+impl JsonpLibraryPlugin {
+  pub fn new(library_type: LibraryType) -> Self {
+    Self::new_inner(library_type)
+  }
+
+  fn parse_options<'a>(&self, library: &'a LibraryOptions) -> Result<JsonpLibraryPluginParsed<'a>> {
+    let Some(LibraryName::NonUmdObject(LibraryNonUmdObject::String(name))) = &library.name else {
+      error_bail!("Jsonp library name must be a simple string. {COMMON_LIBRARY_NAME_MESSAGE}")
+    };
+    Ok(JsonpLibraryPluginParsed { name })
+  }
+
+  fn get_options_for_chunk<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk_ukey: &'a ChunkUkey,
+  ) -> Result<Option<JsonpLibraryPluginParsed<'a>>> {
+    get_options_for_chunk(compilation, chunk_ukey)
+      .filter(|library| library.library_type == self.library_type)
+      .map(|library| self.parse_options(library))
+      .transpose()
+  }
+
+  fn get_callback_name(
+    &self,
+    compilation: &Compilation,
+    chunk_ukey: &ChunkUkey,
+    name: &str,
+  ) -> String {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    compilation
+      .get_path(
+        &FilenameTemplate::from(name.to_string()),
+        PathData::default()
+          .chunk_id_optional(chunk.id())
+          .chunk_hash_optional(chunk.rendered_hash(
+            &compilation.chunk_hashes_results,
+            compilation.options.output.hash_digest_length,
+          ))
+          .chunk_name_optional(chunk.name_for_filename_template())
+          .content_hash_optional(chunk.rendered_content_hash_by_source_type(
+            &compilation.chunk_hashes_results,
+            &SourceType::JavaScript,
+            compilation.options.output.hash_digest_length,
+          )),
+      )
+      .always_ok()
+  }
+}
+
+#[plugin_hook(CompilerCompilation for JsonpLibraryPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut hooks = JsPlugin::get_compilation_hooks_mut(compilation);
+  hooks.render.tap(render::new(self));
+  hooks.chunk_hash.tap(js_chunk_hash::new(self));
+  Ok(())
+}
+
+#[plugin_hook(JavascriptModulesRender for JsonpLibraryPlugin)]
+fn render(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  render_source: &mut RenderSource,
+) -> Result<()> {
+  let Some(options) = self.get_options_for_chunk(compilation, chunk_ukey)? else {
+    return Ok(());
+  };
+  let name = self.get_callback_name(compilation, chunk_ukey, options.name);
+  let mut source = ConcatSource::default();
+  source.add(RawStringSource::from(format!("{name}(")));
+  source.add(render_source.source.clone());
+  source.add(RawStringSource::from_static(")"));
+  render_source.source = source.boxed();
+  Ok(())
+}
+
+#[plugin_hook(JavascriptModulesChunkHash for JsonpLibraryPlugin)]
+async fn js_chunk_hash(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  hasher: &mut RspackHash,
+) -> Result<()> {
+  let Some(options) = self.get_options_for_chunk(compilation, chunk_ukey)? else {
+    return Ok(());
+  };
+  PLUGIN_NAME.hash(hasher);
+  self
+    .get_callback_name(compilation, chunk_ukey, options.name)
+    .hash(hasher);
+  Ok(())
+}
+
+#[plugin_hook(CompilationAdditionalChunkRuntimeRequirements for JsonpLibraryPlugin)]
+fn additional_chunk_runtime_requirements(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &mut RuntimeGlobals,
+) -> Result<()> {
+  if self
+    .get_options_for_chunk(compilation, chunk_ukey)?
+    .is_none()
+  {
+    return Ok(());
+  }
+  runtime_requirements.insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
+  Ok(())
+}
+
+impl Plugin for JsonpLibraryPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .additional_chunk_runtime_requirements
+      .tap(additional_chunk_runtime_requirements::new(self));
+    Ok(())
+  }
+}
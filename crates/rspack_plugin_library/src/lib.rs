@@ -3,6 +3,7 @@
 mod amd_library_plugin;
 mod assign_library_plugin;
 mod export_property_library_plugin;
+mod jsonp_library_plugin;
 mod modern_module;
 mod modern_module_library_plugin;
 mod module_library_plugin;
@@ -13,13 +14,15 @@ mod utils;
 pub use amd_library_plugin::AmdLibraryPlugin;
 pub use assign_library_plugin::*;
 pub use export_property_library_plugin::ExportPropertyLibraryPlugin;
+pub use jsonp_library_plugin::JsonpLibraryPlugin;
 use modern_module_library_plugin::ModernModuleLibraryPlugin;
 pub use module_library_plugin::ModuleLibraryPlugin;
 use rspack_core::{BoxPlugin, PluginExt};
+use rspack_error::{error_bail, Result};
 pub use system_library_plugin::SystemLibraryPlugin;
 pub use umd_library_plugin::UmdLibraryPlugin;
 
-pub fn enable_library_plugin(library_type: String, plugins: &mut Vec<BoxPlugin>) {
+pub fn enable_library_plugin(library_type: String, plugins: &mut Vec<BoxPlugin>) -> Result<()> {
   let ns_object_used = library_type != "module";
   match library_type.as_str() {
     "var" => plugins.push(
@@ -130,6 +133,34 @@ pub fn enable_library_plugin(library_type: String, plugins: &mut Vec<BoxPlugin>)
       );
       plugins.push(SystemLibraryPlugin::default().boxed());
     }
-    _ => {}
+    "jsonp" => {
+      plugins
+        .push(ExportPropertyLibraryPlugin::new(library_type.clone(), ns_object_used, true).boxed());
+      plugins.push(JsonpLibraryPlugin::new(library_type).boxed());
+    }
+    _ => error_bail!(
+      "Unsupported library type {library_type}.\nPlugins which provide custom library types must call EnableLibraryPlugin.setEnabled(compiler, type) in compiler hook 'apply'."
+    ),
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::enable_library_plugin;
+
+  #[test]
+  fn should_reject_unsupported_library_type() {
+    let mut plugins = vec![];
+    let error = enable_library_plugin("unknown".into(), &mut plugins)
+      .expect_err("should reject the unsupported library type");
+    assert_eq!(
+      error.to_string(),
+      "Unsupported library type unknown.\nPlugins which provide custom library types must call EnableLibraryPlugin.setEnabled(compiler, type) in compiler hook 'apply'."
+    );
+    assert!(plugins.is_empty());
+
+    enable_library_plugin("jsonp".into(), &mut plugins).expect("should enable jsonp library type");
+    assert_eq!(plugins.len(), 2);
   }
 }
@@ -22,7 +22,7 @@ use rspack_core::{
   },
   ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
 };
-use rspack_error::{error, Diagnostic, Result};
+use rspack_error::{code::CSS_MINIMIZE_WARNING, error, Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::AssetConditions;
@@ -253,6 +253,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           all_warnings.write().expect("should lock").extend(
             warnings.iter().map(|e| {
               Diagnostic::warn("LightningCSS minimize warning".to_string(), e.to_string())
+                .with_diagnostic_code(&CSS_MINIMIZE_WARNING)
             }),
           );
           result
@@ -3,14 +3,14 @@ use rspack_core::{
   DependencyType, ModuleDependency,
 };
 
-use crate::ExposeOptions;
+use crate::{ExposeOptions, ShareScope};
 
 #[derive(Debug, Clone)]
 pub struct ContainerEntryDependency {
   id: DependencyId,
   pub name: String,
   pub exposes: Vec<(String, ExposeOptions)>,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   resource_identifier: String,
   pub(crate) enhanced: bool,
 }
@@ -19,7 +19,7 @@ impl ContainerEntryDependency {
   pub fn new(
     name: String,
     exposes: Vec<(String, ExposeOptions)>,
-    share_scope: String,
+    share_scope: ShareScope,
     enhanced: bool,
   ) -> Self {
     let resource_identifier = format!("container-entry-{}", &name);
@@ -20,7 +20,7 @@ use rustc_hash::FxHashSet;
 use super::{
   container_exposed_dependency::ContainerExposedDependency, container_plugin::ExposeOptions,
 };
-use crate::utils::json_stringify;
+use crate::{utils::json_stringify, ShareScope};
 
 #[impl_source_map_config]
 #[derive(Debug)]
@@ -30,7 +30,7 @@ pub struct ContainerEntryModule {
   identifier: ModuleIdentifier,
   lib_ident: String,
   exposes: Vec<(String, ExposeOptions)>,
-  share_scope: String,
+  share_scope: ShareScope,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
@@ -41,7 +41,7 @@ impl ContainerEntryModule {
   pub fn new(
     name: String,
     exposes: Vec<(String, ExposeOptions)>,
-    share_scope: String,
+    share_scope: ShareScope,
     enhanced: bool,
   ) -> Self {
     let lib_ident = format!("webpack/container/entry/{}", &name);
@@ -63,6 +63,10 @@ impl ContainerEntryModule {
       enhanced,
     }
   }
+
+  pub fn exposes(&self) -> &[(String, ExposeOptions)] {
+    &self.exposes
+  }
 }
 
 impl Identifiable for ContainerEntryModule {
@@ -173,7 +177,7 @@ impl Module for ContainerEntryModule {
     })
   }
 
-  #[tracing::instrument(name = "ContainerEntryModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "ContainerEntryModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
@@ -232,9 +236,10 @@ var get = function(module, getScope) {{
   {current_remote_get_scope} = undefined;
   return getScope;
 }}
-var init = function(shareScope, initScope) {{
+var init = function(shareScope, initScope, shareScopeName) {{
   if (!{share_scope_map}) return;
-  var name = {share_scope};
+  var names = {share_scopes};
+  var name = names.indexOf(shareScopeName) >= 0 ? shareScopeName : names[0];
   var oldScope = {share_scope_map}[name];
   if(oldScope && oldScope !== shareScope) throw new Error("Container initialization failed as it has already been initialized with a different share scope");
   {share_scope_map}[name] = shareScope;
@@ -247,7 +252,7 @@ var init = function(shareScope, initScope) {{
         current_remote_get_scope = RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE,
         has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY,
         share_scope_map = RuntimeGlobals::SHARE_SCOPE_MAP,
-        share_scope = json_stringify(&self.share_scope),
+        share_scopes = json_stringify(&self.share_scope.iter().collect::<Vec<_>>()),
         initialize_sharing = RuntimeGlobals::INITIALIZE_SHARING,
         define_property_getters = RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
         get_scope_reject = basic_function(
@@ -378,5 +383,5 @@ impl ExposeModuleMap {
 #[derive(Debug, Clone)]
 pub struct CodeGenerationDataExpose {
   pub module_map: ExposeModuleMap,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
 }
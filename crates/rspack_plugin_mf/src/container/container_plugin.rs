@@ -18,11 +18,12 @@ use super::{
   container_entry_module_factory::ContainerEntryModuleFactory,
   expose_runtime_module::ExposeRuntimeModule,
 };
+use crate::ShareScope;
 
 #[derive(Debug)]
 pub struct ContainerPluginOptions {
   pub name: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub library: LibraryOptions,
   pub runtime: Option<EntryRuntime>,
   pub filename: Option<Filename>,
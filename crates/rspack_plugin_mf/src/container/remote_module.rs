@@ -19,6 +19,7 @@ use super::{
 };
 use crate::{
   sharing::share_runtime_module::DataInitInfo, CodeGenerationDataShareInit, ShareInitData,
+  ShareScope,
 };
 
 #[impl_source_map_config]
@@ -32,7 +33,7 @@ pub struct RemoteModule {
   request: String,
   external_requests: Vec<String>,
   pub internal_request: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub remote_key: String,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
@@ -44,7 +45,7 @@ impl RemoteModule {
     request: String,
     external_requests: Vec<String>,
     internal_request: String,
-    share_scope: String,
+    share_scope: ShareScope,
     remote_key: String,
   ) -> Self {
     let readable_identifier = format!("remote {}", &request);
@@ -165,7 +166,7 @@ impl Module for RemoteModule {
     })
   }
 
-  #[tracing::instrument(name = "RemoteModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "RemoteModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
@@ -179,11 +180,15 @@ impl Module for RemoteModule {
       module.and_then(|m| ChunkGraph::get_module_id(&compilation.module_ids, m.identifier()));
     codegen.add(SourceType::Remote, RawStringSource::from_static("").boxed());
     codegen.data.insert(CodeGenerationDataShareInit {
-      items: vec![ShareInitData {
-        share_scope: self.share_scope.clone(),
-        init_stage: 20,
-        init: DataInitInfo::ExternalModuleId(id.cloned()),
-      }],
+      items: self
+        .share_scope
+        .iter()
+        .map(|share_scope| ShareInitData {
+          share_scope: share_scope.to_string(),
+          init_stage: 20,
+          init: DataInitInfo::ExternalModuleId(id.cloned()),
+        })
+        .collect(),
     });
     Ok(codegen)
   }
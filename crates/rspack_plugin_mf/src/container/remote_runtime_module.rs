@@ -9,7 +9,7 @@ use rustc_hash::FxHashMap;
 use serde::Serialize;
 
 use super::remote_module::RemoteModule;
-use crate::utils::json_stringify;
+use crate::{utils::json_stringify, ShareScope};
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -58,7 +58,7 @@ impl RuntimeModule for RemoteRuntimeModule {
         let name = m.internal_request.as_str();
         let id = ChunkGraph::get_module_id(&compilation.module_ids, m.identifier())
           .expect("should have module_id at <RemoteRuntimeModule as RuntimeModule>::generate");
-        let share_scope = m.share_scope.as_str();
+        let share_scope = &m.share_scope;
         let dep = m.get_dependencies()[0];
         let external_module = module_graph
           .get_module_by_dependency_id(&dep)
@@ -113,7 +113,7 @@ __webpack_require__.remotesLoadingData = {{ chunkMapping: {chunk_mapping}, modul
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct RemoteData<'a> {
-  share_scope: &'a str,
+  share_scope: &'a ShareScope,
   name: &'a str,
   external_module_id: &'a ModuleId,
   remote_name: &'a str,
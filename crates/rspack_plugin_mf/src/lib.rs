@@ -2,6 +2,7 @@
 #![feature(hash_raw_entry)]
 
 mod container;
+mod manifest;
 mod sharing;
 
 pub use container::container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
@@ -9,6 +10,9 @@ pub use container::container_reference_plugin::{
   ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
 };
 pub use container::module_federation_runtime_plugin::ModuleFederationRuntimePlugin;
+pub use manifest::manifest_plugin::{
+  ModuleFederationManifestPlugin, ModuleFederationManifestPluginOptions,
+};
 pub use sharing::consume_shared_plugin::{
   ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
 };
@@ -17,6 +21,7 @@ pub use sharing::share_runtime_module::{
   CodeGenerationDataShareInit, DataInitStage, ShareInitData, ShareRuntimeModule,
 };
 pub use sharing::share_runtime_plugin::ShareRuntimePlugin;
+pub use sharing::share_scope::ShareScope;
 
 mod utils {
   use std::fmt;
@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use rspack_collections::UkeyIndexSet;
+use rspack_core::{
+  rspack_sources::{RawStringSource, SourceExt},
+  ApplyContext, AssetInfo, ChunkUkey, Compilation, CompilationAfterProcessAssets, CompilationAsset,
+  CompilerOptions, DependenciesBlock, ExternalModule, ModuleIdentifier, Plugin, PluginContext,
+  PublicPath,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use serde::Serialize;
+
+use crate::{
+  container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule},
+  sharing::{
+    consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+  },
+  ProvideVersion, ShareScope,
+};
+
+#[derive(Debug)]
+pub struct ModuleFederationManifestPluginOptions {
+  /// The name of the container, which is also the name of the remote entry.
+  pub name: String,
+  /// The global name of the container, defaults to the name.
+  pub global_name: Option<String>,
+  /// The filename of the manifest, `mf-manifest.json` by default.
+  pub filename: String,
+  /// The filename of the stats, `mf-stats.json` by default.
+  pub stats_filename: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestAssetGroup {
+  pub sync: Vec<String>,
+  pub r#async: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestAssets {
+  pub js: ManifestAssetGroup,
+  pub css: ManifestAssetGroup,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemoteEntry {
+  pub name: String,
+  pub path: String,
+  pub r#type: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestMetaData {
+  pub name: String,
+  pub global_name: String,
+  pub public_path: String,
+  pub remote_entry: ManifestRemoteEntry,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestExpose {
+  pub id: String,
+  pub name: String,
+  pub path: String,
+  pub assets: ManifestAssets,
+  /// The requests of the exposed modules, only in stats.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestShared {
+  pub id: String,
+  pub name: String,
+  pub version: String,
+  pub share_scope: ShareScope,
+  pub singleton: bool,
+  pub eager: bool,
+  pub required_version: String,
+  pub assets: ManifestAssets,
+  /// The modules consuming the shared module, only in stats.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemote {
+  pub federation_container_name: String,
+  pub module_name: String,
+  pub alias: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entry: Option<String>,
+  /// The modules requesting the remote module, only in stats.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+  pub id: String,
+  pub name: String,
+  pub meta_data: ManifestMetaData,
+  pub shared: Vec<ManifestShared>,
+  pub remotes: Vec<ManifestRemote>,
+  pub exposes: Vec<ManifestExpose>,
+}
+
+impl Manifest {
+  /// Strip the fields only available in stats.
+  fn into_manifest(mut self) -> Self {
+    for expose in &mut self.exposes {
+      expose.file = None;
+    }
+    for shared in &mut self.shared {
+      shared.used_in = None;
+    }
+    for remote in &mut self.remotes {
+      remote.used_in = None;
+    }
+    self
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ModuleFederationManifestPlugin {
+  options: ModuleFederationManifestPluginOptions,
+}
+
+impl ModuleFederationManifestPlugin {
+  pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn stats(&self, compilation: &Compilation) -> Manifest {
+    let name = self.options.name.clone();
+    let global_name = self
+      .options
+      .global_name
+      .clone()
+      .unwrap_or_else(|| name.clone());
+    let module_graph = compilation.get_module_graph();
+
+    let mut exposes = vec![];
+    let mut shared: BTreeMap<(String, String), ManifestShared> = BTreeMap::default();
+    let mut consumes = vec![];
+    let mut remotes = vec![];
+    for (identifier, module) in module_graph
+      .modules()
+      .into_iter()
+      .sorted_by_key(|(id, _)| *id)
+    {
+      if let Some(container) = module.downcast_ref::<ContainerEntryModule>() {
+        for ((expose_name, options), block) in container.exposes().iter().zip(module.get_blocks()) {
+          let chunks = compilation
+            .chunk_graph
+            .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+            .map(|group| group.chunks.clone())
+            .unwrap_or_default();
+          let path = expose_name.clone();
+          let name = expose_name
+            .strip_prefix("./")
+            .unwrap_or(expose_name)
+            .to_string();
+          exposes.push(ManifestExpose {
+            id: format!("{}:{}", self.options.name, name),
+            name,
+            path,
+            assets: get_assets(compilation, &chunks),
+            file: Some(options.import.clone()),
+          });
+        }
+      } else if let Some(provide) = module.downcast_ref::<ProvideSharedModule>() {
+        let provided = module
+          .get_dependencies()
+          .iter()
+          .chain(module.get_blocks().iter().flat_map(|block| {
+            module_graph
+              .block_by_id(block)
+              .map(|block| block.get_dependencies())
+              .unwrap_or_default()
+          }))
+          .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
+          .copied()
+          .collect::<Vec<_>>();
+        let chunks = get_module_chunks(compilation, &provided);
+        let mut assets = get_assets(compilation, &chunks);
+        if !provide.eager() {
+          assets.js.r#async.append(&mut assets.js.sync);
+          assets.css.r#async.append(&mut assets.css.sync);
+        }
+        let version = match provide.version() {
+          ProvideVersion::Version(version) => version.clone(),
+          ProvideVersion::False => String::new(),
+        };
+        shared.insert(
+          (
+            provide.share_scope().to_string(),
+            provide.share_key().to_string(),
+          ),
+          ManifestShared {
+            id: format!("{}:{}", self.options.name, provide.share_key()),
+            name: provide.share_key().to_string(),
+            version,
+            share_scope: provide.share_scope().clone(),
+            singleton: provide.singleton().unwrap_or_default(),
+            eager: provide.eager(),
+            required_version: provide
+              .required_version()
+              .map(|v| v.to_string())
+              .unwrap_or_else(|| "*".to_string()),
+            assets,
+            used_in: Some(vec![]),
+          },
+        );
+      } else if let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() {
+        consumes.push((identifier, consume.options().clone()));
+      } else if let Some(remote) = module.downcast_ref::<RemoteModule>() {
+        let (federation_container_name, entry) = module
+          .get_dependencies()
+          .first()
+          .and_then(|dep| module_graph.get_module_by_dependency_id(dep))
+          .and_then(|external| external.downcast_ref::<ExternalModule>())
+          .map(|external| {
+            let request = serde_json::to_value(&external.request)
+              .ok()
+              .and_then(|request| match request {
+                serde_json::Value::String(request) => Some(request),
+                serde_json::Value::Array(request) => request
+                  .first()
+                  .and_then(|r| r.as_str())
+                  .map(ToOwned::to_owned),
+                _ => None,
+              })
+              .unwrap_or_default();
+            // `global@url` for script remotes
+            match request.split_once('@') {
+              Some((global, url)) if external.external_type == "script" => {
+                (global.to_string(), Some(url.to_string()))
+              }
+              _ => (request, None),
+            }
+          })
+          .unwrap_or_else(|| (remote.remote_key.clone(), None));
+        remotes.push(ManifestRemote {
+          federation_container_name,
+          module_name: remote
+            .internal_request
+            .strip_prefix("./")
+            .unwrap_or(&remote.internal_request)
+            .to_string(),
+          alias: remote.remote_key.clone(),
+          entry,
+          used_in: Some(get_issuers(compilation, &identifier)),
+        });
+      }
+    }
+
+    for (identifier, options) in consumes {
+      let Some(shared) =
+        shared.get_mut(&(options.share_scope.to_string(), options.share_key.clone()))
+      else {
+        continue;
+      };
+      if let Some(required_version) = &options.required_version {
+        shared.required_version = required_version.to_string();
+      }
+      shared.singleton |= options.singleton;
+      if let Some(used_in) = &mut shared.used_in {
+        used_in.extend(get_issuers(compilation, &identifier));
+        used_in.sort();
+        used_in.dedup();
+      }
+    }
+
+    let remote_entry = compilation
+      .entrypoints
+      .get(&name)
+      .map(|ukey| compilation.chunk_group_by_ukey.expect_get(ukey))
+      .and_then(|entrypoint| {
+        compilation
+          .chunk_by_ukey
+          .get(&entrypoint.get_entry_point_chunk())
+      })
+      .and_then(|chunk| chunk.files().iter().find(|file| is_js(file)).cloned())
+      .unwrap_or_default();
+    let (path, remote_entry) = match remote_entry.rsplit_once('/') {
+      Some((path, file)) => (path.to_string(), file.to_string()),
+      None => (String::new(), remote_entry),
+    };
+    let library_type = compilation
+      .entries
+      .get(&name)
+      .and_then(|entry| entry.options.library.as_ref())
+      .map(|library| library.library_type.clone())
+      .unwrap_or_else(|| "global".to_string());
+    let public_path = match &compilation.options.output.public_path {
+      PublicPath::Filename(filename) => filename.template().unwrap_or("auto").to_string(),
+      PublicPath::Auto => "auto".to_string(),
+    };
+
+    Manifest {
+      id: name.clone(),
+      name: name.clone(),
+      meta_data: ManifestMetaData {
+        name,
+        global_name,
+        public_path,
+        remote_entry: ManifestRemoteEntry {
+          name: remote_entry,
+          path,
+          r#type: library_type,
+        },
+      },
+      shared: shared.into_values().collect(),
+      remotes,
+      exposes,
+    }
+  }
+}
+
+fn is_js(file: &str) -> bool {
+  let file = file.split(['?', '#']).next().unwrap_or(file);
+  file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs")
+}
+
+fn is_css(file: &str) -> bool {
+  let file = file.split(['?', '#']).next().unwrap_or(file);
+  file.ends_with(".css")
+}
+
+fn get_module_chunks(compilation: &Compilation, modules: &[ModuleIdentifier]) -> Vec<ChunkUkey> {
+  modules
+    .iter()
+    .flat_map(|module| compilation.chunk_graph.get_module_chunks(*module).iter())
+    .copied()
+    .unique()
+    .collect()
+}
+
+/// The files of the chunks are sync assets, and the files of the async chunks
+/// loaded by them are async assets.
+fn get_assets(compilation: &Compilation, chunks: &[ChunkUkey]) -> ManifestAssets {
+  let mut async_chunks = UkeyIndexSet::default();
+  for chunk in chunks {
+    if let Some(chunk) = compilation.chunk_by_ukey.get(chunk) {
+      async_chunks.extend(chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey));
+    }
+  }
+  let files = |chunks: &mut dyn Iterator<Item = &ChunkUkey>| {
+    chunks
+      .filter_map(|chunk| compilation.chunk_by_ukey.get(chunk))
+      .flat_map(|chunk| chunk.files().iter())
+      .cloned()
+      .sorted()
+      .dedup()
+      .collect::<Vec<_>>()
+  };
+  let sync = files(&mut chunks.iter());
+  let r#async = files(&mut async_chunks.iter().filter(|chunk| !chunks.contains(chunk)))
+    .into_iter()
+    .filter(|file| !sync.contains(file))
+    .collect::<Vec<_>>();
+  ManifestAssets {
+    js: ManifestAssetGroup {
+      sync: sync.iter().filter(|f| is_js(f)).cloned().collect(),
+      r#async: r#async.iter().filter(|f| is_js(f)).cloned().collect(),
+    },
+    css: ManifestAssetGroup {
+      sync: sync.iter().filter(|f| is_css(f)).cloned().collect(),
+      r#async: r#async.iter().filter(|f| is_css(f)).cloned().collect(),
+    },
+  }
+}
+
+fn get_issuers(compilation: &Compilation, module: &ModuleIdentifier) -> Vec<String> {
+  let module_graph = compilation.get_module_graph();
+  module_graph
+    .get_incoming_connections(module)
+    .filter_map(|connection| connection.original_module_identifier)
+    .filter_map(|issuer| module_graph.module_by_identifier(&issuer))
+    .map(|issuer| {
+      issuer
+        .readable_identifier(&compilation.options.context)
+        .into_owned()
+    })
+    .sorted()
+    .dedup()
+    .collect()
+}
+
+#[plugin_hook(CompilationAfterProcessAssets for ModuleFederationManifestPlugin)]
+async fn after_process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let stats = self.stats(compilation);
+  let stats_source = serde_json::to_string_pretty(&stats).expect("should serialize stats");
+  let manifest_source =
+    serde_json::to_string_pretty(&stats.into_manifest()).expect("should serialize manifest");
+  compilation.emit_asset(
+    self.options.stats_filename.clone(),
+    CompilationAsset::new(
+      Some(RawStringSource::from(stats_source).boxed()),
+      AssetInfo::default(),
+    ),
+  );
+  compilation.emit_asset(
+    self.options.filename.clone(),
+    CompilationAsset::new(
+      Some(RawStringSource::from(manifest_source).boxed()),
+      AssetInfo::default(),
+    ),
+  );
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for ModuleFederationManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleFederationManifestPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .after_process_assets
+      .tap(after_process_assets::new(self));
+    Ok(())
+  }
+}
@@ -81,6 +81,10 @@ impl ConsumeSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn options(&self) -> &ConsumeOptions {
+    &self.options
+  }
 }
 
 impl Identifiable for ConsumeSharedModule {
@@ -173,7 +177,7 @@ impl Module for ConsumeSharedModule {
     })
   }
 
-  #[tracing::instrument(name = "ConsumeSharedModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "ConsumeSharedModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
@@ -12,7 +12,10 @@ use rspack_core::{
   NormalModuleFactoryCreateModule, NormalModuleFactoryFactorize, Plugin, PluginContext,
   ResolveOptionsWithDependencyType, ResolveResult, Resolver, RuntimeGlobals,
 };
-use rspack_error::{error, Diagnostic, Result};
+use rspack_error::{
+  code::{MODULE_NOT_FOUND, SHARED_MODULE_VERSION},
+  error, Diagnostic, Result,
+};
 use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::FxHashMap;
 
@@ -20,13 +23,14 @@ use super::{
   consume_shared_module::ConsumeSharedModule,
   consume_shared_runtime_module::ConsumeSharedRuntimeModule,
 };
+use crate::ShareScope;
 
 #[derive(Debug, Clone, Hash)]
 pub struct ConsumeOptions {
   pub import: Option<String>,
   pub import_resolved: Option<String>,
   pub share_key: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub required_version: Option<ConsumeVersion>,
   pub package_name: Option<String>,
   pub strict_version: bool,
@@ -221,7 +225,7 @@ impl ConsumeSharedPlugin {
     mut add_diagnostic: impl FnMut(Diagnostic),
   ) -> Option<ConsumeVersion> {
     let mut required_version_warning = |details: &str| {
-      add_diagnostic(Diagnostic::warn(self.name().into(), format!("No required version specified and unable to automatically determine one. {details} file: shared module {request}")))
+      add_diagnostic(Diagnostic::warn(self.name().into(), format!("No required version specified and unable to automatically determine one. {details} file: shared module {request}")).with_diagnostic_code(&SHARED_MODULE_VERSION))
     };
     if let Some(version) = config.required_version.as_ref() {
       Some(version.clone())
@@ -314,10 +318,13 @@ impl ConsumeSharedPlugin {
             import,
           )
           .map_err(|_e| {
-            add_diagnostic(Diagnostic::error(
-              "ModuleNotFoundError".into(),
-              format!("resolving fallback for shared module {request}"),
-            ))
+            add_diagnostic(
+              Diagnostic::error(
+                "ModuleNotFoundError".into(),
+                format!("resolving fallback for shared module {request}"),
+              )
+              .with_diagnostic_code(&MODULE_NOT_FOUND),
+            )
           })
           .ok()
       })
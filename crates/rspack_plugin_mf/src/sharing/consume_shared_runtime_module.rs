@@ -8,7 +8,7 @@ use rspack_core::{
 use rustc_hash::FxHashMap;
 
 use super::consume_shared_plugin::ConsumeVersion;
-use crate::utils::json_stringify;
+use crate::{utils::json_stringify, ShareScope};
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -148,7 +148,7 @@ __webpack_require__.consumesLoadingData = {{ chunkMapping: {chunk_mapping}, modu
 
 #[derive(Debug, Clone)]
 pub struct CodeGenerationDataConsumeShared {
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub share_key: String,
   pub import: Option<String>,
   pub required_version: Option<ConsumeVersion>,
@@ -430,10 +430,24 @@ var get = function(entry) {
 	entry.loaded = 1;
 	return entry.get()
 };
+// the share scopes of a module are searched in the configured order after all of them are
+// initialized, the first one providing the key is used, otherwise the primary (first) one
+var resolveScope = function(scopeName, key) {
+	if (!Array.isArray(scopeName)) return scopeName;
+	for (var i = 0; i < scopeName.length; i++) {
+		var scope = __webpack_require__.S[scopeName[i]];
+		if (scope && __webpack_require__.o(scope, key)) return scopeName[i];
+	}
+	return scopeName[0];
+};
 var init = function(fn) { return function(scopeName, a, b, c) {
 	var promise = __webpack_require__.I(scopeName);
-	if (promise && promise.then) return promise.then(fn.bind(fn, scopeName, __webpack_require__.S[scopeName], a, b, c));
-	return fn(scopeName, __webpack_require__.S[scopeName], a, b, c);
+	var call = function() {
+		var name = resolveScope(scopeName, a);
+		return fn(name, __webpack_require__.S[name], a, b, c);
+	};
+	if (promise && promise.then) return promise.then(call);
+	return call();
 }; };
 
 var load = /*#__PURE__*/ init(function(scopeName, scope, key) {
@@ -2,6 +2,18 @@ var initPromises = {};
 var initTokens = {};
 __webpack_require__.I = function(name, initScope) {
 	if (!initScope) initScope = [];
+	// initializes every share scope of a module registered into several ones
+	if (Array.isArray(name)) {
+		var pending = [];
+		name.forEach(function (name) {
+			var promise = __webpack_require__.I(name, initScope);
+			if (promise && promise.then) pending.push(promise);
+		});
+		if (!pending.length) return 1;
+		return Promise.all(pending).then(function () {
+			return 1;
+		});
+	}
 	// handling circular init calls
 	var initToken = initTokens[name];
 	if (!initToken) initToken = initTokens[name] = {};
@@ -41,7 +53,7 @@ __webpack_require__.I = function(name, initScope) {
 				return (
 					module &&
 					module.init &&
-					module.init(__webpack_require__.S[name], initScope)
+					module.init(__webpack_require__.S[name], initScope, name)
 				);
 			};
 			if (module.then) return promises.push(module.then(initFn, handleError));
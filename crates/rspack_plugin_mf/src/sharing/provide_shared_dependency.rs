@@ -4,13 +4,13 @@ use rspack_core::{
 };
 
 use super::provide_shared_plugin::ProvideVersion;
-use crate::ConsumeVersion;
+use crate::{ConsumeVersion, ShareScope};
 
 #[derive(Debug, Clone)]
 pub struct ProvideSharedDependency {
   id: DependencyId,
   request: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub name: String,
   pub version: ProvideVersion,
   pub eager: bool,
@@ -23,7 +23,7 @@ pub struct ProvideSharedDependency {
 impl ProvideSharedDependency {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    share_scope: String,
+    share_scope: ShareScope,
     name: String,
     version: ProvideVersion,
     request: String,
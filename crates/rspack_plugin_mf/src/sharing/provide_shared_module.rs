@@ -20,7 +20,7 @@ use super::{
     CodeGenerationDataShareInit, DataInitInfo, ProvideSharedInfo, ShareInitData,
   },
 };
-use crate::ConsumeVersion;
+use crate::{ConsumeVersion, ShareScope};
 
 #[impl_source_map_config]
 #[derive(Debug)]
@@ -31,7 +31,7 @@ pub struct ProvideSharedModule {
   lib_ident: String,
   readable_identifier: String,
   name: String,
-  share_scope: String,
+  share_scope: ShareScope,
   version: ProvideVersion,
   request: String,
   eager: bool,
@@ -46,7 +46,7 @@ pub struct ProvideSharedModule {
 impl ProvideSharedModule {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    share_scope: String,
+    share_scope: ShareScope,
     name: String,
     version: ProvideVersion,
     request: String,
@@ -79,6 +79,30 @@ impl ProvideSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn share_key(&self) -> &str {
+    &self.name
+  }
+
+  pub fn share_scope(&self) -> &ShareScope {
+    &self.share_scope
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn eager(&self) -> bool {
+    self.eager
+  }
+
+  pub fn singleton(&self) -> Option<bool> {
+    self.singleton
+  }
+
+  pub fn required_version(&self) -> Option<&ConsumeVersion> {
+    self.required_version.as_ref()
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -168,7 +192,7 @@ impl Module for ProvideSharedModule {
     })
   }
 
-  #[tracing::instrument(name = "ProvideSharedModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  #[tracing::instrument(name = "ProvideSharedModule::code_generation", skip_all, fields(identifier = %self.identifier()))]
   fn code_generation(
     &self,
     compilation: &Compilation,
@@ -197,19 +221,23 @@ impl Module for ProvideSharedModule {
     code_generation_result
       .data
       .insert(CodeGenerationDataShareInit {
-        items: vec![ShareInitData {
-          share_scope: self.share_scope.clone(),
-          init_stage: 10,
-          init: DataInitInfo::ProvideSharedInfo(ProvideSharedInfo {
-            name: self.name.clone(),
-            version: self.version.clone(),
-            factory,
-            eager: self.eager,
-            singleton: self.singleton,
-            strict_version: self.strict_version,
-            required_version: self.required_version.clone(),
-          }),
-        }],
+        items: self
+          .share_scope
+          .iter()
+          .map(|share_scope| ShareInitData {
+            share_scope: share_scope.to_string(),
+            init_stage: 10,
+            init: DataInitInfo::ProvideSharedInfo(ProvideSharedInfo {
+              name: self.name.clone(),
+              version: self.version.clone(),
+              factory: factory.clone(),
+              eager: self.eager,
+              singleton: self.singleton,
+              strict_version: self.strict_version,
+              required_version: self.required_version.clone(),
+            }),
+          })
+          .collect(),
       });
     Ok(code_generation_result)
   }
@@ -8,7 +8,7 @@ use rspack_core::{
   CompilerOptions, DependencyType, EntryOptions, ModuleFactoryCreateData, NormalModuleCreateData,
   NormalModuleFactoryModule, Plugin, PluginContext,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{code::SHARED_MODULE_VERSION, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_loader_runner::ResourceData;
 use rustc_hash::FxHashMap;
@@ -18,7 +18,7 @@ use super::{
   provide_shared_dependency::ProvideSharedDependency,
   provide_shared_module_factory::ProvideSharedModuleFactory,
 };
-use crate::ConsumeVersion;
+use crate::{ConsumeVersion, ShareScope};
 
 static RELATIVE_REQUEST: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"^(\/|[A-Za-z]:\\|\\\\|\.\.?(\/|$))").expect("Invalid regex"));
@@ -28,7 +28,7 @@ static ABSOLUTE_REQUEST: LazyLock<Regex> =
 #[derive(Debug, Clone)]
 pub struct ProvideOptions {
   pub share_key: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub version: Option<ProvideVersion>,
   pub eager: bool,
   pub singleton: Option<bool>,
@@ -39,7 +39,7 @@ pub struct ProvideOptions {
 #[derive(Debug, Clone)]
 pub struct VersionedProvideOptions {
   pub share_key: String,
-  pub share_scope: String,
+  pub share_scope: ShareScope,
   pub version: ProvideVersion,
   pub eager: bool,
   pub singleton: Option<bool>,
@@ -101,7 +101,7 @@ impl ProvideSharedPlugin {
     &self,
     key: &str,
     share_key: &str,
-    share_scope: &str,
+    share_scope: &ShareScope,
     version: Option<&ProvideVersion>,
     eager: bool,
     singleton: Option<bool>,
@@ -118,7 +118,7 @@ impl ProvideSharedPlugin {
         resource.to_string(),
         VersionedProvideOptions {
           share_key: share_key.to_string(),
-          share_scope: share_scope.to_string(),
+          share_scope: share_scope.clone(),
           version: version.to_owned(),
           eager,
           singleton,
@@ -135,7 +135,7 @@ impl ProvideSharedPlugin {
           resource.to_string(),
           VersionedProvideOptions {
             share_key: share_key.to_string(),
-            share_scope: share_scope.to_string(),
+            share_scope: share_scope.clone(),
             version: ProvideVersion::Version(version.to_string()),
             eager,
             singleton,
@@ -144,10 +144,10 @@ impl ProvideSharedPlugin {
           },
         );
       } else {
-        add_diagnostic(Diagnostic::warn(title.to_string(), format!("{error_header} No version in description file (usually package.json). Add version to description file {}, or manually specify version in shared config. shared module {key} -> {resource}", description.path().display())));
+        add_diagnostic(Diagnostic::warn(title.to_string(), format!("{error_header} No version in description file (usually package.json). Add version to description file {}, or manually specify version in shared config. shared module {key} -> {resource}", description.path().display())).with_diagnostic_code(&SHARED_MODULE_VERSION));
       }
     } else {
-      add_diagnostic(Diagnostic::warn(title.to_string(), format!("{error_header} No description file (usually package.json) found. Add description file with name and version, or manually specify version in shared config. shared module {key} -> {resource}")));
+      add_diagnostic(Diagnostic::warn(title.to_string(), format!("{error_header} No description file (usually package.json) found. Add description file with name and version, or manually specify version in shared config. shared module {key} -> {resource}")).with_diagnostic_code(&SHARED_MODULE_VERSION));
     }
   }
 }
@@ -188,7 +188,7 @@ async fn finish_make(&self, compilation: &mut Compilation) -> Result<()> {
     compilation
       .add_include(
         Box::new(ProvideSharedDependency::new(
-          config.share_scope.to_string(),
+          config.share_scope.clone(),
           config.share_key.to_string(),
           config.version.clone(),
           resource.to_string(),
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// The share scopes of a shared module or a container.
+///
+/// A shared module can be registered into several share scopes, and a container
+/// can initialize several, the first one is the primary share scope.
+///
+/// The order of the share scopes is kept as configured, since a consumed module is
+/// resolved from the first share scope that provides it at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShareScope(Vec<String>);
+
+impl ShareScope {
+  pub fn new(scopes: Vec<String>) -> Self {
+    debug_assert!(!scopes.is_empty(), "should have at least one share scope");
+    Self(dedupe(scopes))
+  }
+
+  pub fn primary(&self) -> &str {
+    self.0.first().map(|s| s.as_str()).unwrap_or("default")
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    self.0.iter().map(|s| s.as_str())
+  }
+
+  pub fn contains(&self, scope: &str) -> bool {
+    self.0.iter().any(|s| s == scope)
+  }
+}
+
+impl Default for ShareScope {
+  fn default() -> Self {
+    Self(vec!["default".to_string()])
+  }
+}
+
+impl From<String> for ShareScope {
+  fn from(value: String) -> Self {
+    Self(vec![value])
+  }
+}
+
+impl From<&str> for ShareScope {
+  fn from(value: &str) -> Self {
+    Self(vec![value.to_string()])
+  }
+}
+
+impl From<Vec<String>> for ShareScope {
+  fn from(value: Vec<String>) -> Self {
+    if value.is_empty() {
+      Self::default()
+    } else {
+      Self(dedupe(value))
+    }
+  }
+}
+
+/// Removes the repeated share scopes, the first occurrence decides the order.
+fn dedupe(scopes: Vec<String>) -> Vec<String> {
+  let mut deduped: Vec<String> = Vec::with_capacity(scopes.len());
+  for scope in scopes {
+    if !deduped.contains(&scope) {
+      deduped.push(scope);
+    }
+  }
+  deduped
+}
+
+/// A single share scope is rendered as a string to keep the runtime code
+/// compatible with the containers that only know one share scope.
+impl Serialize for ShareScope {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self.0.as_slice() {
+      [scope] => scope.serialize(serializer),
+      scopes => scopes.serialize(serializer),
+    }
+  }
+}
+
+impl fmt::Display for ShareScope {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0.join("|"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::json_stringify;
+
+  #[test]
+  fn render_share_scope() {
+    let single = ShareScope::from("default");
+    assert_eq!(json_stringify(&single), r#""default""#);
+    assert_eq!(single.to_string(), "default");
+
+    let multiple = ShareScope::from(vec!["legacy".to_string(), "next".to_string()]);
+    assert_eq!(json_stringify(&multiple), r#"["legacy","next"]"#);
+    assert_eq!(multiple.to_string(), "legacy|next");
+    assert_eq!(multiple.primary(), "legacy");
+    assert!(multiple.contains("next"));
+
+    let repeated = ShareScope::from(vec![
+      "next".to_string(),
+      "legacy".to_string(),
+      "next".to_string(),
+    ]);
+    assert_eq!(json_stringify(&repeated), r#"["next","legacy"]"#);
+    assert_eq!(repeated.primary(), "next");
+  }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use rspack_core::{
+  ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree,
+  Plugin, PluginContext, RuntimeGlobals, RuntimeModule,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+/// Creates the chunk loading runtime module for a chunk with the runtime requirements of its tree,
+/// the runtime globals used by the runtime module should be inserted into the mutable requirements.
+pub type CreateChunkLoadingRuntimeModule = Box<
+  dyn Fn(
+      &Compilation,
+      &ChunkUkey,
+      &RuntimeGlobals,
+      &mut RuntimeGlobals,
+    ) -> Option<Box<dyn RuntimeModule>>
+    + Send
+    + Sync,
+>;
+
+/// Adds the runtime module of a chunk loading type provided by a plugin.
+///
+/// The runtime module is added to the chunks which load chunks with the custom type,
+/// e.g. `output.chunkLoading: "my-loading"` or `entry.main.chunkLoading: "my-loading"`.
+#[plugin]
+pub struct CustomChunkLoadingPlugin {
+  chunk_loading: ChunkLoading,
+  create_runtime_module: CreateChunkLoadingRuntimeModule,
+}
+
+impl CustomChunkLoadingPlugin {
+  pub fn new(
+    chunk_loading_type: impl Into<String>,
+    create_runtime_module: CreateChunkLoadingRuntimeModule,
+  ) -> Self {
+    Self::new_inner(
+      ChunkLoading::Enable(ChunkLoadingType::Custom(chunk_loading_type.into())),
+      create_runtime_module,
+    )
+  }
+}
+
+impl fmt::Debug for CustomChunkLoadingPlugin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CustomChunkLoadingPlugin")
+      .field("chunk_loading", &self.chunk_loading)
+      .finish_non_exhaustive()
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for CustomChunkLoadingPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    || !is_enabled_for_chunk(chunk_ukey, &self.chunk_loading, compilation)
+  {
+    return Ok(None);
+  }
+  if let Some(runtime_module) = (self.create_runtime_module)(
+    compilation,
+    chunk_ukey,
+    all_runtime_requirements,
+    runtime_requirements_mut,
+  ) {
+    compilation.add_runtime_module(chunk_ukey, runtime_module)?;
+  }
+  Ok(None)
+}
+
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
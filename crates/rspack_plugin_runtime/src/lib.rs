@@ -8,6 +8,8 @@ use rspack_core::{BoxPlugin, ChunkLoading, ChunkLoadingType, PluginExt};
 pub use runtime_plugin::RuntimePlugin;
 mod array_push_callback_chunk_format;
 pub use array_push_callback_chunk_format::ArrayPushCallbackChunkFormatPlugin;
+mod custom_chunk_loading;
+pub use custom_chunk_loading::{CreateChunkLoadingRuntimeModule, CustomChunkLoadingPlugin};
 mod common_js_chunk_loading;
 pub use common_js_chunk_loading::CommonJsChunkLoadingPlugin;
 mod jsonp_chunk_loading;
@@ -65,5 +67,8 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin::default().boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin::default().boxed()),
+    // the plugin which provides the custom chunk loading type adds the runtime module
+    // with `CustomChunkLoadingPlugin`
+    ChunkLoadingType::Custom(_) => {}
   }
 }
@@ -54,7 +54,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::SCRIPT_NONCE,
     RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
@@ -247,9 +247,10 @@ fn runtime_requirements_in_tree(
     .and_then(|options| options.public_path.clone())
     .unwrap_or_else(|| compilation.options.output.public_path.clone());
 
-  // TODO check output.scriptType
+  // module scripts resolve the auto public path from `import.meta.url` instead of the global object
   if matches!(public_path, PublicPath::Auto)
     && runtime_requirements.contains(RuntimeGlobals::PUBLIC_PATH)
+    && compilation.options.output.script_type != "module"
   {
     runtime_requirements_mut.insert(RuntimeGlobals::GLOBAL);
   }
@@ -6,7 +6,10 @@ use rspack_core::{
   ApplyContext, ChunkGroup, ChunkGroupUkey, Compilation, CompilationAsset, CompilerAfterEmit,
   CompilerOptions, Plugin, PluginContext,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{
+  code::{ASSETS_OVER_SIZE_LIMIT, ENTRYPOINTS_OVER_SIZE_LIMIT, NO_ASYNC_CHUNKS},
+  Diagnostic, DiagnosticCode, Result,
+};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::size::format_size;
 
@@ -71,6 +74,7 @@ impl SizeLimitsPlugin {
     hints: &str,
     title: String,
     message: String,
+    code: &'static DiagnosticCode,
     diagnostics: &mut Vec<Diagnostic>,
   ) {
     let diagnostic = match hints {
@@ -78,7 +82,7 @@ impl SizeLimitsPlugin {
       "warning" => Diagnostic::warn(title, message),
       _ => Diagnostic::error(title, format!("Invalid hints type: {hints}")),
     };
-    diagnostics.push(diagnostic);
+    diagnostics.push(diagnostic.with_diagnostic_code(code));
   }
 
   fn add_assets_over_size_limit_warning(
@@ -95,7 +99,7 @@ impl SizeLimitsPlugin {
     let title = String::from("assets over size limit warning");
     let message = format!("asset size limit: The following asset(s) exceed the recommended size limit ({}). This can impact web performance.\nAssets:{}", format_size(limit), asset_list);
 
-    Self::add_diagnostic(hints, title, message, diagnostics);
+    Self::add_diagnostic(hints, title, message, &ASSETS_OVER_SIZE_LIMIT, diagnostics);
   }
 
   fn add_entrypoints_over_size_limit_warning(
@@ -127,7 +131,13 @@ impl SizeLimitsPlugin {
       entrypoint_list
     );
 
-    Self::add_diagnostic(hints, title, message, diagnostics);
+    Self::add_diagnostic(
+      hints,
+      title,
+      message,
+      &ENTRYPOINTS_OVER_SIZE_LIMIT,
+      diagnostics,
+    );
   }
 }
 
@@ -215,7 +225,7 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
         let title = String::from("no async chunks warning");
         let message = String::from("Rspack performance recommendations:\nYou can limit the size of your bundles by using import() to lazy load some parts of your application.\nFor more info visit https://www.rspack.dev/guide/optimization/code-splitting");
 
-        Self::add_diagnostic(hints, title, message, &mut diagnostics);
+        Self::add_diagnostic(hints, title, message, &NO_ASYNC_CHUNKS, &mut diagnostics);
       }
 
       compilation.extend_diagnostics(diagnostics);
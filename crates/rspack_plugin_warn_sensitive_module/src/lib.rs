@@ -8,7 +8,7 @@ use rspack_core::{
   ApplyContext, Compilation, CompilationSeal, CompilerOptions, Logger, ModuleGraph, Plugin,
   PluginContext,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{code::CASE_SENSITIVE_MODULES, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
@@ -89,10 +89,13 @@ async fn seal(&self, compilation: &mut Compilation) -> Result<()> {
   for (_, set) in case_map_vec {
     let mut case_modules = set.iter().copied().collect::<Vec<_>>();
     case_modules.sort_unstable();
-    diagnostics.push(Diagnostic::warn(
-      "Sensitive Modules Warn".to_string(),
-      self.create_sensitive_modules_warning(case_modules, &compilation.get_module_graph()),
-    ));
+    diagnostics.push(
+      Diagnostic::warn(
+        "Sensitive Modules Warn".to_string(),
+        self.create_sensitive_modules_warning(case_modules, &compilation.get_module_graph()),
+      )
+      .with_diagnostic_code(&CASE_SENSITIVE_MODULES),
+    );
   }
 
   compilation.extend_diagnostics(diagnostics);
@@ -3,7 +3,9 @@ use rspack_core::{
   DependencyRange, DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph,
   RuntimeSpec,
 };
+use rspack_error::{code::UNSUPPORTED_FEATURE, Diagnostic};
 use swc_core::ecma::atoms::Atom;
+use wasmparser::{TypeRef, ValType};
 
 use crate::WasmNode;
 
@@ -13,26 +15,44 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// non-function imports can only be provided by another wasm instance directly
+  only_direct_import: bool,
   /// the WASM AST node
   pub desc: WasmNode,
+  /// params of the imported function signature
+  params: Vec<ValType>,
   span: Option<DependencyRange>,
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(request: String, name: String, desc: WasmNode, only_direct_import: bool) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
+      only_direct_import,
       desc,
-      // only_direct_import,
+      params: vec![],
       span: None,
     }
   }
+
+  pub fn with_params(mut self, params: Vec<ValType>) -> Self {
+    self.params = params;
+    self
+  }
+
   pub fn name(&self) -> &str {
     &self.name
   }
+
+  pub fn only_direct_import(&self) -> bool {
+    self.only_direct_import
+  }
+
+  pub fn params(&self) -> &[ValType] {
+    &self.params
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -59,6 +79,31 @@ impl Dependency for WasmImportDependency {
   fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
     rspack_core::AffectType::True
   }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    if !self.only_direct_import {
+      return None;
+    }
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if module.module_type().is_wasm_like() {
+      return None;
+    }
+    let kind = match self.desc {
+      TypeRef::Func(_) => "Func",
+      TypeRef::Table(_) => "Table",
+      TypeRef::Memory(_) => "Memory",
+      TypeRef::Global(_) => "Global",
+      TypeRef::Tag(_) => "Tag",
+    };
+    Some(vec![Diagnostic::error(
+      "UnsupportedWebAssemblyFeatureError".into(),
+      format!(
+        "Import \"{}\" from \"{}\" with {kind} can only be used for direct wasm to wasm dependencies",
+        self.name, self.request
+      ),
+    )
+    .with_diagnostic_code(&UNSUPPORTED_FEATURE)])
+  }
 }
 
 impl ModuleDependency for WasmImportDependency {
@@ -1,17 +1,163 @@
 use rspack_core::{
-  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginContext,
-  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
+  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, ModuleType, Plugin,
+  PluginContext, PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin::default().boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new(false).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileWasmPlugin::new(true).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
+  }
+}
+
+fn chunk_has_sync_wasm(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> bool {
+  let module_graph = compilation.get_module_graph();
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  chunk
+    .get_all_referenced_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|chunk_ukey| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(chunk_ukey, &module_graph)
+        .iter()
+        .any(|module| *module.module_type() == ModuleType::WasmSync)
+    })
+}
+
+fn read_file_compile_wasm_code(import: bool) -> String {
+  if import {
+    include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+  } else {
+    include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && chunk_has_sync_wasm(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(
+        self,
+      ));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self::new_inner(import)
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && chunk_has_sync_wasm(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        read_file_compile_wasm_code(self.import),
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
   }
 }
 
@@ -89,11 +235,7 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
     compilation.add_runtime_module(
       chunk_ukey,
       AsyncWasmLoadingRuntimeModule::new(
-        if self.import {
-          include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
-        } else {
-          include_str!("runtime/read_file_compile_async_wasm.js").to_string()
-        },
+        read_file_compile_wasm_code(self.import),
         false,
         *chunk_ukey,
       )
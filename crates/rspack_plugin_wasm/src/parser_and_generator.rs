@@ -12,7 +12,9 @@ use rspack_core::{
   ParseContext, ParseResult, ParserAndGenerator, PathData, RuntimeGlobals, SourceType,
   StaticExportsDependency, StaticExportsSpec, UsedName,
 };
-use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{
+  code::WASM_PARSE_FAILED, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+};
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::itoa;
 use swc_core::atoms::Atom;
@@ -51,10 +53,10 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
             for export in s {
               match export {
                 Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
+                Err(err) => diagnostic.push(
+                  Diagnostic::error("Wasm Export Parse Error".into(), err.to_string())
+                    .with_diagnostic_code(&WASM_PARSE_FAILED),
+                ),
               };
             }
           }
@@ -66,22 +68,23 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                     module.into(),
                     name.into(),
                     ty,
+                    false,
                   )));
                 }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
+                Err(err) => diagnostic.push(
+                  Diagnostic::error("Wasm Import Parse Error".into(), err.to_string())
+                    .with_diagnostic_code(&WASM_PARSE_FAILED),
+                ),
               }
             }
           }
           _ => {}
         },
         Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-          ));
+          diagnostic.push(
+            Diagnostic::error("Wasm Parse Error".into(), err.to_string())
+              .with_diagnostic_code(&WASM_PARSE_FAILED),
+          );
         }
       }
     }
@@ -297,7 +300,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &FilenameTemplate,
@@ -314,12 +317,12 @@ fn render_wasm_name(
     .always_ok()
 }
 
-fn render_import_stmt(import_var: &str, module_id: &ModuleId) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &ModuleId) -> String {
   let module_id = serde_json::to_string(module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
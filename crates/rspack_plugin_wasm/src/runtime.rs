@@ -1,13 +1,17 @@
 use cow_utils::CowUtils;
+use indexmap::IndexMap;
 use rspack_collections::Identifier;
 use rspack_core::rspack_sources::{BoxSource, RawStringSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, ChunkGraph, ChunkUkey, Compilation,
+  ModuleDependency, ModuleIdentifier, ModuleType, PathData, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage, RuntimeSpec, SourceType, UsedName,
 };
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::itoa;
 
+use crate::{dependency::WasmImportDependency, hash_for_source};
+
 #[impl_runtime_module]
 #[derive(Debug)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -37,29 +41,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
     self.id
   }
   fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
-    let (fake_filename, hash_len_map) =
-      get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
-
-    // Even use content hash when [hash] in webpack
-    let hash = match hash_len_map
-      .get("[contenthash]")
-      .or(hash_len_map.get("[hash]"))
-    {
-      Some(hash_len) => format!("\" + wasmModuleHash.slice(0, {}) + \"", itoa!(*hash_len)),
-      None => "\" + wasmModuleHash + \"".to_string(),
-    };
-
-    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
-    let path = compilation
-      .get_path(
-        &fake_filename,
-        PathData::default()
-          .hash(&hash)
-          .content_hash(&hash)
-          .id("\" + wasmModuleId + \"")
-          .runtime(chunk.runtime().as_str()),
-      )
-      .always_ok();
+    let path = get_wasm_module_path(compilation, &self.chunk);
     Ok(
       RawStringSource::from(get_async_wasm_loading(
         &self
@@ -80,6 +62,34 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   }
 }
 
+/// The path of the wasm file, `wasmModuleId` and `wasmModuleHash` should be defined
+/// in the scope where the path is used
+fn get_wasm_module_path(compilation: &Compilation, chunk: &ChunkUkey) -> String {
+  let (fake_filename, hash_len_map) =
+    get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
+
+  // Even use content hash when [hash] in webpack
+  let hash = match hash_len_map
+    .get("[contenthash]")
+    .or(hash_len_map.get("[hash]"))
+  {
+    Some(hash_len) => format!("\" + wasmModuleHash.slice(0, {}) + \"", itoa!(*hash_len)),
+    None => "\" + wasmModuleHash + \"".to_string(),
+  };
+
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  compilation
+    .get_path(
+      &fake_filename,
+      PathData::default()
+        .hash(&hash)
+        .content_hash(&hash)
+        .id("\" + wasmModuleId + \"")
+        .runtime(chunk.runtime().as_str()),
+    )
+    .always_ok()
+}
+
 fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
   let fallback_code = r#"
           .then(function(x) { return x.arrayBuffer();})
@@ -129,3 +139,332 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct WasmChunkLoadingRuntimeModule {
+  id: Identifier,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      generate_load_binary_code,
+      supports_streaming,
+      chunk,
+    )
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let module_graph = compilation.get_module_graph();
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let runtime = chunk.runtime();
+
+    let mut wasm_modules = vec![];
+    let mut wasm_module_map = vec![];
+    for chunk_ukey in chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey) {
+      let Some(chunk_id) = compilation.chunk_by_ukey.expect_get(&chunk_ukey).id() else {
+        continue;
+      };
+      let mut modules = compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk_ukey, &module_graph)
+        .into_iter()
+        .filter(|module| *module.module_type() == ModuleType::WasmSync)
+        .filter_map(|module| {
+          ChunkGraph::get_module_id(&compilation.module_ids, module.identifier())
+            .map(|module_id| (module.identifier(), module_id))
+        })
+        .collect::<Vec<_>>();
+      if modules.is_empty() {
+        continue;
+      }
+      modules.sort_unstable_by(|a, b| a.1.as_str().cmp(b.1.as_str()));
+      wasm_module_map.push(format!(
+        "{}: [{}]",
+        serde_json::to_string(chunk_id).expect("should convert to json string"),
+        modules
+          .iter()
+          .map(|(_, module_id)| {
+            serde_json::to_string(module_id).expect("should convert to json string")
+          })
+          .collect::<Vec<_>>()
+          .join(", ")
+      ));
+      wasm_modules.extend(modules);
+    }
+    wasm_modules.sort_unstable_by(|a, b| a.1.as_str().cmp(b.1.as_str()));
+    wasm_modules.dedup_by(|a, b| a.0 == b.0);
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut wasm_module_hashes = vec![];
+    for (module_identifier, module_id) in &wasm_modules {
+      let module_id = serde_json::to_string(module_id).expect("should convert to json string");
+      import_objects.push(generate_import_object(
+        compilation,
+        module_identifier,
+        &module_id,
+        runtime,
+        &mut declarations,
+      ));
+      if let Some(source) = compilation
+        .code_generation_results
+        .get(module_identifier, Some(runtime))
+        .get(&SourceType::Wasm)
+      {
+        wasm_module_hashes.push(format!("{module_id}: \"{}\"", hash_for_source(source)));
+      }
+    }
+
+    let path = get_wasm_module_path(compilation, &self.chunk);
+    let req = self
+      .generate_load_binary_code
+      .cow_replace("$PATH", &format!("\"{}\"", path))
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .trim_end_matches(';')
+      .to_string();
+
+    let instantiate_code = if self.supports_streaming {
+      r#"
+      if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+        promise = WebAssembly.instantiateStreaming(req, importObject);
+      } else {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+    } else {
+      r#"
+      if(importObject && typeof importObject.then === 'function') {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = Promise.all([
+          bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+          importObject
+        ]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+    };
+
+    Ok(
+      RawStringSource::from(format!(
+        r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {{
+{wasm_module_map}
+}};
+
+var wasmModuleHashes = {{
+{wasm_module_hashes}
+}};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData) {{
+      promises.push(installedWasmModuleData);
+    }} else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var wasmModuleHash = wasmModuleHashes[wasmModuleId];
+      var req = {req};
+      var promise;{instantiate_code}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+        declarations = declarations.join("\n"),
+        import_objects = import_objects.join(",\n"),
+        wasm_module_map = wasm_module_map.join(",\n"),
+        wasm_module_hashes = wasm_module_hashes.join(",\n"),
+        wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+        ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      ))
+      .boxed(),
+    )
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+/// Generate the function returning the import object of a sync wasm module.
+///
+/// Functions are wrapped to read the exports lazily from the module cache, other imports
+/// can only be provided by the instance of another wasm module, which has to be loaded first.
+fn generate_import_object(
+  compilation: &Compilation,
+  module_identifier: &ModuleIdentifier,
+  module_id: &str,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let module = module_graph
+    .module_by_identifier(module_identifier)
+    .expect("should have module");
+
+  let mut wait_for_instances: Vec<(String, String)> = vec![];
+  let mut properties_by_request: IndexMap<&str, Vec<String>> = IndexMap::default();
+  for dep_id in module.get_dependencies() {
+    let Some(dep) = module_graph
+      .dependency_by_id(dep_id)
+      .and_then(|dep| dep.downcast_ref::<WasmImportDependency>())
+    else {
+      continue;
+    };
+    let Some(imported_module) = module_graph.get_module_by_dependency_id(dep_id) else {
+      continue;
+    };
+    let Some(imported_module_id) =
+      ChunkGraph::get_module_id(&compilation.module_ids, imported_module.identifier())
+    else {
+      continue;
+    };
+    let imported_module_id =
+      serde_json::to_string(imported_module_id).expect("should convert to json string");
+    let used_name = module_graph
+      .get_exports_info(&imported_module.identifier())
+      .get_used_name(
+        &module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      )
+      .and_then(|used_name| match used_name {
+        UsedName::Str(used_name) => Some(used_name),
+        UsedName::Vec(_) => None,
+      })
+      .unwrap_or_else(|| dep.name().into());
+    let used_name = serde_json::to_string(&used_name).expect("should convert to json string");
+
+    let value = if dep.only_direct_import() {
+      let instance_var = format!("m{}", itoa!(wait_for_instances.len()));
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.push((instance_var, imported_module_id));
+      value
+    } else {
+      let params = dep
+        .params()
+        .iter()
+        .enumerate()
+        .map(|(index, param)| format!("p{}{param}", itoa!(index)))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let module = format!("{}[{imported_module_id}]", RuntimeGlobals::MODULE_CACHE);
+      let cache = format!("wasmImportedFuncCache{}", itoa!(declarations.len()));
+      declarations.push(format!("var {cache};"));
+      let module_code = if imported_module.module_type().is_wasm_like() {
+        format!("{module} ? {module}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{module_code}function({params}) {{
+    if({cache} === undefined) {cache} = {module}.exports;
+    return {cache}[{used_name}]({params});
+  }}"#
+      )
+    };
+    properties_by_request
+      .entry(dep.request())
+      .or_default()
+      .push(format!(
+        "{}: {value}",
+        serde_json::to_string(dep.name()).expect("should convert to json string")
+      ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties_by_request
+      .into_iter()
+      .map(|(request, properties)| format!(
+        "{}: {{\n{}\n}}",
+        serde_json::to_string(request).expect("should convert to json string"),
+        properties.join(",\n")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+
+  match wait_for_instances.len() {
+    0 => format!("{module_id}: function() {{\n{import_object}\n}}"),
+    1 => {
+      let (instance_var, imported_module_id) = &wait_for_instances[0];
+      format!(
+        r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return installedWasmModules[{imported_module_id}]; }}).then(function({instance_var}) {{
+{import_object}
+}});
+}}"#
+      )
+    }
+    _ => {
+      let promises = wait_for_instances
+        .iter()
+        .map(|(_, imported_module_id)| format!("installedWasmModules[{imported_module_id}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .iter()
+        .enumerate()
+        .map(|(index, (instance_var, _))| format!("{instance_var} = array[{}]", itoa!(index)))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{
+var {variables};
+{import_object}
+}});
+}}"#
+      )
+    }
+  }
+}
@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+
+use indexmap::IndexSet;
+use rspack_core::rspack_sources::{BoxSource, RawStringSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, ChunkGraph, GenerateContext, Module, ModuleIdentifier,
+  ParseContext, ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType,
+  StaticExportsDependency, StaticExportsSpec,
+};
+use rspack_error::{
+  code::WASM_PARSE_FAILED, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
+};
+use rspack_util::itoa;
+use swc_core::atoms::Atom;
+use wasmparser::{CompositeInnerType, FuncType, Import, Parser, Payload, TypeRef};
+
+use crate::dependency::WasmImportDependency;
+use crate::{
+  hash_for_source, render_import_stmt, render_wasm_name, ModuleIdToFileName, WASM_SOURCE_TYPE,
+};
+
+/// Parser and generator for `webassembly/sync` modules.
+///
+/// The wasm instance is created by the chunk loading runtime before the module is executed,
+/// so the generated javascript only needs to read the exports from `__webpack_require__.w`.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut func_types: Vec<Option<FuncType>> = vec![];
+    let mut exports = Vec::with_capacity(1);
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for rec_group in s {
+              match rec_group {
+                Ok(rec_group) => {
+                  func_types.extend(rec_group.into_types().map(
+                    |ty| match ty.composite_type.inner {
+                      CompositeInnerType::Func(func_type) => Some(func_type),
+                      _ => None,
+                    },
+                  ))
+                }
+                Err(err) => diagnostic.push(
+                  Diagnostic::error("Wasm Type Parse Error".into(), err.to_string())
+                    .with_diagnostic_code(&WASM_PARSE_FAILED),
+                ),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => exports.push(export.name.to_string()),
+                Err(err) => diagnostic.push(
+                  Diagnostic::error("Wasm Export Parse Error".into(), err.to_string())
+                    .with_diagnostic_code(&WASM_PARSE_FAILED),
+                ),
+              };
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let params = match ty {
+                    TypeRef::Func(index) => func_types
+                      .get(index as usize)
+                      .and_then(|func_type| func_type.as_ref())
+                      .map(|func_type| func_type.params().to_vec())
+                      .unwrap_or_default(),
+                    _ => vec![],
+                  };
+                  dependencies.push(Box::new(
+                    WasmImportDependency::new(
+                      module.into(),
+                      name.into(),
+                      ty,
+                      !matches!(ty, TypeRef::Func(_)),
+                    )
+                    .with_params(params),
+                  ));
+                }
+                Err(err) => diagnostic.push(
+                  Diagnostic::error("Wasm Import Parse Error".into(), err.to_string())
+                    .with_diagnostic_code(&WASM_PARSE_FAILED),
+                ),
+              }
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(
+            Diagnostic::error("Wasm Parse Error".into(), err.to_string())
+              .with_diagnostic_code(&WASM_PARSE_FAILED),
+          );
+        }
+      }
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let GenerateContext {
+      compilation,
+      runtime,
+      ..
+    } = generate_context;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.get_module_graph();
+        let module_argument = module.get_module_argument();
+        let exports_argument = module.get_exports_argument();
+
+        // the wasm instance is not renamed, so map the used names to the original export names
+        let exports_info = module_graph.get_exports_info(&module.identifier());
+        let exports_code = exports_info
+          .ordered_exports(module_graph)
+          .filter_map(|export_info| {
+            let name = export_info.name(module_graph)?;
+            let used_name = export_info.get_used_name(module_graph, Some(name), *runtime)?;
+            Some(format!(
+              "{exports_argument}[{}] = wasmExports[{}];\n",
+              serde_json::to_string(&used_name).expect("should convert to json string"),
+              serde_json::to_string(name).expect("should convert to json string"),
+            ))
+          })
+          .collect::<Vec<_>>()
+          .join("");
+
+        let imported_modules = module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id).expect("should be ok"))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+          .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep.id()))
+          .copied()
+          .collect::<IndexSet<ModuleIdentifier>>();
+        let imports_code = imported_modules
+          .iter()
+          .enumerate()
+          .map(|(index, module_identifier)| {
+            let module_id = ChunkGraph::get_module_id(&compilation.module_ids, *module_identifier)
+              .expect("should have module id");
+            render_import_stmt(
+              &format!("WEBPACK_IMPORTED_MODULE_{}", itoa!(index)),
+              module_id,
+            )
+          })
+          .collect::<Vec<_>>()
+          .join("");
+
+        Ok(
+          RawStringSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[{module_argument}.id];
+{}({exports_argument});
+// export exports from WebAssembly module
+{exports_code}// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES,
+            RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<Cow<'static, str>> {
+    Some("Module Concatenation is not implemented for SyncWasmParserAndGenerator".into())
+  }
+}
@@ -7,10 +7,10 @@ use rspack_core::{
   CompilerCompilation, CompilerOptions, DependencyType, ModuleType, ParserAndGenerator, Plugin,
   PluginContext, RenderManifestEntry, SourceType,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{code::WASM_IN_INITIAL_CHUNK, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -45,6 +45,21 @@ async fn render_manifest(
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<()> {
+  manifest.extend(render_wasm_manifest(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmAsync,
+    &self.module_id_to_filename_without_ext,
+  )?);
+  Ok(())
+}
+
+fn render_wasm_manifest(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module_type: &ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> Result<Vec<RenderManifestEntry>> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
   let module_graph = &compilation.get_module_graph();
 
@@ -54,15 +69,14 @@ async fn render_manifest(
 
   let files = ordered_modules
     .par_iter()
-    .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+    .filter(|m| m.module_type() == module_type)
     .map(|m| {
       let code_gen_result = compilation
         .code_generation_results
         .get(&m.identifier(), Some(chunk.runtime()));
 
       let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-        let (output_path, asset_info) = self
-          .module_id_to_filename_without_ext
+        let (output_path, asset_info) = module_id_to_filename
           .get(&m.identifier())
           .map(|s| s.clone())
           .expect("should have wasm_filename");
@@ -82,9 +96,8 @@ async fn render_manifest(
     .into_iter()
     .flatten()
     .collect::<Vec<RenderManifestEntry>>();
-  manifest.extend(files);
 
-  Ok(())
+  Ok(files)
 }
 
 #[async_trait]
@@ -121,3 +134,97 @@ impl Plugin for AsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn sync_wasm_compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationRenderManifest for SyncWasmPlugin)]
+async fn sync_wasm_render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  // sync wasm modules are instantiated during chunk loading, which never happens for initial chunks
+  if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+    let module_graph = compilation.get_module_graph();
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(chunk_ukey, &module_graph)
+      .into_iter()
+      .filter(|m| *m.module_type() == ModuleType::WasmSync)
+    {
+      diagnostics.push(
+        Diagnostic::error(
+          "WebAssemblyInInitialChunkError".into(),
+          format!(
+            "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:\n* {}",
+            module.readable_identifier(&compilation.options.context)
+          ),
+        )
+        .with_diagnostic_code(&WASM_IN_INITIAL_CHUNK),
+      );
+    }
+  }
+
+  manifest.extend(render_wasm_manifest(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmSync,
+    &self.module_id_to_filename_without_ext,
+  )?);
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(sync_wasm_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(sync_wasm_render_manifest::new(self));
+
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(move |_, _| {
+        Box::new({
+          SyncWasmParserAndGenerator {
+            module_id_to_filename: module_id_to_filename_without_ext.clone(),
+          }
+        }) as Box<dyn ParserAndGenerator>
+      }),
+    );
+
+    Ok(())
+  }
+}
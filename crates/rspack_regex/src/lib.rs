@@ -6,12 +6,18 @@ mod napi;
 use std::fmt::Debug;
 
 use cow_utils::CowUtils;
+use rspack_cacheable::{
+  cacheable,
+  with::{AsString, AsStringConverter},
+  DeserializeError, SerializeError,
+};
 use rspack_error::Error;
 use swc_core::ecma::ast::Regex as SwcRegex;
 
 use self::algo::Algo;
 
 /// Using wrapper type required by [TryFrom] trait
+#[cacheable(with=AsString)]
 #[derive(Clone, Hash)]
 pub struct RspackRegex {
   algo: Box<Algo>,
@@ -104,3 +110,19 @@ impl TryFrom<SwcRegex> for RspackRegex {
     RspackRegex::with_flags(value.exp.as_ref(), value.flags.as_ref())
   }
 }
+
+impl AsStringConverter for RspackRegex {
+  fn to_string(&self) -> Result<String, SerializeError> {
+    Ok(format!("{}#{}", self.flags, self.source))
+  }
+  fn from_str(s: &str) -> Result<Self, DeserializeError>
+  where
+    Self: Sized,
+  {
+    let (flags, source) = s
+      .split_once('#')
+      .ok_or(DeserializeError::MessageError("invalid regex"))?;
+    RspackRegex::with_flags(source, flags)
+      .map_err(|_| DeserializeError::MessageError("invalid regex"))
+  }
+}
@@ -15,6 +15,8 @@ pub mod chrome {
   pub use tracing_chrome::FlushGuard;
 }
 
+pub mod profile;
+
 static IS_TRACING_ENABLED: AtomicBool = AtomicBool::new(false);
 
 // skip event because it's not useful for performance analysis
@@ -114,6 +116,26 @@ pub fn enable_tracing_by_env_with_chrome_layer(filter: &str, output: &str) -> Op
   }
 }
 
+/// Aggregates the time spent per hook, plugin, loader and module instead of recording every span,
+/// see [profile] for details.
+pub fn enable_tracing_by_env_with_profile_layer(
+  filter: &str,
+  output: &str,
+) -> Option<profile::ProfileGuard> {
+  if !IS_TRACING_ENABLED.swap(true, Ordering::Relaxed) {
+    use tracing_subscriber::prelude::*;
+    let (profile_layer, guard) = profile::ProfileLayer::new(output);
+    let layers = generate_common_layers(filter);
+    tracing_subscriber::registry()
+      .with(layers)
+      .with(profile_layer.with_filter(FilterEvent {}))
+      .init();
+    Some(guard)
+  } else {
+    None
+  }
+}
+
 enum TraceWriter<'a> {
   Stdout,
   Stderr,
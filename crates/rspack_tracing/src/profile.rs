@@ -0,0 +1,421 @@
+//! A layer aggregating the time spent in spans, so a build profile can be reported without
+//! recording every span like the chrome layer does.
+//!
+//! The spans are categorized by their names:
+//! - `XxxHook::call`: the call of a hook with taps or interceptors, which is instrumented at the
+//!   trace level by `define_hook!`.
+//! - `Plugin::fn`: a tap of a plugin, which is instrumented by `#[plugin_hook]`, if it's called by a hook.
+//! - `Loader::pitch` and `Loader::run`: a loader, with the identifier in the `loader` field.
+//! - `Xxx::build` and `Xxx::code_generation`: a module, with the identifier in the `identifier` field.
+
+use std::{
+  cell::RefCell,
+  fmt::{self, Write as _},
+  io::{self, Write},
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::{json, Value};
+use tracing::{
+  field::{Field, Visit},
+  span::{Attributes, Id},
+  Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::TraceWriter;
+
+thread_local! {
+  /// The spans entered on the current thread, used to calculate the self time.
+  static ENTERED: RefCell<Vec<EnteredSpan>> = const { RefCell::new(Vec::new()) };
+}
+
+struct EnteredSpan {
+  id: Id,
+  start: Instant,
+  children: Duration,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Stat {
+  count: u64,
+  self_time: Duration,
+  total_time: Duration,
+}
+
+impl Stat {
+  fn to_json(self) -> Value {
+    json!({
+      "count": self.count,
+      "selfTime": as_millis(self.self_time),
+      "totalTime": as_millis(self.total_time),
+    })
+  }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+  (duration.as_micros() as f64) / 1000.0
+}
+
+#[derive(Debug, Clone)]
+enum SpanKind {
+  Hook {
+    hook: &'static str,
+  },
+  Plugin {
+    plugin: &'static str,
+    hook: &'static str,
+  },
+  Loader {
+    loader: String,
+  },
+  ModuleBuild {
+    identifier: String,
+  },
+  ModuleCodeGeneration {
+    identifier: String,
+  },
+  Other,
+}
+
+/// The data attached to the span when it's created.
+struct SpanProfile {
+  name: &'static str,
+  kind: SpanKind,
+  /// The folded stack of the span, frames are separated by `;`.
+  stack: Arc<str>,
+}
+
+#[derive(Default)]
+struct Fields {
+  identifier: Option<String>,
+  loader: Option<String>,
+}
+
+impl Visit for Fields {
+  fn record_str(&mut self, field: &Field, value: &str) {
+    match field.name() {
+      "identifier" => self.identifier = Some(value.to_string()),
+      "loader" => self.loader = Some(value.to_string()),
+      _ => {}
+    }
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+    match field.name() {
+      "identifier" => self.identifier = Some(format!("{value:?}")),
+      "loader" => self.loader = Some(format!("{value:?}")),
+      _ => {}
+    }
+  }
+}
+
+#[derive(Default)]
+struct Stats {
+  spans: HashMap<&'static str, Stat>,
+  hooks: HashMap<&'static str, Stat>,
+  plugins: HashMap<(&'static str, &'static str), Stat>,
+  loaders: HashMap<String, Stat>,
+  module_build: HashMap<String, Stat>,
+  module_code_generation: HashMap<String, Stat>,
+  folded: HashMap<Arc<str>, Duration>,
+}
+
+impl Stats {
+  fn update(&mut self, profile: &SpanProfile, f: impl Fn(&mut Stat)) {
+    f(self.spans.entry(profile.name).or_default());
+    match &profile.kind {
+      SpanKind::Hook { hook } => f(self.hooks.entry(hook).or_default()),
+      SpanKind::Plugin { plugin, hook } => f(self.plugins.entry((plugin, hook)).or_default()),
+      SpanKind::Loader { loader } => f(self.loaders.entry(loader.clone()).or_default()),
+      SpanKind::ModuleBuild { identifier } => {
+        f(self.module_build.entry(identifier.clone()).or_default())
+      }
+      SpanKind::ModuleCodeGeneration { identifier } => f(self
+        .module_code_generation
+        .entry(identifier.clone())
+        .or_default()),
+      SpanKind::Other => {}
+    }
+  }
+
+  fn to_json(&self, duration: Duration) -> Value {
+    fn sorted<K>(map: &HashMap<K, Stat>, to_json: impl Fn(&K, Stat) -> Value) -> Vec<Value> {
+      let mut stats = map.iter().collect::<Vec<_>>();
+      stats.sort_by(|a, b| b.1.self_time.cmp(&a.1.self_time));
+      stats.into_iter().map(|(k, v)| to_json(k, *v)).collect()
+    }
+    fn with(mut value: Value, stat: Stat) -> Value {
+      if let (Some(value), Value::Object(stat)) = (value.as_object_mut(), stat.to_json()) {
+        value.extend(stat);
+      }
+      value
+    }
+
+    json!({
+      "duration": as_millis(duration),
+      "hooks": sorted(&self.hooks, |hook, stat| with(json!({ "hook": hook }), stat)),
+      "plugins": sorted(&self.plugins, |(plugin, hook), stat| {
+        with(json!({ "plugin": plugin, "hook": hook }), stat)
+      }),
+      "loaders": sorted(&self.loaders, |loader, stat| with(json!({ "loader": loader }), stat)),
+      "modules": {
+        "build": sorted(&self.module_build, |identifier, stat| {
+          with(json!({ "identifier": identifier }), stat)
+        }),
+        "codeGeneration": sorted(&self.module_code_generation, |identifier, stat| {
+          with(json!({ "identifier": identifier }), stat)
+        }),
+      },
+      "spans": sorted(&self.spans, |name, stat| with(json!({ "name": name }), stat)),
+    })
+  }
+
+  /// Stacks in the folded format of `flamegraph.pl` and `inferno`, the values are self time in microseconds.
+  fn to_folded(&self) -> String {
+    let mut stacks = self
+      .folded
+      .iter()
+      .map(|(stack, duration)| (stack, duration.as_micros()))
+      .filter(|(_, micros)| *micros > 0)
+      .collect::<Vec<_>>();
+    stacks.sort_unstable();
+    let mut folded = String::new();
+    for (stack, micros) in stacks {
+      let _ = writeln!(folded, "{stack} {micros}");
+    }
+    folded
+  }
+}
+
+struct ProfileState {
+  start: Instant,
+  stats: Mutex<Stats>,
+}
+
+/// Aggregates the self time and the total time of spans, see the [module level docs](self).
+pub struct ProfileLayer {
+  state: Arc<ProfileState>,
+}
+
+/// Writes the profile report aggregated by the [ProfileLayer].
+pub struct ProfileGuard {
+  state: Arc<ProfileState>,
+  output: String,
+}
+
+impl ProfileLayer {
+  /// Creates the layer and the guard writing the report to `output`,
+  /// which could be `stdout`, `stderr` or a file path.
+  pub fn new(output: &str) -> (Self, ProfileGuard) {
+    let state = Arc::new(ProfileState {
+      start: Instant::now(),
+      stats: Default::default(),
+    });
+    (
+      Self {
+        state: state.clone(),
+      },
+      ProfileGuard {
+        state,
+        output: output.to_string(),
+      },
+    )
+  }
+}
+
+fn frame_of(name: &'static str, kind: &SpanKind) -> String {
+  let frame = match kind {
+    SpanKind::Loader { loader } => format!("{name}({loader})"),
+    _ => name.to_string(),
+  };
+  // `;` separates the frames of a folded stack
+  frame.cow_replace(';', ",").into_owned()
+}
+
+impl<S> Layer<S> for ProfileLayer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    let name = span.name();
+    let mut fields = Fields::default();
+    attrs.record(&mut fields);
+
+    let parent = span.parent();
+    let parent_extensions = parent.as_ref().map(|parent| parent.extensions());
+    let parent_profile = parent_extensions
+      .as_ref()
+      .and_then(|extensions| extensions.get::<SpanProfile>());
+
+    let kind = match (
+      name.strip_suffix("::call"),
+      fields.loader,
+      fields.identifier,
+    ) {
+      (Some(hook), _, _) if hook.ends_with("Hook") => SpanKind::Hook { hook },
+      (_, Some(loader), _) if name.starts_with("Loader::") => SpanKind::Loader { loader },
+      (_, _, Some(identifier)) if name.ends_with("::build") => SpanKind::ModuleBuild { identifier },
+      (_, _, Some(identifier)) if name.ends_with("::code_generation") => {
+        SpanKind::ModuleCodeGeneration { identifier }
+      }
+      _ => match parent_profile {
+        Some(SpanProfile {
+          kind: SpanKind::Hook { hook },
+          ..
+        }) => SpanKind::Plugin {
+          plugin: name.split_once("::").map_or(name, |(plugin, _)| plugin),
+          hook,
+        },
+        _ => SpanKind::Other,
+      },
+    };
+
+    let frame = frame_of(name, &kind);
+    let stack: Arc<str> = match parent_profile {
+      Some(parent) => format!("{};{frame}", parent.stack).into(),
+      None => frame.into(),
+    };
+    drop(parent_extensions);
+
+    span
+      .extensions_mut()
+      .insert(SpanProfile { name, kind, stack });
+  }
+
+  fn on_enter(&self, id: &Id, _ctx: Context<'_, S>) {
+    ENTERED.with_borrow_mut(|entered| {
+      entered.push(EnteredSpan {
+        id: id.clone(),
+        start: Instant::now(),
+        children: Duration::ZERO,
+      })
+    });
+  }
+
+  fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+    let Some((elapsed, self_time)) = ENTERED.with_borrow_mut(|entered| {
+      let index = entered.iter().rposition(|span| &span.id == id)?;
+      let span = entered.remove(index);
+      let elapsed = span.start.elapsed();
+      if let Some(parent) = index.checked_sub(1).and_then(|i| entered.get_mut(i)) {
+        parent.children += elapsed;
+      }
+      Some((elapsed, elapsed.saturating_sub(span.children)))
+    }) else {
+      return;
+    };
+
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    let extensions = span.extensions();
+    let Some(profile) = extensions.get::<SpanProfile>() else {
+      return;
+    };
+    let mut stats = self
+      .state
+      .stats
+      .lock()
+      .expect("failed to lock profile stats");
+    stats.update(profile, |stat| {
+      stat.self_time += self_time;
+      stat.total_time += elapsed;
+    });
+    *stats.folded.entry(profile.stack.clone()).or_default() += self_time;
+  }
+
+  fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else {
+      return;
+    };
+    let extensions = span.extensions();
+    let Some(profile) = extensions.get::<SpanProfile>() else {
+      return;
+    };
+    let mut stats = self
+      .state
+      .stats
+      .lock()
+      .expect("failed to lock profile stats");
+    stats.update(profile, |stat| stat.count += 1);
+  }
+}
+
+impl ProfileGuard {
+  /// Writes the report as json to the output, and the folded stacks next to it
+  /// with the `.folded` extension if the output is a file.
+  pub fn flush(&self) -> io::Result<()> {
+    let (report, folded) = {
+      let stats = self
+        .state
+        .stats
+        .lock()
+        .expect("failed to lock profile stats");
+      (stats.to_json(self.state.start.elapsed()), stats.to_folded())
+    };
+    let writer = TraceWriter::from(self.output.as_str());
+    write_report(&writer, &report, &folded).map_err(|e| {
+      io::Error::new(
+        e.kind(),
+        format!("Failed to write the profile to {}: {e}", self.output),
+      )
+    })
+  }
+}
+
+fn write_report(writer: &TraceWriter, report: &Value, folded: &str) -> io::Result<()> {
+  let mut w = writer.writer();
+  serde_json::to_writer_pretty(&mut w, report)?;
+  w.write_all(b"\n")?;
+  w.flush()?;
+  if let TraceWriter::File { path } = writer {
+    std::fs::write(path.with_extension("folded"), folded)?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use tracing_subscriber::prelude::*;
+
+  use super::*;
+
+  #[test]
+  fn should_aggregate_spans_by_kind() {
+    let (layer, guard) = ProfileLayer::new("stdout");
+    let subscriber = tracing_subscriber::registry().with(layer);
+    tracing::subscriber::with_default(subscriber, || {
+      let hook = tracing::trace_span!("CompilationProcessAssetsHook::call");
+      let _hook = hook.enter();
+      for _ in 0..2 {
+        let plugin = tracing::trace_span!("RealContentHashPlugin::process_assets");
+        let _plugin = plugin.enter();
+        std::thread::sleep(Duration::from_millis(1));
+      }
+      let loader = tracing::trace_span!("Loader::run", loader = "builtin:swc-loader");
+      let _loader = loader.enter();
+    });
+
+    let stats = guard.state.stats.lock().expect("should lock");
+    let hook = stats.hooks["CompilationProcessAssetsHook"];
+    let plugin = stats.plugins[&("RealContentHashPlugin", "CompilationProcessAssetsHook")];
+    assert_eq!(hook.count, 1);
+    assert_eq!(plugin.count, 2);
+    assert!(plugin.self_time >= Duration::from_millis(2));
+    assert!(hook.total_time >= plugin.total_time);
+    assert!(hook.self_time < hook.total_time);
+    assert_eq!(stats.loaders["builtin:swc-loader"].count, 1);
+
+    let folded = stats.to_folded();
+    assert!(
+      folded.contains("CompilationProcessAssetsHook::call;RealContentHashPlugin::process_assets ")
+    );
+  }
+}
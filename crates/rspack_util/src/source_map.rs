@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use rkyv::{rancor::Fallible, Archive, Deserialize, Place, Serialize};
 
 bitflags! {
   #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -15,6 +16,28 @@ impl Default for SourceMapKind {
   }
 }
 
+// SourceMapKind is archived as its bits, so modules carrying it can be persisted
+impl Archive for SourceMapKind {
+  type Archived = u8;
+  type Resolver = ();
+
+  fn resolve(&self, _resolver: Self::Resolver, out: Place<Self::Archived>) {
+    out.write(self.bits());
+  }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for SourceMapKind {
+  fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<SourceMapKind, D> for u8 {
+  fn deserialize(&self, _deserializer: &mut D) -> Result<SourceMapKind, D::Error> {
+    Ok(SourceMapKind::from_bits_retain(*self))
+  }
+}
+
 impl SourceMapKind {
   pub fn enabled(&self) -> bool {
     !self.is_empty()
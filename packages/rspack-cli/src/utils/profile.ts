@@ -8,8 +8,8 @@ The full syntax, remember update this when you change something in this file.
 																																																				 ^: | is a delimiter for different profile options
 																																																					^------------------------------: querystring syntax stats.logging options
 											 ^-----------: trace filter, default to `trace`, more syntax: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html#example-syntax
-																		^--------------------: trace output, `stderr`, `stdout`, or a file path, default to `./.rspack-profile-${timestamp}/trace.json` for layer `chrome` and default to `stdout` for layer `logger`
-																													^-----------: trace layer, `chrome` or `logger`, default to `chrome`
+																		^--------------------: trace output, `stderr`, `stdout`, or a file path, default to `./.rspack-profile-${timestamp}/trace.json` for layer `chrome`, `./.rspack-profile-${timestamp}/profile.json` for layer `profile` and default to `stdout` for layer `logger`
+																													^-----------: trace layer, `chrome`, `logger` or `profile`, default to `chrome`
 																																						 ^---------------------------: js cpuprofile output, `stderr`, `stdout`, or a file path, default to `./.rspack-profile-${timestamp}/jscpuprofile.json`
 																																																									^----------------------: stats.logging output, default to `./.rspack-profile-${timestamp}/logging.json`
 
@@ -17,6 +17,8 @@ The full syntax, remember update this when you change something in this file.
 
 `RSPACK_PROFILE=TRACE rspack build`: only enable trace, and use default options for trace
 
+`RSPACK_PROFILE='TRACE=layer=profile' rspack build`: only enable trace, and aggregate the time spent per hook, plugin, loader and module into `profile.json` and `profile.folded`
+
 `RSPACK_PROFILE='JSCPU=output=./rspack.jscpuprofile' rspack build`: only enable js cpuprofile
 
 `RSPACK_PROFILE=JSCPU rspack build`: only enable js cpuprofile, and use default options for js cpuprofile
@@ -75,6 +77,10 @@ const defaultRustTraceChromeOutput = path.join(
 	defaultOutputDirname,
 	"./trace.json"
 );
+const defaultRustTraceProfileOutput = path.join(
+	defaultOutputDirname,
+	"./profile.json"
+);
 const defaultRustTraceLoggerOutput = "stdout";
 const defaultRustTraceFilter = "trace";
 const defaultRustTraceLayer = "chrome";
@@ -133,10 +139,17 @@ function resolveRustTraceOptions(value: string): RustTraceOptions {
 		const output =
 			layer === "chrome"
 				? parsed.get("output") || defaultRustTraceChromeOutput
-				: parsed.get("output") || defaultRustTraceLoggerOutput;
-		if (layer !== "chrome" && layer !== "logger" && layer !== "console") {
+				: layer === "profile"
+					? parsed.get("output") || defaultRustTraceProfileOutput
+					: parsed.get("output") || defaultRustTraceLoggerOutput;
+		if (
+			layer !== "chrome" &&
+			layer !== "logger" &&
+			layer !== "console" &&
+			layer !== "profile"
+		) {
 			throw new Error(
-				`${layer} is not a valid layer, should be chrome or logger`
+				`${layer} is not a valid layer, should be chrome, logger or profile`
 			);
 		}
 		return {
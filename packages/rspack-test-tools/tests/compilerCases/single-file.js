@@ -45,6 +45,8 @@ module.exports = {
 		 4 │     return "This is an empty dependency";
 		 5 │ };
 		   ╰────
+
+		  RSPACK_E0007: See https://rspack.dev/config/other-options#rspack_e0007
 	`);
 	}
 };
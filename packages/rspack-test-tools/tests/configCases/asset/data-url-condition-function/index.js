@@ -0,0 +1,11 @@
+import INLINE from "./inline.png";
+import RESOURCE from "./resource.png";
+
+it("should inline the asset when `dataUrlCondition` returns true", () => {
+	expect(INLINE.startsWith("data:image/png;base64,")).toBeTruthy();
+});
+
+it("should emit the asset when `dataUrlCondition` returns false", () => {
+	expect(RESOURCE.endsWith(".png")).toBeTruthy();
+	expect(RESOURCE.startsWith("data:")).toBeFalsy();
+});
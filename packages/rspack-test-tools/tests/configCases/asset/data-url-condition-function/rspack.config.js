@@ -0,0 +1,23 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.png$/,
+				type: "asset"
+			}
+		],
+		parser: {
+			asset: {
+				dataUrlCondition: (source, { filename, context }) => {
+					expect(source).toBeInstanceOf(Buffer);
+					expect(context).toBe(__dirname);
+					return path.basename(filename) === "inline.png";
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,22 @@
+const fs = require("fs");
+const path = require("path");
+
+const PAGES = 300;
+
+it("should render all pages with the native template engine", () => {
+	const native = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "./native.json"), "utf-8")
+	);
+	expect(native).toEqual({ plugins: PAGES, templateFunctions: 0, parameterFunctions: 0 });
+});
+
+it("should render the data of each page", () => {
+	for (let i = 0; i < PAGES; i++) {
+		const htmlContent = fs.readFileSync(path.join(__dirname, `./page${i}.html`), "utf-8");
+		expect(htmlContent).toContain(`<title>Page ${i} &amp; more</title>`);
+		expect(htmlContent).toContain(`<meta name="description" content="page ${i}">`);
+		expect(htmlContent).toContain(`<h1>Page ${i} &amp; more</h1>`);
+		expect(htmlContent).toContain(`<script src="page${i}.js"></script>`);
+		expect(htmlContent).toContain(`<ul><li>/page${(i + 1) % PAGES}</li><li>/page${(i + PAGES - 1) % PAGES}</li></ul>`);
+	}
+});
@@ -0,0 +1,58 @@
+const { rspack } = require("@rspack/core");
+
+const PAGES = 300;
+const pages = Array.from({ length: PAGES }, (_, i) => i);
+
+// records whether the html plugins render templates natively, instead of calling back into JavaScript
+class NativeTemplatePlugin {
+	apply(compiler) {
+		compiler.hooks.thisCompilation.tap("NativeTemplatePlugin", compilation => {
+			compilation.hooks.processAssets.tap("NativeTemplatePlugin", () => {
+				const plugins = compiler.__internal__builtinPlugins.filter(
+					plugin => plugin.name === "HtmlRspackPlugin"
+				);
+				const native = {
+					plugins: plugins.length,
+					templateFunctions: plugins.filter(plugin => plugin.options.templateFn)
+						.length,
+					parameterFunctions: plugins.filter(
+						plugin => typeof plugin.options.templateParameters === "function"
+					).length
+				};
+				compilation.emitAsset(
+					"native.json",
+					new rspack.sources.RawSource(JSON.stringify(native))
+				);
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		...Object.fromEntries(pages.map(i => [`page${i}`, "./page.js"]))
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		...pages.map(
+			i =>
+				new rspack.HtmlRspackPlugin({
+					filename: `page${i}.html`,
+					template: "./page.ejs",
+					chunks: [`page${i}`],
+					minify: false,
+					inject: false,
+					templateParameters: {
+						index: i,
+						title: `Page ${i} & more`,
+						links: [`/page${(i + 1) % PAGES}`, `/page${(i + PAGES - 1) % PAGES}`]
+					}
+				})
+		),
+		new NativeTemplatePlugin()
+	]
+};
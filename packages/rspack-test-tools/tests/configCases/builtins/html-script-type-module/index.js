@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+it("default-index.html should inject module scripts", () => {
+	const htmlPath = path.join(__dirname, "./default-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes('<script type="module" src="bundle0.js"></script>')
+	).toBe(true);
+});
+
+it("blocking-index.html should keep the explicit script loading", () => {
+	const htmlPath = path.join(__dirname, "./blocking-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent.includes('<script src="bundle1.js"></script>')).toBe(true);
+});
+
+it("defer-index.html should keep the explicit script loading", () => {
+	const htmlPath = path.join(__dirname, "./defer-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes('<script defer src="bundle2.js"></script>')
+	).toBe(true);
+});
@@ -0,0 +1,37 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		output: {
+			scriptType: "module"
+		},
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "default-index.html"
+			})
+		]
+	},
+	{
+		output: {
+			scriptType: "module"
+		},
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "blocking-index.html",
+				scriptLoading: "blocking"
+			})
+		]
+	},
+	{
+		output: {
+			scriptType: "module"
+		},
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "defer-index.html",
+				scriptLoading: "defer"
+			})
+		]
+	}
+];
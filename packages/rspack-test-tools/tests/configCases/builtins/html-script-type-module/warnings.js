@@ -0,0 +1,4 @@
+module.exports = [
+	[/`scriptLoading: 'blocking'` conflicts with `output.scriptType: 'module'`/],
+	[/`scriptLoading: 'defer'` conflicts with `output.scriptType: 'module'`/]
+];
@@ -0,0 +1,11 @@
+module.exports = [
+	[/failed to render template from string: ReferenceError: title is not defined/],
+	[
+		/failed to render template from string: TypeError: Cannot read properties of null \(reading 'title'\)/
+	],
+	[/failed to render template from string: Error: could not include "\.\/missing"/],
+	[
+		/failed to render template from string: RangeError: Maximum number of 10000000 steps exceeded, check for endless loops/
+	],
+	[/failed to render template from string: SyntaxError: Missing "}" before the end of template/]
+];
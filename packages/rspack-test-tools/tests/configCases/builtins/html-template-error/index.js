@@ -0,0 +1 @@
+it("should compile", () => {});
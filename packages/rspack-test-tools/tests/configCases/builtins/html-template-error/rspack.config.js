@@ -0,0 +1,21 @@
+const { rspack } = require("@rspack/core");
+
+const templates = {
+	"reference.html": "<title><%= title %></title>",
+	"null.html": "<title><%= options.title %></title>",
+	"include.html": "<%- include('./missing') %>",
+	"loop.html": "<% while (true) {} %>",
+	"syntax.html": "<% if (true) { %>unclosed"
+};
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: Object.entries(templates).map(
+		([filename, templateContent]) =>
+			new rspack.HtmlRspackPlugin({
+				filename,
+				templateContent,
+				templateParameters: { options: null }
+			})
+	)
+};
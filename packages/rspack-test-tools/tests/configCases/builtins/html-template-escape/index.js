@@ -0,0 +1,30 @@
+const fs = require("fs");
+const path = require("path");
+
+const htmlContent = fs.readFileSync(path.join(__dirname, "./index.html"), "utf-8");
+
+it("should escape the output of <%- like lodash.template", () => {
+	expect(htmlContent).toContain(
+		"<title>&lt;i&gt;rspack&lt;/i&gt; &amp; friends</title>"
+	);
+	expect(htmlContent).toContain(
+		`<p id="escaped">&lt;b&gt;bold&lt;/b&gt;</p>`
+	);
+	expect(htmlContent).toContain(
+		`<p id="quotes">&quot;double&quot; &amp; &#39;single&#39;</p>`
+	);
+	expect(htmlContent).toContain(`<p id="number">2</p>`);
+});
+
+it("should interpolate the output of <%= as is", () => {
+	expect(htmlContent).toContain(`<p id="raw"><b>bold</b></p>`);
+});
+
+it("should render nullish values as empty strings", () => {
+	expect(htmlContent).toContain(`<p id="nullish">[][]</p>`);
+});
+
+it("should render literal tags and drop comments", () => {
+	expect(htmlContent).toContain(`<p id="literal"><%= not interpolated %></p>`);
+	expect(htmlContent).not.toContain("a comment is not rendered");
+});
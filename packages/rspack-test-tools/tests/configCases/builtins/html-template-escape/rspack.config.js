@@ -0,0 +1,17 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			minify: false,
+			inject: false,
+			template: "./index.ejs",
+			title: "<i>rspack</i> & friends",
+			templateParameters: {
+				content: "<b>bold</b>",
+				nothing: null
+			}
+		})
+	]
+};
@@ -0,0 +1,23 @@
+require("./main.css");
+
+const fs = require("fs");
+const path = require("path");
+
+const htmlContent = fs.readFileSync(path.join(__dirname, "./index.html"), "utf-8");
+
+it("should iterate htmlRspackPlugin.files.css with for...in", () => {
+	expect(htmlContent).toContain(
+		`<link href="/static/main.css" rel="stylesheet"><link href="/static/other.css" rel="stylesheet">`
+	);
+});
+
+it("should iterate htmlRspackPlugin.files.js with for...of", () => {
+	expect(htmlContent).toContain(
+		`<script src="/static/main.js"></script><script src="/static/other.js"></script>`
+	);
+});
+
+it("should read the properties of htmlRspackPlugin.files", () => {
+	expect(htmlContent).toContain(`<p id="count">2 js, 2 css</p>`);
+	expect(htmlContent).toContain(`<p id="relative">main.js other.js</p>`);
+});
@@ -0,0 +1 @@
+require("./other.css");
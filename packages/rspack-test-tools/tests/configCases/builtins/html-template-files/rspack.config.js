@@ -0,0 +1,34 @@
+const { rspack } = require("@rspack/core");
+const { CssExtractRspackPlugin, HtmlRspackPlugin } = rspack;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js",
+		publicPath: "/static/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				use: [CssExtractRspackPlugin.loader, "css-loader"]
+			}
+		]
+	},
+	experiments: {
+		css: false
+	},
+	plugins: [
+		new CssExtractRspackPlugin(),
+		new HtmlRspackPlugin({
+			minify: false,
+			inject: false,
+			template: "./index.ejs",
+			chunks: ["main", "other"]
+		})
+	]
+};
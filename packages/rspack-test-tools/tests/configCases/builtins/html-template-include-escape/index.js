@@ -0,0 +1,25 @@
+const fs = require("fs");
+const path = require("path");
+
+const htmlContent = fs.readFileSync(path.join(__dirname, "./index.html"), "utf-8");
+
+it("should pass the data of the including template to the included one", () => {
+	expect(htmlContent).toContain("<title>&lt;Title&gt;</title>");
+});
+
+it("should not escape the output of included templates again", () => {
+	expect(htmlContent).toContain(
+		`<div id="escaped-include"><span>&lt;b&gt;item&lt;/b&gt;</span></div>`
+	);
+	expect(htmlContent).toContain(`<div id="raw-include"><span>raw</span></div>`);
+});
+
+it("should resolve nested includes from the directory of the included template", () => {
+	expect(htmlContent).toContain(
+		`<div id="nested-include"><ul><li><span>a &amp; b</span></li><li><span>&lt;c&gt;</span></li></ul></div>`
+	);
+});
+
+it("should not leak the data of the include into the including template", () => {
+	expect(htmlContent).toContain(`<div id="outer-data">&lt;outer&gt;</div>`);
+});
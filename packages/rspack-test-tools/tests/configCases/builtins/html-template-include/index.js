@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+const htmlPath = path.join(__dirname, "./index.html");
+const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+
+it("should render included templates", () => {
+	expect(htmlContent).toContain("<title>i am title</title>");
+	expect(htmlContent).toContain(`<meta name="description" content="included">`);
+});
+
+it("should render loops with escaped output", () => {
+	expect(htmlContent).toContain(
+		`<li data-index="0"><a href="/home">Home</a></li>`
+	);
+	expect(htmlContent).toContain(
+		`<li data-index="1"><a href="/about">&lt;About&gt;</a></li>`
+	);
+});
+
+it("should render conditionals and expressions", () => {
+	expect(htmlContent).toContain("<p>links: /home,/about</p>");
+	expect(htmlContent).toContain(`<script src="http://cdn.com/bundle0.js"></script>`);
+});
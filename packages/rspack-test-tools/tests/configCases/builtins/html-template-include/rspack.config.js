@@ -0,0 +1,22 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		publicPath: "http://cdn.com/"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			minify: false,
+			template: "./index.ejs?foo=bar",
+			title: "i am title",
+			inject: false,
+			templateParameters: {
+				links: [
+					{ href: "/home", text: "Home" },
+					{ href: "/about", text: "<About>" }
+				]
+			}
+		})
+	]
+};
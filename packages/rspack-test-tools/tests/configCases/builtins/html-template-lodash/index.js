@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should render the same html as lodash.template", () => {
+	const native = fs.readFileSync(path.join(__dirname, "./native.html"), "utf-8");
+	const lodash = fs.readFileSync(path.join(__dirname, "./lodash.html"), "utf-8");
+	expect(native).toBe(lodash);
+	expect(native).toContain(
+		`<p id="string">&lt;a href=&quot;/&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;|<a href="/">Tom & 'Jerry'</a>|`
+	);
+	expect(native).toContain(`<p id="array">&lt;,null,3|<,,3|1,2,3|1,2,3</p>`);
+});
@@ -0,0 +1,60 @@
+const fs = require("fs");
+const path = require("path");
+const _ = require("lodash");
+const { rspack } = require("@rspack/core");
+
+const templateParameters = {
+	title: "<lodash> & rspack",
+	html: `<a href="/">Tom & 'Jerry'</a>`,
+	count: 1.5,
+	enabled: false,
+	nothing: null,
+	list: ["<", null, 3],
+	nested: [[1, 2], [3]],
+	dict: { text: "<dict>" }
+};
+
+class LodashTemplatePlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("LodashTemplatePlugin", compilation => {
+			let html = "";
+			rspack.HtmlRspackPlugin.getCompilationHooks(
+				compilation
+			).afterTemplateExecution.tap("LodashTemplatePlugin", data => {
+				html = data.html;
+				return data;
+			});
+			compilation.hooks.processAssets.tap(
+				{
+					name: "LodashTemplatePlugin",
+					stage: rspack.Compilation.PROCESS_ASSETS_STAGE_REPORT
+				},
+				() => {
+					// html-webpack-plugin renders templates by lodash.template by default
+					const template = fs.readFileSync(
+						path.join(__dirname, "index.ejs"),
+						"utf-8"
+					);
+					compilation.emitAsset(
+						"lodash.html",
+						new rspack.sources.RawSource(_.template(template)(templateParameters))
+					);
+					compilation.emitAsset("native.html", new rspack.sources.RawSource(html));
+				}
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			minify: false,
+			inject: false,
+			template: "./index.ejs",
+			templateParameters
+		}),
+		new LodashTemplatePlugin()
+	]
+};
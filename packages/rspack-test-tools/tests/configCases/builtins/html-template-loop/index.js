@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+const htmlContent = fs.readFileSync(path.join(__dirname, "./index.html"), "utf-8");
+
+it("should iterate the files with forEach", () => {
+	expect(htmlContent).toContain(
+		`<ul id="for-each">\n    <li data-index="0">main.js</li>\n    <li data-index="1">other.js</li>\n  </ul>`
+	);
+});
+
+it("should support for...of, for...in and while loops", () => {
+	expect(htmlContent).toContain(`<p id="for-of">[a][&lt;b&gt;]</p>`);
+	expect(htmlContent).toContain(`<p id="for-in">x=1;y=two;</p>`);
+	expect(htmlContent).toContain(`<p id="while">13</p>`);
+});
+
+it("should support nested loops with break", () => {
+	expect(htmlContent).toContain(`<p id="nested">00 10 11 20 21 22 </p>`);
+});
+
+it("should support array methods with arrow functions", () => {
+	expect(htmlContent).toContain(`<p id="map">0:a,1:<b></p>`);
+});
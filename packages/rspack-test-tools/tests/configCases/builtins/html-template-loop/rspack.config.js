@@ -0,0 +1,24 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			minify: false,
+			inject: false,
+			template: "./index.ejs",
+			chunks: ["main", "other"],
+			templateParameters: {
+				items: ["a", "<b>"],
+				dict: { x: 1, y: "two" }
+			}
+		})
+	]
+};
@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = filename =>
+	fs.readFileSync(path.join(__dirname, filename), "utf-8");
+
+it("should pass the pairs of the query to the template", () => {
+	const htmlContent = read("pairs.html");
+	expect(htmlContent).toContain("<title>Hello World!</title>");
+	expect(htmlContent).toContain("<ul><li>a</li><li>&lt;b&gt;</li></ul>");
+	expect(htmlContent).toContain(`<p id="flags">true,false</p>`);
+});
+
+it("should pass the JSON object of the query to the template", () => {
+	const htmlContent = read("json.html");
+	expect(htmlContent).toContain("<title>json</title>");
+	expect(htmlContent).toContain("<ul><li>1</li><li>2</li></ul>");
+	expect(htmlContent).toContain(`<p id="flags">false,true</p>`);
+});
+
+it("should override the query by template parameters", () => {
+	expect(read("pairs.html")).toContain(`<p id="overridden">parameters</p>`);
+	expect(read("json.html")).toContain(`<p id="overridden">parameters</p>`);
+});
@@ -0,0 +1,34 @@
+const { rspack } = require("@rspack/core");
+
+const json = JSON.stringify({
+	title: "json",
+	tags: [1, 2],
+	dark: false,
+	debug: true,
+	overridden: "query"
+});
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			filename: "pairs.html",
+			minify: false,
+			inject: false,
+			template:
+				"./index.ejs?title=Hello+World%21&tags[]=a&tags[]=%3Cb%3E&dark&-debug&overridden=query",
+			templateParameters: {
+				overridden: "parameters"
+			}
+		}),
+		new rspack.HtmlRspackPlugin({
+			filename: "json.html",
+			minify: false,
+			inject: false,
+			template: `./index.ejs?${json}`,
+			templateParameters: {
+				overridden: "parameters"
+			}
+		})
+	]
+};
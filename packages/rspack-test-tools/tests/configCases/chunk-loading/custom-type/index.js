@@ -0,0 +1,6 @@
+it("should load chunks with the custom chunk loading type", function () {
+	return import("./async").then(function (m) {
+		expect(m.default).toBe("async");
+		expect(__webpack_require__.customLoadedChunks).toHaveLength(1);
+	});
+});
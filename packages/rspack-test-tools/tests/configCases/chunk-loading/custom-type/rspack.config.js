@@ -0,0 +1,57 @@
+const { RuntimeModule, RuntimeGlobals, javascript } = require("@rspack/core");
+
+class CustomChunkLoadingRuntimeModule extends RuntimeModule {
+	constructor() {
+		super("custom chunk loading", RuntimeModule.STAGE_ATTACH);
+	}
+
+	generate() {
+		return `
+var installedChunks = {};
+${RuntimeGlobals.ensureChunkHandlers}.customRequire = function (chunkId) {
+	if (installedChunks[chunkId]) return;
+	var chunk = require("./" + ${RuntimeGlobals.getChunkScriptFilename}(chunkId));
+	var moreModules = chunk.modules;
+	for (var moduleId in moreModules) {
+		if (${RuntimeGlobals.hasOwnProperty}(moreModules, moduleId)) {
+			${RuntimeGlobals.moduleFactories}[moduleId] = moreModules[moduleId];
+		}
+	}
+	if (chunk.runtime) chunk.runtime(__webpack_require__);
+	for (var i = 0; i < chunk.ids.length; i++) {
+		installedChunks[chunk.ids[i]] = 1;
+		__webpack_require__.customLoadedChunks.push(chunk.ids[i]);
+	}
+};
+__webpack_require__.customLoadedChunks = [];
+`;
+	}
+}
+
+class CustomChunkLoadingPlugin {
+	apply(compiler) {
+		javascript.EnableChunkLoadingPlugin.setEnabled(compiler, "custom-require");
+		compiler.hooks.thisCompilation.tap("CustomChunkLoadingPlugin", compilation => {
+			compilation.hooks.runtimeRequirementInTree
+				.for(RuntimeGlobals.ensureChunkHandlers)
+				.tap("CustomChunkLoadingPlugin", (chunk, set) => {
+					set.add(RuntimeGlobals.getChunkScriptFilename);
+					set.add(RuntimeGlobals.hasOwnProperty);
+					set.add(RuntimeGlobals.moduleFactoriesAddOnly);
+					compilation.addRuntimeModule(
+						chunk,
+						new CustomChunkLoadingRuntimeModule()
+					);
+				});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkLoading: "custom-require"
+	},
+	plugins: [new CustomChunkLoadingPlugin()]
+};
@@ -0,0 +1,12 @@
+it("should load the chunks of nested blocks", function (done) {
+	require.ensure(
+		[],
+		function () {
+			import(/* webpackChunkName: "inner" */ "./inner").then(function (m) {
+				expect(m.default).toBe("inner");
+				done();
+			}, done);
+		},
+		"outer"
+	);
+});
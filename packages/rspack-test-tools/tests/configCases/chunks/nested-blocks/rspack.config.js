@@ -0,0 +1,32 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkFilename: "[name].js"
+	},
+	plugins: [
+		compiler => {
+			compiler.hooks.thisCompilation.tap("NestedBlocksPlugin", compilation => {
+				compilation.hooks.afterSeal.tap("NestedBlocksPlugin", () => {
+					const inner = compilation.namedChunkGroups.get("inner");
+					const outer = compilation.namedChunkGroups.get("outer");
+					expect(inner.getParents().map(group => group.name)).toEqual([
+						"outer"
+					]);
+					expect(outer.getParents().map(group => group.name)).toEqual([
+						"main"
+					]);
+					expect(inner.getFiles()).toEqual(["inner.js"]);
+					expect(outer.getFiles()).toEqual(["outer.js"]);
+				});
+			});
+			compiler.hooks.done.tap("NestedBlocksPlugin", stats => {
+				const outputPath = stats.compilation.outputOptions.path;
+				expect(fs.existsSync(path.join(outputPath, "inner.js"))).toBe(true);
+			});
+		}
+	]
+};
@@ -0,0 +1,20 @@
+import fs from "fs";
+import * as ns from "./data.json";
+import { name, list, nested } from "./data.json";
+
+it("should concatenate json modules with mangled and pruned exports", async () => {
+	const content = await fs.promises.readFile(__filename, "utf-8");
+	expect(content).not.toContain(["var", "__webpack_modules__"].join(" "));
+	expect(content).not.toContain(["top level", "unused value"].join(" "));
+	expect(content).not.toContain(["nested", "unused value"].join(" "));
+	// the nested property is accessed by the mangled name
+	expect(content).not.toContain(['{"used"', "true}"].join(":"));
+
+	expect(name).toBe("rspack");
+	expect(list).toEqual([1, 2, 3]);
+	expect(nested.used).toBe(true);
+	expect(ns.list).toBe(list);
+	expect(ns.nested.used).toBe(true);
+	expect(ns["not-identifier"]).toBe("dash");
+	expect(ns.class).toBe("reserved");
+});
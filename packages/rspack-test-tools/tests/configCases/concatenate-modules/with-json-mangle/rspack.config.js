@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	entry: {
+		main: "./index.js"
+	},
+	externalsPresets: {
+		node: true
+	},
+	output: {
+		environment: {
+			const: true
+		}
+	},
+	optimization: {
+		concatenateModules: true,
+		usedExports: true,
+		mangleExports: "deterministic",
+		minimize: false
+	}
+};
@@ -0,0 +1,16 @@
+import fs from "fs";
+import data, { name, list } from "./data.json";
+
+it("should concatenate json modules as top level bindings", async () => {
+	const content = await fs.promises.readFile(__filename, "utf-8");
+	expect(content).not.toContain(["var", "__webpack_modules__"].join(" "));
+	expect(content).toContain(["const", "name", "="].join(" "));
+	expect(content).not.toContain(["top level", "unused value"].join(" "));
+	expect(content).not.toContain(["nested", "unused value"].join(" "));
+
+	expect(name).toBe("rspack");
+	expect(list).toEqual([1, 2, 3]);
+	expect(data.list).toBe(list);
+	expect(data.nested.used).toBe(true);
+	expect(data["not-identifier"]).toBe("dash");
+});
@@ -0,0 +1,20 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	entry: {
+		main: "./index.js"
+	},
+	externalsPresets: {
+		node: true
+	},
+	output: {
+		environment: {
+			const: true
+		}
+	},
+	optimization: {
+		concatenateModules: true,
+		mangleExports: false,
+		minimize: false
+	}
+};
@@ -0,0 +1,24 @@
+it("should register shared modules into every share scope", async () => {
+	await __webpack_init_sharing__(["legacy", "next"]);
+	expect(Object.keys(__webpack_share_scopes__.legacy["shared-lib"])).toEqual([
+		"1.0.0"
+	]);
+	expect(Object.keys(__webpack_share_scopes__.next["shared-lib"])).toEqual([
+		"1.0.0"
+	]);
+});
+
+it("should consume shared modules from the first share scope providing them", async () => {
+	// a higher version in the second share scope is not used, as the first one provides the module
+	__webpack_share_scopes__.next["shared-lib"]["1.1.0"] = {
+		get: () => () => "shared-lib 1.1.0",
+		from: "next"
+	};
+	const { default: lib } = await import("shared-lib");
+	expect(lib).toBe("shared-lib 1.0.0");
+});
+
+it("should initialize the container with every share scope", async () => {
+	const { default: Component } = await import("self/Component");
+	expect(Component()).toBe("Component rendered with [shared-lib 1.0.0]");
+});
@@ -0,0 +1,24 @@
+const { ModuleFederationPluginV1: ModuleFederationPlugin } =
+	require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		uniqueName: "multiple-share-scopes"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			exposes: {
+				"./Component": "./Component"
+			},
+			remotes: {
+				self: "./container.js"
+			},
+			shared: ["shared-lib"],
+			shareScope: ["legacy", "next"]
+		})
+	]
+};
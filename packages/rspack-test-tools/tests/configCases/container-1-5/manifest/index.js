@@ -0,0 +1,33 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should emit the manifest and the stats", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "mf-manifest.json"), "utf-8")
+	);
+	expect(manifest.name).toBe("container");
+	expect(manifest.metaData.remoteEntry.name).toBe("container.js");
+	expect(manifest.metaData.remoteEntry.type).toBe("commonjs-module");
+
+	expect(manifest.exposes).toHaveLength(1);
+	const [button] = manifest.exposes;
+	expect(button.id).toBe("container:Button");
+	expect(button.path).toBe("./Button");
+	expect(button.assets.js.sync.length).toBeGreaterThan(0);
+	for (const file of button.assets.js.sync) {
+		expect(fs.existsSync(path.resolve(__dirname, file))).toBe(true);
+	}
+	expect(button.file).toBeUndefined();
+
+	const react = manifest.shared.find(shared => shared.name === "react");
+	expect(react.version).toBe("0.1.2");
+	expect(react.requiredVersion).toBe("^0.1.0");
+	expect(react.singleton).toBe(true);
+	expect(react.assets.js.async.length).toBeGreaterThan(0);
+
+	const stats = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "mf-stats.json"), "utf-8")
+	);
+	expect(stats.exposes[0].file).toEqual(["./Button"]);
+	expect(stats.shared[0].usedIn).toContain("./Button.js");
+});
@@ -0,0 +1,27 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: { type: "commonjs-module" },
+			exposes: {
+				"./Button": "./Button"
+			},
+			shared: {
+				react: {
+					version: "0.1.2",
+					requiredVersion: "^0.1.0",
+					singleton: true
+				}
+			},
+			manifest: true
+		})
+	]
+};
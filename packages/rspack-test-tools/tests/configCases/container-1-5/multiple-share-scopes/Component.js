@@ -0,0 +1,3 @@
+import lib from "shared-lib";
+
+export default () => `Component rendered with [${lib}]`;
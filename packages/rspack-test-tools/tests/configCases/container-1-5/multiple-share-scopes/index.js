@@ -0,0 +1,43 @@
+if (globalThis.__FEDERATION__) {
+	globalThis.__GLOBAL_LOADING_REMOTE_ENTRY__ = {};
+	//@ts-ignore
+	globalThis.__FEDERATION__.__INSTANCES__.map(i => {
+		i.moduleCache.clear();
+		if (globalThis[i.name]) {
+			delete globalThis[i.name];
+		}
+	});
+	globalThis.__FEDERATION__.__INSTANCES__ = [];
+}
+
+it("should pass every share scope to the federation runtime", () => {
+	const shared = __webpack_require__.federation.initOptions.shared["shared-lib"];
+	expect(shared.map(options => options.scope)).toEqual([["legacy"], ["next"]]);
+	const handlers = Object.values(
+		__webpack_require__.federation.consumesLoadingModuleToHandlerMapping
+	);
+	expect(handlers.length).toBeGreaterThan(0);
+	for (const handler of handlers) {
+		expect(handler.shareInfo.scope).toEqual(["legacy", "next"]);
+	}
+});
+
+it("should register shared modules into every share scope", async () => {
+	await __webpack_init_sharing__(["legacy", "next"]);
+	expect(Object.keys(__webpack_share_scopes__.legacy["shared-lib"])).toEqual([
+		"1.0.0"
+	]);
+	expect(Object.keys(__webpack_share_scopes__.next["shared-lib"])).toEqual([
+		"1.0.0"
+	]);
+});
+
+it("should consume shared modules from the share scopes", async () => {
+	const { default: lib } = await import("shared-lib");
+	expect(lib).toBe("shared-lib 1.0.0");
+});
+
+it("should initialize the container with every share scope", async () => {
+	const { default: Component } = await import("self/Component");
+	expect(Component()).toBe("Component rendered with [shared-lib 1.0.0]");
+});
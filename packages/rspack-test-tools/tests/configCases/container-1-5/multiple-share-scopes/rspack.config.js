@@ -0,0 +1,23 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		uniqueName: "multiple-share-scopes-enhanced"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			exposes: {
+				"./Component": "./Component"
+			},
+			remotes: {
+				self: "./container.js"
+			},
+			shared: ["shared-lib"],
+			shareScope: ["legacy", "next"]
+		})
+	]
+};
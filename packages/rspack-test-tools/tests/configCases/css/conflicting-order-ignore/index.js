@@ -0,0 +1 @@
+it("should not warn about the conflicting order of css modules with ignoreOrder", () => {});
@@ -0,0 +1,38 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		ab: "./ab.js",
+		ba: "./ba.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	target: "web",
+	module: {
+		rules: [
+			{
+				test: /a\.css$/,
+				type: "css/auto",
+				parser: {
+					ignoreOrder: true
+				}
+			}
+		]
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					chunks: "all",
+					test: /\.css$/,
+					enforce: true
+				}
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1 @@
+it("should warn about the conflicting order of css modules", () => {});
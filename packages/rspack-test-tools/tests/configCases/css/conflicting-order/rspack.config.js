@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		ab: "./ab.js",
+		ba: "./ba.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	target: "web",
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					chunks: "all",
+					test: /\.css$/,
+					enforce: true
+				}
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle() {
+		return ["main.js"];
+	}
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Conflicting order between .*[ab]\.css and .*[ab]\.css:\n \* .* is imported before .* in chunk group\(s\) (ab|ba)\n \* .* is imported before .* in chunk group\(s\) (ab|ba)\n/
+	]
+];
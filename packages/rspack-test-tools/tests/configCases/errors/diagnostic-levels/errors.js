@@ -0,0 +1 @@
+module.exports = [[/Conflicting order between .*[ab]\.css and .*[ab]\.css/]];
@@ -0,0 +1 @@
+it("should promote the conflicting order warning to an error", () => {});
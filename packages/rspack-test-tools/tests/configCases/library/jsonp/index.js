@@ -0,0 +1,13 @@
+export const value = 42;
+
+it("should wrap the entry in the jsonp callback", function () {
+	var fs = require("fs");
+	var source = fs.readFileSync(__filename, "utf-8");
+
+	expect(source).toMatch("jsonpCallback((");
+	expect(source.trim().endsWith(")")).toBe(true);
+});
+
+it("should pass the exports to the jsonp callback", function () {
+	expect(globalThis.__JSONP_EXPORTS__.value).toBe(42);
+});
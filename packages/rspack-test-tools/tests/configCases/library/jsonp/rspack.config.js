@@ -0,0 +1,17 @@
+const rspack = require("@rspack/core");
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		library: {
+			name: "jsonpCallback",
+			type: "jsonp"
+		}
+	},
+	plugins: [
+		new rspack.BannerPlugin({
+			raw: true,
+			banner:
+				"function jsonpCallback(exports) { globalThis.__JSONP_EXPORTS__ = exports; }\n"
+		})
+	]
+};
@@ -11,4 +11,6 @@ it("script type should be module", async function () {
 	expect(content).toContain(
 		'if (typeof import.meta.url === "string") scriptUrl = import.meta.url'
 	);
+	// the auto public path of module scripts doesn't read the global object
+	expect(content).not.toContain("__webpack_require__.g = ");
 });
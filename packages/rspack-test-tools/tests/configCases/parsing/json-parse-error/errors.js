@@ -0,0 +1 @@
+module.exports = [/Toml parsing error: invalid string/];
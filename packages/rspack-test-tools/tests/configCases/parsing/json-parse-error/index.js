@@ -0,0 +1,10 @@
+it("should report the location of a toml parsing error", () => {
+	let errored = false;
+	try {
+		require("./data.toml");
+	} catch (err) {
+		errored = true;
+		expect(err.message).toContain("Toml parsing error: invalid string");
+	}
+	expect(errored).toBe(true);
+});
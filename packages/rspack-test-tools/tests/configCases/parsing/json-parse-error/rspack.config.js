@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.toml$/,
+				type: "json",
+				parser: {
+					parse: "toml"
+				}
+			}
+		]
+	}
+};
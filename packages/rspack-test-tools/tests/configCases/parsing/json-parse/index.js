@@ -0,0 +1,28 @@
+import yaml, { name as yamlName } from "./data.yaml";
+import toml, { name as tomlName } from "./data.toml";
+import json5 from "./data.json5";
+import txt from "./data.txt";
+
+const expected = {
+	name: "rspack",
+	list: [1, 2],
+	nested: { value: true }
+};
+
+it("should parse yaml with the builtin parser", () => {
+	expect(yaml).toEqual(expected);
+	expect(yamlName).toBe("rspack");
+});
+
+it("should parse toml with the builtin parser", () => {
+	expect(toml).toEqual(expected);
+	expect(tomlName).toBe("rspack");
+});
+
+it("should parse json5 with the builtin parser", () => {
+	expect(json5).toEqual(expected);
+});
+
+it("should parse with the custom parse function", () => {
+	expect(txt).toEqual({ name: "rspack", bundler: "true" });
+});
@@ -0,0 +1,41 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.ya?ml$/,
+				type: "json",
+				parser: {
+					parse: "yaml"
+				}
+			},
+			{
+				test: /\.toml$/,
+				type: "json",
+				parser: {
+					parse: "toml"
+				}
+			},
+			{
+				test: /\.json5$/,
+				type: "json",
+				parser: {
+					parse: "json5"
+				}
+			},
+			{
+				test: /\.txt$/,
+				type: "json",
+				parser: {
+					parse: source =>
+						Object.fromEntries(
+							source
+								.trim()
+								.split("\n")
+								.map(line => line.split("="))
+						)
+				}
+			}
+		]
+	}
+};
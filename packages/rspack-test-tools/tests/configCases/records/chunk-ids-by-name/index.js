@@ -0,0 +1,9 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should revive the chunk id from the records", function () {
+	return import(/* webpackChunkName: "async" */ "./async").then(function (m) {
+		expect(m.default).toBe("async");
+		expect(fs.existsSync(path.join(__dirname, "chunk-100.js"))).toBe(true);
+	});
+});
@@ -0,0 +1,13 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkFilename: "chunk-[id].js"
+	},
+	optimization: {
+		chunkIds: "natural"
+	},
+	recordsInputPath: path.resolve(__dirname, "records.json")
+};
@@ -0,0 +1,4 @@
+it("should revive the module ids from the records written by webpack", function () {
+	expect(String(require("./a"))).toBe("42");
+	expect(String(require("./b"))).toBe("b-module");
+});
@@ -0,0 +1,10 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	optimization: {
+		moduleIds: "natural"
+	},
+	recordsInputPath: path.resolve(__dirname, "records.json")
+};
@@ -0,0 +1,10 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should keep the revived chunk id when chunkIds is named", function () {
+	return import(/* webpackChunkName: "async" */ "./async").then(function (m) {
+		expect(m.default).toBe("async");
+		expect(fs.existsSync(path.join(__dirname, "chunk-7.js"))).toBe(true);
+		expect(fs.existsSync(path.join(__dirname, "chunk-async.js"))).toBe(false);
+	});
+});
@@ -0,0 +1,5 @@
+import a from "vfs:/lib/a.js";
+
+it("should resolve relative requests within a custom scheme", () => {
+	expect(a).toBe("bc");
+});
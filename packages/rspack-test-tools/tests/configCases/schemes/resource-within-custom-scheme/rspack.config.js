@@ -0,0 +1,22 @@
+const files = {
+	"vfs:/lib/a.js": 'export { default } from "./b.js";',
+	"vfs:/lib/b.js": 'import c from "../c.js";\nexport default "b" + c;',
+	"vfs:/c.js": 'export default "c";'
+};
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				scheme: /^vfs$/,
+				use: [
+					{
+						loader: "./vfs-loader.js",
+						options: { files }
+					}
+				]
+			}
+		]
+	}
+};
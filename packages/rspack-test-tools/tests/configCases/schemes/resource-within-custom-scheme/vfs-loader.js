@@ -0,0 +1,8 @@
+module.exports = function (content) {
+	return content;
+};
+
+module.exports.pitch = function () {
+	const { files } = this.getOptions();
+	return files[this.resource];
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	/Import "memory" from "\.\/memory\.js" with Memory can only be used for direct wasm to wasm dependencies/
+];
@@ -0,0 +1,7 @@
+function load() {
+	return import("./memory.wasm");
+}
+
+it("should report non-function imports from javascript modules", function () {
+	expect(typeof load).toBe("function");
+});
@@ -0,0 +1,3 @@
+export function getOffset() {
+	return 10;
+}
@@ -0,0 +1,13 @@
+it("should instantiate a sync webassembly module in an async chunk", function () {
+	return import("./math.wasm").then(function (wasm) {
+		expect(wasm.add(1, 2)).toBe(13);
+	});
+});
+
+it("should share the instance between imports", function () {
+	return Promise.all([import("./math.wasm"), import("./math.wasm")]).then(
+		function ([a, b]) {
+			expect(a).toBe(b);
+		}
+	);
+});
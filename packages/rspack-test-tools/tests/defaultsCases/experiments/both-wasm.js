@@ -13,6 +13,7 @@ module.exports = {
 		-     "asyncWebAssembly": false,
 		+     "asyncWebAssembly": true,
 		@@ ... @@
+		-     "syncWebAssembly": false,
 		+     "syncWebAssembly": true,
 		@@ ... @@
 		+       },
@@ -95,12 +95,15 @@ module.exports = {
 		+     },
 		@@ ... @@
 		+       "css": Object {
+		+         "ignoreOrder": false,
 		+         "namedExports": true,
 		+       },
 		+       "css/auto": Object {
+		+         "ignoreOrder": false,
 		+         "namedExports": true,
 		+       },
 		+       "css/module": Object {
+		+         "ignoreOrder": false,
 		+         "namedExports": true,
 		+       },
 		@@ ... @@
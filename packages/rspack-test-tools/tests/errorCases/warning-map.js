@@ -23,8 +23,9 @@ module.exports = {
 		expect(warnings).toMatchInlineSnapshot(`
 		Array [
 		  Object {
+		  "code": "RSPACK_E0010",
 		  "index": 0,
-		  "message": "  ⚠ Module parse warning:\\n  ╰─▶   ⚠ Unsupported feature: require.main.require() is not supported by Rspack.\\n         ╭────\\n       1 │ require.main.require('./file');\\n         · ──────────────────────────────\\n         ╰────\\n      \\n",
+		  "message": "  ⚠ Module parse warning:\\n  ╰─▶   ⚠ Unsupported feature: require.main.require() is not supported by Rspack.\\n         ╭────\\n       1 │ require.main.require('./file');\\n         · ──────────────────────────────\\n         ╰────\\n      \\n\\n  RSPACK_E0010: See https://rspack.dev/config/other-options#rspack_e0010\\n",
 		  "moduleIdentifier": "<TEST_TOOLS_ROOT>/tests/fixtures/errors/require.main.require.js",
 		  "name": "ModuleParseWarning",
 		},
@@ -0,0 +1,25 @@
+import { a } from "./shared";
+
+var value = require("./module");
+var sharedUpdated = false;
+
+if (Math.random() < 0) new Worker(new URL("./worker.js", import.meta.url));
+
+it("should only update the shared module in the runtimes it is changed for", done => {
+	expect(a).toBe("a");
+	expect(value).toBe(1);
+	module.hot.accept("./shared", () => {
+		sharedUpdated = true;
+	});
+	module.hot.accept("./module", () => {
+		value = require("./module");
+	});
+	NEXT(
+		require("../../update")(done, true, () => {
+			expect(value).toBe(2);
+			// only the exports used by the worker runtime are changed
+			expect(sharedUpdated).toBe(false);
+			done();
+		})
+	);
+});
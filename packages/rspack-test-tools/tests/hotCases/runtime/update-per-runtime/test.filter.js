@@ -0,0 +1,5 @@
+module.exports = function (config) {
+	if (config.target !== "web") {
+		return false;
+	}
+};
@@ -0,0 +1,5 @@
+import { b } from "./shared";
+self.postMessage(b);
+---
+import { b, c } from "./shared";
+self.postMessage(b + c);
@@ -0,0 +1,37 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should report the diagnostics configured as advices",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/a",
+			performance: {
+				hints: "warning",
+				maxAssetSize: 1,
+				maxEntrypointSize: 1
+			},
+			diagnostics: {
+				RSPACK_E0013: "advice",
+				RSPACK_E0014: "advice",
+				RSPACK_E0015: "off"
+			}
+		};
+	},
+	async check(stats) {
+		expect(stats?.hasWarnings()).toBe(false);
+		expect(stats?.hasErrors()).toBe(false);
+
+		const json = stats?.toJson({ all: false, advices: true, warnings: true });
+		expect(json?.warnings).toHaveLength(0);
+		expect(json?.advices).toHaveLength(2);
+		expect(json?.advices?.[0].message).toContain("asset size limit");
+		expect(json?.advices?.[1].message).toContain("entrypoint size limit");
+
+		expect(stats?.toJson({ all: false, advices: false }).advices).toBe(
+			undefined
+		);
+		expect(stats?.toString({ all: false, advices: true })).toContain(
+			"ADVICE in"
+		);
+	}
+};
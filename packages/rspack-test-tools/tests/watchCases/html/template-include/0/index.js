@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should rebuild the html when the included templates change", () => {
+	const htmlContent = fs.readFileSync(path.join(__dirname, "./index.html"), "utf-8");
+	switch (WATCH_STEP) {
+		case "0":
+			expect(htmlContent).toContain("<title>title 0</title>");
+			expect(htmlContent).toContain(`<p id="body">body 0</p>`);
+			break;
+		case "1":
+			expect(htmlContent).toContain("<title>title 1</title>");
+			expect(htmlContent).toContain(`<p id="body">body 0</p>`);
+			break;
+		case "3":
+			// the broken template is still watched, so fixing it triggers a rebuild
+			expect(htmlContent).toContain("<title>title 1</title>");
+			expect(htmlContent).toContain(`<p id="body">body 3</p>`);
+			break;
+	}
+});
@@ -0,0 +1 @@
+module.exports = [[/failed to render template from string: SyntaxError/]];
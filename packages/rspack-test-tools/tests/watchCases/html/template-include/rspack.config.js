@@ -0,0 +1,12 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			minify: false,
+			inject: false,
+			template: "./index.ejs"
+		})
+	]
+};
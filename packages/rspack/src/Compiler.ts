@@ -261,11 +261,11 @@ class Compiler {
 	}
 
 	get recordsInputPath() {
-		return unsupported("Compiler.recordsInputPath");
+		return this.options.recordsInputPath || null;
 	}
 
 	get recordsOutputPath() {
-		return unsupported("Compiler.recordsOutputPath");
+		return this.options.recordsOutputPath || null;
 	}
 
 	get managedPaths() {
@@ -1191,9 +1191,9 @@ class Compiler {
 							Chunk.__from_binding(chunk, that.deref()!.#compilation!),
 							hash
 						);
-						const digestResult = hash.digest(
-							that.deref()!.options.output.hashDigest
-						);
+						// the result is only fed into the chunk hash on the Rust side,
+						// so the encoding does not need to follow `output.hashDigest`
+						const digestResult = hash.digest("hex");
 						return Buffer.from(digestResult);
 					};
 				}
@@ -1485,9 +1485,9 @@ class Compiler {
 							Chunk.__from_binding(chunk, that.deref()!.#compilation!),
 							hash
 						);
-						const digestResult = hash.digest(
-							that.deref()!.options.output.hashDigest
-						);
+						// the result is only fed into the chunk hash on the Rust side,
+						// so the encoding does not need to follow `output.hashDigest`
+						const digestResult = hash.digest("hex");
 						return Buffer.from(digestResult);
 					};
 				}
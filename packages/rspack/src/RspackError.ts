@@ -8,8 +8,24 @@ export class JsRspackDiagnostic {
 		error: Error | RspackError,
 		severity: binding.JsRspackSeverity
 	): binding.JsRspackDiagnostic {
+		const jsError = concatErrorMsgAndStack(error);
+		// The `code` of the other errors, such as the system errors of Node.js, may not be a string
+		if ("code" in jsError && typeof jsError.code !== "string") {
+			return {
+				error: {
+					name: jsError.name,
+					message: jsError.message,
+					moduleIdentifier: jsError.moduleIdentifier,
+					loc: jsError.loc,
+					file: jsError.file,
+					stack: jsError.stack,
+					hideStack: jsError.hideStack
+				},
+				severity
+			};
+		}
 		return {
-			error: concatErrorMsgAndStack(error),
+			error: jsError,
 			severity
 		};
 	}
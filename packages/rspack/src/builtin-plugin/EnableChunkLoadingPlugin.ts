@@ -1,8 +1,57 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import { type BuiltinPlugin, BuiltinPluginName } from "@rspack/binding";
 
-import { create } from "./base";
+import type { ChunkLoadingType, Compiler } from "..";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
 
-export const EnableChunkLoadingPlugin = create(
-	BuiltinPluginName.EnableChunkLoadingPlugin,
-	(type: string): string => type
-);
+const enabledTypes = new WeakMap();
+
+const getEnabledTypes = (compiler: Compiler) => {
+	let set = enabledTypes.get(compiler);
+	if (set === undefined) {
+		set = new Set();
+		enabledTypes.set(compiler, set);
+	}
+	return set;
+};
+
+export class EnableChunkLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.EnableChunkLoadingPlugin;
+
+	constructor(private type: ChunkLoadingType) {
+		super();
+	}
+
+	static setEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		getEnabledTypes(compiler).add(type);
+	}
+
+	static checkEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		if (!getEnabledTypes(compiler).has(type)) {
+			throw new Error(
+				`Chunk loading type "${type}" is not enabled. EnableChunkLoadingPlugin need to be used to enable this type of chunk loading. This usually happens through the "output.enabledChunkLoadingTypes" option. If you are using a function as entry which sets "chunkLoading", you need to add all potential chunk loading types to "output.enabledChunkLoadingTypes". These types are enabled: ${Array.from(getEnabledTypes(compiler)).join(", ")}`
+			);
+		}
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin | undefined {
+		const { type } = this;
+
+		// Only enable once
+		const enabled = getEnabledTypes(compiler);
+		if (enabled.has(type)) return;
+		enabled.add(type);
+
+		switch (type) {
+			case "jsonp":
+			case "import-scripts":
+			case "require":
+			case "async-node":
+			case "import":
+				return createBuiltinPlugin(this.name, type);
+			default:
+				throw new Error(
+					`Unsupported chunk loading type ${type}.\nPlugins which provide custom chunk loading types must call EnableChunkLoadingPlugin.setEnabled(compiler, type) to disable this error.`
+				);
+		}
+	}
+}
@@ -72,7 +72,7 @@ export type HtmlRspackPluginOptions = {
 	 * Allows to overwrite the parameters used in the template.
 	 * When using a function, pass in the original template parameters and use the returned object as the final template parameters.
 	 */
-	templateParameters?: Record<string, string> | boolean | TemplateParamFunction;
+	templateParameters?: Record<string, any> | boolean | TemplateParamFunction;
 
 	/**
 	 * The script and link tag inject position in template. Use false to not inject.
@@ -147,7 +147,7 @@ const htmlRspackPluginOptions = z.strictObject({
 		.optional(),
 	templateContent: z.string().or(templateRenderFunction).optional(),
 	templateParameters: z
-		.record(z.string())
+		.record(z.any())
 		.or(z.boolean())
 		.or(templateParamFunction)
 		.optional(),
@@ -355,7 +355,7 @@ const HtmlRspackPluginImpl = create(
 			sri: c.sri,
 			minify: c.minify,
 			meta,
-			scriptLoading,
+			scriptLoading: c.scriptLoading,
 			inject,
 			base,
 			templateFn,
@@ -0,0 +1,9 @@
+import { BuiltinPluginName } from "@rspack/binding";
+
+import { create } from "./base";
+
+export const WebAssemblyModulesPlugin = create(
+	BuiltinPluginName.WebAssemblyModulesPlugin,
+	() => {},
+	"compilation"
+);
@@ -50,6 +50,7 @@ export * from "./NodeTargetPlugin";
 export * from "./ProgressPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
+export * from "./RecordIdsPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./RuntimeChunkPlugin";
 export * from "./RuntimePlugin";
@@ -62,6 +63,7 @@ export * from "./RemoveDuplicateModulesPlugin";
 export * from "./LightningCssMinimizerRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./WebWorkerTemplatePlugin";
 export * from "./WorkerPlugin";
 export * from "./FetchCompileAsyncWasmPlugin";
@@ -18,6 +18,7 @@ import {
 	type RawGeneratorOptions,
 	type RawIncremental,
 	type RawJavascriptParserOptions,
+	type RawJsonParserOptions,
 	type RawModuleRule,
 	type RawModuleRuleUse,
 	type RawOptions,
@@ -61,6 +62,7 @@ import type {
 	GeneratorOptionsByModuleType,
 	Incremental,
 	JavascriptParserOptions,
+	JsonParserOptions,
 	LibraryName,
 	LibraryOptions,
 	Node,
@@ -126,6 +128,9 @@ export const getRawOptions = (
 		amd: options.amd,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		diagnostics: options.diagnostics,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		__references: {}
 	};
 };
@@ -653,6 +658,12 @@ function getRawParserOptions(
 			cssModule: getRawCssParserOptions(parser)
 		};
 	}
+	if (type === "json") {
+		return {
+			type: "json",
+			json: getRawJsonParserOptions(parser)
+		};
+	}
 	// FIXME: shouldn't depend on module type, for example: `rules: [{ test: /\.css/, generator: {..} }]` will error
 	throw new Error(`unreachable: unknow module type: ${type}`);
 }
@@ -716,8 +727,14 @@ function getRawAssetParserDataUrl(
 			}
 		};
 	}
+	if (typeof dataUrlCondition === "function") {
+		return {
+			type: "function",
+			function: dataUrlCondition
+		};
+	}
 	throw new Error(
-		`unreachable: AssetParserDataUrl type should be one of "options", but got ${dataUrlCondition}`
+		`unreachable: AssetParserDataUrl type should be one of "options", "function", but got ${dataUrlCondition}`
 	);
 }
 
@@ -725,7 +742,20 @@ function getRawCssParserOptions(
 	parser: CssParserOptions
 ): RawCssParserOptions | RawCssAutoParserOptions | RawCssModuleParserOptions {
 	return {
-		namedExports: parser.namedExports
+		namedExports: parser.namedExports,
+		ignoreOrder: parser.ignoreOrder
+	};
+}
+
+function getRawJsonParserOptions(
+	parser: JsonParserOptions
+): RawJsonParserOptions {
+	const { parse } = parser;
+	return {
+		parse:
+			typeof parse === "function"
+				? (source: string) => JSON.stringify(parse(source))
+				: parse
 	};
 }
 
@@ -88,7 +88,7 @@ export interface Diagnostic {
 	 */
 	location?: DiagnosticLocation;
 	file?: string;
-	severity: "error" | "warning";
+	severity: "error" | "warning" | "advice";
 }
 
 interface LoaderExperiments {
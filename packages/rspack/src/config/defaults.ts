@@ -83,6 +83,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "profile", false);
 	// IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	// IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
 	// but Rspack currently does not support this option
@@ -94,6 +96,7 @@ export const applyRspackOptionsDefaults = (
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		css: options.experiments.css,
 		targetProperties
 	});
@@ -203,6 +206,7 @@ const applyExperimentsDefaults = (
 	// IGNORE(experiments.lazyCompilation): In webpack, lazyCompilation is undefined by default
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", experiments.futureDefaults);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "css", experiments.futureDefaults ? true : undefined);
 	D(experiments, "layers", false);
 	D(experiments, "topLevelAwait", true);
@@ -277,10 +281,12 @@ const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly: boolean;
 		css?: boolean;
 		targetProperties: any;
 	}
@@ -304,14 +310,17 @@ const applyModuleDefaults = (
 		F(module.parser, "css", () => ({}));
 		assertNotNill(module.parser.css);
 		D(module.parser.css, "namedExports", true);
+		D(module.parser.css, "ignoreOrder", false);
 
 		F(module.parser, "css/auto", () => ({}));
 		assertNotNill(module.parser["css/auto"]);
 		D(module.parser["css/auto"], "namedExports", true);
+		D(module.parser["css/auto"], "ignoreOrder", false);
 
 		F(module.parser, "css/module", () => ({}));
 		assertNotNill(module.parser["css/module"]);
 		D(module.parser["css/module"], "namedExports", true);
+		D(module.parser["css/module"], "ignoreOrder", false);
 
 		// IGNORE(module.generator): already check to align in 2024.6.27
 		F(module.generator, "css", () => ({}));
@@ -435,6 +444,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
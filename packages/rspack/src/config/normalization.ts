@@ -25,6 +25,7 @@ import type {
 	Dependencies,
 	DevServer,
 	DevTool,
+	Diagnostics,
 	DevtoolFallbackModuleFilenameTemplate,
 	DevtoolModuleFilenameTemplate,
 	DevtoolNamespace,
@@ -68,6 +69,8 @@ import type {
 	Plugins,
 	Profile,
 	PublicPath,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -102,6 +105,7 @@ export const getNormalizedRspackOptions = (
 						};
 					})
 				: undefined,
+		diagnostics: config.diagnostics,
 		name: config.name,
 		dependencies: config.dependencies,
 		context: config.context,
@@ -343,7 +347,9 @@ export const getNormalizedRspackOptions = (
 		devServer: config.devServer,
 		profile: config.profile,
 		amd: config.amd ? JSON.stringify(config.amd) : undefined,
-		bail: config.bail
+		bail: config.bail,
+		recordsInputPath: config.recordsInputPath ?? config.recordsPath,
+		recordsOutputPath: config.recordsOutputPath ?? config.recordsPath
 	};
 };
 
@@ -548,6 +554,7 @@ export interface ExperimentsNormalized {
 	cache?: ExperimentCacheOptions;
 	lazyCompilation?: false | LazyCompilationOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
@@ -596,8 +603,11 @@ export interface RspackOptionsNormalized {
 	watchOptions: WatchOptions;
 	devServer?: DevServer;
 	ignoreWarnings?: IgnoreWarningsNormalized;
+	diagnostics?: Diagnostics;
 	performance?: Performance;
 	profile?: Profile;
 	amd?: string;
 	bail?: Bail;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 }
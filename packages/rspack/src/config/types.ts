@@ -281,7 +281,19 @@ export type ChunkLoadingGlobal = string;
 export type EnabledLibraryTypes = string[];
 
 /** Whether delete all files in the output directory. */
-export type Clean = boolean | { keep?: string };
+export type Clean =
+	| boolean
+	| {
+			/**
+			 * Keep the files matched by it, the path is relative to the output path.
+			 * A string is a path prefix, or a glob pattern if it contains glob characters.
+			 */
+			keep?: string | RegExp | ((path: string) => boolean);
+			/** Log the files that would be removed instead of removing them. */
+			dry?: boolean;
+			/** Only remove the files emitted by this compiler before. */
+			emittedOnly?: boolean;
+	  };
 
 /** Output JavaScript files as module type. */
 export type OutputModule = boolean;
@@ -328,14 +340,23 @@ export type TrustedTypes = {
 	onPolicyCreationFailure?: "continue" | "stop";
 };
 
-/** The encoding to use when generating the hash. */
+/**
+ * The encoding to use when generating the hash.
+ * Supports "hex", "base64", "base64url", "base26", "base36", "base58" and "base62".
+ */
 export type HashDigest = string;
 
 /** The prefix length of the hash digest to use. */
 export type HashDigestLength = number;
 
 /** The hashing algorithm to use. */
-export type HashFunction = "md4" | "xxhash64";
+export type HashFunction =
+	| "md4"
+	| "md5"
+	| "sha256"
+	| "sha512"
+	| "xxhash64"
+	| "xxhash3-128";
 
 /** An optional salt to update the hash. */
 export type HashSalt = string;
@@ -936,9 +957,24 @@ export type AssetParserDataUrlOptions = {
 };
 
 /**
- * Options object for DataUrl condition.
+ * Function for DataUrl condition, the asset is inlined when it returns true.
  * */
-export type AssetParserDataUrl = AssetParserDataUrlOptions;
+export type AssetParserDataUrlFunction = (
+	source: Buffer,
+	context: {
+		/** The resource path of the asset module. */
+		filename: string;
+		/** The context directory of the asset module. */
+		context: string;
+	}
+) => boolean;
+
+/**
+ * Options object or function for DataUrl condition.
+ * */
+export type AssetParserDataUrl =
+	| AssetParserDataUrlOptions
+	| AssetParserDataUrlFunction;
 
 /** Options object for `asset` modules. */
 export type AssetParserOptions = {
@@ -946,11 +982,13 @@ export type AssetParserOptions = {
 	 * It be used only for Asset Module scenarios.
 	 * @default { maxSize: 8096 }
 	 * */
-	dataUrlCondition?: AssetParserDataUrlOptions;
+	dataUrlCondition?: AssetParserDataUrl;
 };
 
 export type CssParserNamedExports = boolean;
 
+export type CssParserIgnoreOrder = boolean;
+
 /** Options object for `css` modules. */
 export type CssParserOptions = {
 	/**
@@ -958,6 +996,11 @@ export type CssParserOptions = {
 	 * @default true
 	 * */
 	namedExports?: CssParserNamedExports;
+	/**
+	 * Do not warn about the conflicting order between this module and other CSS modules in the same chunk.
+	 * @default false
+	 * */
+	ignoreOrder?: CssParserIgnoreOrder;
 };
 
 /** Options object for `css/auto` modules. */
@@ -967,6 +1010,11 @@ export type CssAutoParserOptions = {
 	 * @default true
 	 * */
 	namedExports?: CssParserNamedExports;
+	/**
+	 * Do not warn about the conflicting order between this module and other CSS modules in the same chunk.
+	 * @default false
+	 * */
+	ignoreOrder?: CssParserIgnoreOrder;
 };
 
 /** Options object for `css/module` modules. */
@@ -976,6 +1024,31 @@ export type CssModuleParserOptions = {
 	 * @default true
 	 * */
 	namedExports?: CssParserNamedExports;
+	/**
+	 * Do not warn about the conflicting order between this module and other CSS modules in the same chunk.
+	 * @default false
+	 * */
+	ignoreOrder?: CssParserIgnoreOrder;
+};
+
+/**
+ * How to parse the source of `json` modules, either a builtin parser or a function
+ * which returns the parsed value, e.g. `parse: require("yaml").parse`.
+ * */
+export type JsonParserParse =
+	| "json"
+	| "json5"
+	| "toml"
+	| "yaml"
+	| ((source: string) => any);
+
+/** Options object for `json` modules. */
+export type JsonParserOptions = {
+	/**
+	 * Parse the source of `json` modules with a custom parser.
+	 * @default "json"
+	 * */
+	parse?: JsonParserParse;
 };
 
 type ExportsPresence = "error" | "warn" | "auto" | false;
@@ -1093,6 +1166,9 @@ export type ParserOptionsByModuleTypeKnown = {
 
 	/** Parser options for `javascript/esm` modules. */
 	"javascript/esm"?: JavascriptParserOptions;
+
+	/** Parser options for `json` modules. */
+	json?: JsonParserOptions;
 };
 
 /** Configure all parsers' options in one place with module.parser. */
@@ -1667,6 +1743,12 @@ export type StatsOptions = {
 	 * @default true
 	 */
 	warningsCount?: boolean;
+	/**
+	 * Enables or disables the display of advice stats, which are the diagnostics
+	 * configured as `'advice'` by `diagnostics`.
+	 * @default true
+	 */
+	advices?: boolean;
 	/**
 	 * Enables or disables the display of error stats.
 	 * @default true
@@ -2334,6 +2416,11 @@ export type ExperimentCacheOptions =
 				immutablePaths: Array<string | RegExp>;
 				unmanagedPaths: Array<string | RegExp>;
 				managedPaths: Array<string | RegExp>;
+				/**
+				 * The paths validated by the content hash instead of the modified time,
+				 * which is useful when the modified time is unreliable, such as after a git checkout.
+				 */
+				hashPaths?: Array<string | RegExp>;
 			};
 			storage: {
 				type: "filesystem";
@@ -2529,6 +2616,11 @@ export type Experiments = {
 	 * Support the new WebAssembly according to the [updated specification](https://github.com/WebAssembly/esm-integration), it makes a WebAssembly module an async module.
 	 */
 	asyncWebAssembly?: boolean;
+	/**
+	 * Enable sync WebAssembly.
+	 * Support the old WebAssembly like in webpack 4, the WebAssembly module is instantiated during chunk loading.
+	 */
+	syncWebAssembly?: boolean;
 	/**
 	 * Enable output as ES module.
 	 */
@@ -2626,6 +2718,19 @@ export type IgnoreWarnings = (
 )[];
 //#endregion
 
+//#region Diagnostics
+/**
+ * The level of the diagnostics with a code.
+ * - `"off"` ignores the diagnostics.
+ */
+export type DiagnosticLevel = "error" | "warning" | "advice" | "off";
+
+/**
+ * Promote, demote or ignore the diagnostics by their stable codes, such as `RSPACK_E0012`.
+ */
+export type Diagnostics = Record<string, DiagnosticLevel>;
+//#endregion
+
 //#region Profile
 /**
  * Capture a "profile" of the application, including statistics and hints, which can then be dissected using the Analyze tool.
@@ -2648,6 +2753,27 @@ export type Amd = false | Record<string, any>;
 export type Bail = boolean;
 //#endregion
 
+//#region Records
+/**
+ * The path of the JSON file to store the module ids and chunk ids,
+ * which is used as both `recordsInputPath` and `recordsOutputPath`.
+ * @default false
+ * */
+export type RecordsPath = false | string;
+
+/**
+ * The path of the JSON file to read the records of the last build from.
+ * @default false
+ * */
+export type RecordsInputPath = false | string;
+
+/**
+ * The path of the JSON file to write the records of this build to.
+ * @default false
+ * */
+export type RecordsOutputPath = false | string;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -2743,6 +2869,10 @@ export type RspackOptions = {
 	 * Warnings to ignore during compilation.
 	 */
 	ignoreWarnings?: IgnoreWarnings;
+	/**
+	 * Override the severity of the diagnostics by their codes.
+	 */
+	diagnostics?: Diagnostics;
 	/**
 	 * Options for watch mode.
 	 */
@@ -2796,6 +2926,18 @@ export type RspackOptions = {
 	 * Whether to fail on the first error.
 	 */
 	bail?: Bail;
+	/**
+	 * Keep the module ids and chunk ids stable across builds by storing them in a JSON file.
+	 */
+	recordsPath?: RecordsPath;
+	/**
+	 * Read the records of the last build from this file.
+	 */
+	recordsInputPath?: RecordsInputPath;
+	/**
+	 * Write the records of this build to this file.
+	 */
+	recordsOutputPath?: RecordsOutputPath;
 	/**
 	 * Performance optimization options.
 	 */
@@ -239,7 +239,13 @@ const enabledLibraryTypes = z.array(
 const clean = z.union([
 	z.boolean(),
 	z.strictObject({
-		keep: z.string().optional()
+		keep: z
+			.string()
+			.or(z.instanceof(RegExp))
+			.or(z.function().args(z.string()).returns(z.boolean()))
+			.optional(),
+		dry: z.boolean().optional(),
+		emittedOnly: z.boolean().optional()
 	})
 ]) satisfies z.ZodType<t.Clean>;
 
@@ -284,7 +290,11 @@ const hashDigestLength = z.number() satisfies z.ZodType<t.HashDigestLength>;
 
 const hashFunction = z.enum([
 	"md4",
-	"xxhash64"
+	"md5",
+	"sha256",
+	"sha512",
+	"xxhash64",
+	"xxhash3-128"
 ]) satisfies z.ZodType<t.HashFunction>;
 
 const hashSalt = z.string() satisfies z.ZodType<t.HashSalt>;
@@ -503,8 +513,20 @@ const assetParserDataUrlOptions = z.strictObject({
 	maxSize: z.number().optional()
 }) satisfies z.ZodType<t.AssetParserDataUrlOptions>;
 
-const assetParserDataUrl =
-	assetParserDataUrlOptions satisfies z.ZodType<t.AssetParserDataUrl>;
+const assetParserDataUrlFunction = z
+	.function()
+	.args(
+		z.instanceof(Buffer),
+		z.strictObject({
+			filename: z.string(),
+			context: z.string()
+		})
+	)
+	.returns(z.boolean()) satisfies z.ZodType<t.AssetParserDataUrlFunction>;
+
+const assetParserDataUrl = assetParserDataUrlOptions.or(
+	assetParserDataUrlFunction
+) satisfies z.ZodType<t.AssetParserDataUrl>;
 
 const assetParserOptions = z.strictObject({
 	dataUrlCondition: assetParserDataUrl.optional()
@@ -513,18 +535,32 @@ const assetParserOptions = z.strictObject({
 const cssParserNamedExports =
 	z.boolean() satisfies z.ZodType<t.CssParserNamedExports>;
 
+const cssParserIgnoreOrder =
+	z.boolean() satisfies z.ZodType<t.CssParserIgnoreOrder>;
+
 const cssParserOptions = z.strictObject({
-	namedExports: cssParserNamedExports.optional()
+	namedExports: cssParserNamedExports.optional(),
+	ignoreOrder: cssParserIgnoreOrder.optional()
 }) satisfies z.ZodType<t.CssParserOptions>;
 
 const cssAutoParserOptions = z.strictObject({
-	namedExports: cssParserNamedExports.optional()
+	namedExports: cssParserNamedExports.optional(),
+	ignoreOrder: cssParserIgnoreOrder.optional()
 }) satisfies z.ZodType<t.CssAutoParserOptions>;
 
 const cssModuleParserOptions = z.strictObject({
-	namedExports: cssParserNamedExports.optional()
+	namedExports: cssParserNamedExports.optional(),
+	ignoreOrder: cssParserIgnoreOrder.optional()
 }) satisfies z.ZodType<t.CssModuleParserOptions>;
 
+const jsonParserParse = z
+	.enum(["json", "json5", "toml", "yaml"])
+	.or(z.function().args(z.string()).returns(z.any())) satisfies z.ZodType<t.JsonParserParse>;
+
+const jsonParserOptions = z.strictObject({
+	parse: jsonParserParse.optional()
+}) satisfies z.ZodType<t.JsonParserOptions>;
+
 const dynamicImportMode = z.enum(["eager", "lazy", "weak", "lazy-once"]);
 const dynamicImportPreload = z.union([z.boolean(), z.number()]);
 const dynamicImportPrefetch = z.union([z.boolean(), z.number()]);
@@ -580,7 +616,8 @@ const parserOptionsByModuleTypeKnown = z.strictObject({
 	javascript: javascriptParserOptions.optional(),
 	"javascript/auto": javascriptParserOptions.optional(),
 	"javascript/dynamic": javascriptParserOptions.optional(),
-	"javascript/esm": javascriptParserOptions.optional()
+	"javascript/esm": javascriptParserOptions.optional(),
+	json: jsonParserOptions.optional()
 }) satisfies z.ZodType<t.ParserOptionsByModuleTypeKnown>;
 
 const parserOptionsByModuleTypeUnknown = z.record(
@@ -1017,6 +1054,7 @@ const statsOptions = z.strictObject({
 	chunkGroups: z.boolean().optional(),
 	warnings: z.boolean().optional(),
 	warningsCount: z.boolean().optional(),
+	advices: z.boolean().optional(),
 	errors: z.boolean().optional(),
 	errorsCount: z.boolean().optional(),
 	colors: z.boolean().optional(),
@@ -1277,7 +1315,8 @@ const experimentCacheOptions = z
 			snapshot: z.strictObject({
 				immutablePaths: z.string().or(z.instanceof(RegExp)).array(),
 				unmanagedPaths: z.string().or(z.instanceof(RegExp)).array(),
-				managedPaths: z.string().or(z.instanceof(RegExp)).array()
+				managedPaths: z.string().or(z.instanceof(RegExp)).array(),
+				hashPaths: z.string().or(z.instanceof(RegExp)).array().optional()
 			}),
 			storage: z.strictObject({
 				type: z.enum(["filesystem"]),
@@ -1323,6 +1362,7 @@ const experiments = z.strictObject({
 	cache: z.boolean().optional().or(experimentCacheOptions),
 	lazyCompilation: z.boolean().optional().or(lazyCompilationOptions),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	css: z.boolean().optional(),
@@ -1368,6 +1408,20 @@ const ignoreWarnings = z
 	.array() satisfies z.ZodType<t.IgnoreWarnings>;
 //#endregion
 
+//#region Diagnostics
+const diagnosticLevel = z.enum([
+	"error",
+	"warning",
+	"advice",
+	"off"
+]) satisfies z.ZodType<t.DiagnosticLevel>;
+
+const diagnostics = z.record(
+	z.string().regex(/^RSPACK_E\d{4}$/i),
+	diagnosticLevel
+) satisfies z.ZodType<t.Diagnostics>;
+//#endregion
+
 //#region Profile
 const profile = z.boolean() satisfies z.ZodType<t.Profile>;
 //#endregion
@@ -1380,6 +1434,20 @@ const amd = z.literal(false).or(z.record(z.any())) satisfies z.ZodType<t.Amd>;
 const bail = z.boolean() satisfies z.ZodType<t.Bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsPath>;
+
+const recordsInputPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsInputPath>;
+
+const recordsOutputPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsOutputPath>;
+//#endregion
+
 //#region Performance
 const performance = z
 	.strictObject({
@@ -1409,6 +1477,7 @@ export const rspackOptions = z.strictObject({
 	node: node.optional(),
 	loader: loader.optional(),
 	ignoreWarnings: ignoreWarnings.optional(),
+	diagnostics: diagnostics.optional(),
 	watchOptions: watchOptions.optional(),
 	watch: watch.optional(),
 	stats: statsValue.optional(),
@@ -1422,5 +1491,8 @@ export const rspackOptions = z.strictObject({
 	profile: profile.optional(),
 	amd: amd.optional(),
 	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional(),
 	performance: performance.optional()
 }) satisfies z.ZodType<t.RspackOptions>;
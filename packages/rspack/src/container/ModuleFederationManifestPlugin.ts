@@ -0,0 +1,41 @@
+import {
+	type BuiltinPlugin,
+	BuiltinPluginName,
+	type RawModuleFederationManifestPluginOptions
+} from "@rspack/binding";
+
+import type { Compiler } from "../Compiler";
+import {
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "../builtin-plugin/base";
+
+export type ModuleFederationManifestPluginOptions = {
+	/** The name of the container. */
+	name: string;
+	/** The global name of the container, defaults to the name. */
+	globalName?: string;
+	/** The filename of the manifest, defaults to `mf-manifest.json`. */
+	filename?: string;
+	/** The filename of the stats, defaults to `mf-stats.json`. */
+	statsFilename?: string;
+};
+
+export class ModuleFederationManifestPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.ModuleFederationManifestPlugin;
+	_options: RawModuleFederationManifestPluginOptions;
+
+	constructor(options: ModuleFederationManifestPluginOptions) {
+		super();
+		this._options = {
+			name: options.name,
+			globalName: options.globalName,
+			filename: options.filename ?? "mf-manifest.json",
+			statsFilename: options.statsFilename ?? "mf-stats.json"
+		};
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		return createBuiltinPlugin(this.name, this._options);
+	}
+}
@@ -2,6 +2,7 @@ import type { Compiler } from "../Compiler";
 import { type ExternalsType, externalsType } from "../config";
 import { isValidate } from "../util/validate";
 import type { ModuleFederationPluginV1Options } from "./ModuleFederationPluginV1";
+import { ModuleFederationManifestPlugin } from "./ModuleFederationManifestPlugin";
 import { ModuleFederationRuntimePlugin } from "./ModuleFederationRuntimePlugin";
 import { parseOptions } from "./options";
 
@@ -10,8 +11,17 @@ export interface ModuleFederationPluginOptions
 	runtimePlugins?: RuntimePlugins;
 	implementation?: string;
 	shareStrategy?: "version-first" | "loaded-first";
+	/**
+	 * Emit `mf-manifest.json` and `mf-stats.json` describing the exposes,
+	 * shared modules, remotes and the assets they need.
+	 */
+	manifest?: boolean | ManifestOptions;
 }
 export type RuntimePlugins = string[];
+export type ManifestOptions = {
+	filename?: string;
+	statsFilename?: string;
+};
 
 export class ModuleFederationPlugin {
 	constructor(private _options: ModuleFederationPluginOptions) {}
@@ -32,6 +42,18 @@ export class ModuleFederationPlugin {
 			).apply(compiler);
 		});
 		new ModuleFederationRuntimePlugin().apply(compiler);
+		if (this._options.manifest) {
+			const manifestOptions =
+				this._options.manifest === true ? {} : this._options.manifest;
+			new ModuleFederationManifestPlugin({
+				name: this._options.name ?? compiler.options.output.uniqueName ?? "",
+				globalName:
+					typeof this._options.library?.name === "string"
+						? this._options.library.name
+						: undefined,
+				...manifestOptions
+			}).apply(compiler);
+		}
 		new webpack.container.ModuleFederationPluginV1({
 			...this._options,
 			enhanced: true
@@ -50,7 +72,7 @@ interface RemoteInfo {
 	name?: string;
 	entry?: string;
 	externalType: ExternalsType;
-	shareScope: string;
+	shareScope: string | string[];
 }
 
 type RemoteInfos = Record<string, RemoteInfo[]>;
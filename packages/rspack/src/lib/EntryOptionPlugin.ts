@@ -11,7 +11,11 @@
 import assert from "node:assert";
 
 import type { Compiler, EntryDescriptionNormalized, EntryNormalized } from "..";
-import { DynamicEntryPlugin, EntryPlugin } from "../builtin-plugin";
+import {
+	DynamicEntryPlugin,
+	EnableChunkLoadingPlugin,
+	EntryPlugin
+} from "../builtin-plugin";
 import type { EntryOptions } from "../builtin-plugin";
 
 export class EntryOptionPlugin {
@@ -87,10 +91,9 @@ export class EntryOptionPlugin {
 				"'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
 			);
 		}
-		// if (desc.chunkLoading) {
-		// 	const EnableChunkLoadingPlugin = require("./javascript/EnableChunkLoadingPlugin");
-		// 	EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
-		// }
+		if (desc.chunkLoading) {
+			EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
+		}
 		// if (desc.wasmLoading) {
 		// 	const EnableWasmLoadingPlugin = require("./wasm/EnableWasmLoadingPlugin");
 		// 	EnableWasmLoadingPlugin.checkEnabled(compiler, desc.wasmLoading);
@@ -637,6 +637,7 @@ export async function runLoaders(
 		)})`;
 		error = concatErrorMsgAndStack(error);
 		(error as RspackError).moduleIdentifier = this._module.identifier();
+		(error as RspackError).code = "RSPACK_E0005";
 		compiler._lastCompilation!.__internal__pushRspackDiagnostic({
 			error,
 			severity: JsRspackSeverity.Error
@@ -653,6 +654,7 @@ export async function runLoaders(
 		)})`;
 		warning = concatErrorMsgAndStack(warning);
 		(warning as RspackError).moduleIdentifier = this._module.identifier();
+		(warning as RspackError).code = "RSPACK_E0006";
 		compiler._lastCompilation!.__internal__pushRspackDiagnostic({
 			error: warning,
 			severity: JsRspackSeverity.Warn
@@ -57,6 +57,7 @@ import {
 	NoEmitOnErrorsPlugin,
 	NodeTargetPlugin,
 	RealContentHashPlugin,
+	RecordIdsPlugin,
 	RemoveEmptyChunksPlugin,
 	RuntimeChunkPlugin,
 	RuntimePlugin,
@@ -65,6 +66,7 @@ import {
 	SourceMapDevToolPlugin,
 	SplitChunksPlugin,
 	WarnCaseSensitiveModulesPlugin,
+	WebAssemblyModulesPlugin,
 	WorkerPlugin
 } from "./builtin-plugin";
 import EntryOptionPlugin from "./lib/EntryOptionPlugin";
@@ -213,6 +215,9 @@ export class RspackOptionsApply {
 		new JavascriptModulesPlugin().apply(compiler);
 		new JsonModulesPlugin().apply(compiler);
 		new AssetModulesPlugin().apply(compiler);
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
@@ -340,6 +345,9 @@ export class RspackOptionsApply {
 					throw new Error(`chunkIds: ${chunkIds} is not implemented`);
 			}
 		}
+		if (options.recordsInputPath || options.recordsOutputPath) {
+			new RecordIdsPlugin().apply(compiler);
+		}
 		if (options.optimization.nodeEnv) {
 			new DefinePlugin({
 				"process.env.NODE_ENV": JSON.stringify(options.optimization.nodeEnv)
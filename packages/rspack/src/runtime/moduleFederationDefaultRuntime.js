@@ -69,7 +69,9 @@ module.exports = function () {
 								singleton: data.singleton,
 								eager: data.eager
 							},
-							scope: [data.shareScope]
+							scope: Array.isArray(data.shareScope)
+								? data.shareScope
+								: [data.shareScope]
 						},
 						shareKey: data.shareKey
 					};
@@ -232,15 +234,22 @@ module.exports = function () {
 				webpackRequire: __webpack_require__
 			})
 		);
-		override(__webpack_require__, "I", (name, initScope) =>
+		const initializeSharing = (name, initScope) =>
 			__webpack_require__.federation.bundlerRuntime.I({
 				shareScopeName: name,
 				initScope,
 				initPromises: initializeSharingInitPromises,
 				initTokens: initializeSharingInitTokens,
 				webpackRequire: __webpack_require__
-			})
-		);
+			});
+		override(__webpack_require__, "I", (name, initScope) => {
+			if (!Array.isArray(name)) return initializeSharing(name, initScope);
+			if (!initScope) initScope = [];
+			const pending = name
+				.map(name => initializeSharing(name, initScope))
+				.filter(promise => promise && promise.then);
+			return pending.length ? Promise.all(pending).then(() => 1) : 1;
+		});
 		override(
 			__webpack_require__,
 			"initContainer",
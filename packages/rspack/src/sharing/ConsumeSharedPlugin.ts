@@ -15,7 +15,7 @@ import { isRequiredVersion } from "./utils";
 
 export type ConsumeSharedPluginOptions = {
 	consumes: Consumes;
-	shareScope?: string;
+	shareScope?: string | string[];
 	enhanced?: boolean;
 };
 export type Consumes = (ConsumesItem | ConsumesObject)[] | ConsumesObject;
@@ -29,7 +29,7 @@ export type ConsumesConfig = {
 	packageName?: string;
 	requiredVersion?: false | string;
 	shareKey?: string;
-	shareScope?: string;
+	shareScope?: string | string[];
 	singleton?: boolean;
 	strictVersion?: boolean;
 };
@@ -14,7 +14,7 @@ import { ShareRuntimePlugin } from "./ShareRuntimePlugin";
 
 export type ProvideSharedPluginOptions<Enhanced extends boolean = false> = {
 	provides: Provides<Enhanced>;
-	shareScope?: string;
+	shareScope?: string | string[];
 	enhanced?: Enhanced;
 };
 export type Provides<Enhanced extends boolean> =
@@ -30,7 +30,7 @@ export type ProvidesConfig<Enhanced extends boolean> = Enhanced extends true
 type ProvidesV1Config = {
 	eager?: boolean;
 	shareKey: string;
-	shareScope?: string;
+	shareScope?: string | string[];
 	version?: false | string;
 };
 type ProvidesEnhancedConfig = ProvidesV1Config & ProvidesEnhancedExtraConfig;
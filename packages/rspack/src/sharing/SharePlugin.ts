@@ -5,7 +5,7 @@ import { ProvideSharedPlugin } from "./ProvideSharedPlugin";
 import { isRequiredVersion } from "./utils";
 
 export type SharePluginOptions = {
-	shareScope?: string;
+	shareScope?: string | string[];
 	shared: Shared;
 	enhanced: boolean;
 };
@@ -20,7 +20,7 @@ export type SharedConfig = {
 	packageName?: string;
 	requiredVersion?: false | string;
 	shareKey?: string;
-	shareScope?: string;
+	shareScope?: string | string[];
 	singleton?: boolean;
 	strictVersion?: boolean;
 	version?: false | string;
@@ -76,6 +76,7 @@ const ITEM_NAMES: Record<string, string> = {
 	"compilation.namedChunkGroups[]": "chunkGroup",
 	"compilation.errors[]": "error",
 	"compilation.warnings[]": "warning",
+	"compilation.advices[]": "warning",
 	"chunk.modules[]": "module",
 	"chunk.rootModules[]": "module",
 	"chunk.origins[]": "chunkOrigin",
@@ -615,6 +616,9 @@ const EXTRACT_ERROR: Record<
 > = {
 	_: (object, error) => {
 		object.message = error.message;
+		if (error.code) {
+			object.code = error.code;
+		}
 		if (error.chunkName) {
 			object.chunkName = error.chunkName;
 		}
@@ -1102,6 +1106,21 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				return cachedGetWarnings!(c);
 			});
 		},
+		advices: (
+			object,
+			compilation,
+			context: KnownStatsFactoryContext,
+			_options,
+			factory
+		) => {
+			const { type } = context;
+			const statsCompilation = context.getStatsCompilation(compilation);
+			object.advices = factory.create(
+				`${type}.advices`,
+				statsCompilation.advices,
+				context
+			);
+		},
 		children: (object, compilation, context, _options, factory) => {
 			const { type } = context;
 
@@ -236,6 +236,7 @@ const DEFAULTS: StatsDefault = {
 	errorStack: OFF_FOR_TO_STRING,
 	warnings: NORMAL_ON,
 	warningsCount: NORMAL_ON,
+	advices: NORMAL_ON,
 	publicPath: OFF_FOR_TO_STRING,
 	logging: ({ all }, { forToString }) =>
 		forToString && all !== false ? "info" : false,
@@ -679,6 +679,7 @@ const ITEM_NAMES: Record<
 	"compilation.namedChunkGroups[]": "chunkGroup",
 	"compilation.errors[]": "error",
 	"compilation.warnings[]": "error",
+	"compilation.advices[]": "error",
 	"compilation.logging[]": "loggingGroup",
 	"compilation.children[]": "compilation",
 	"asset.related[]": "asset",
@@ -749,6 +750,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"filteredModules",
 		"children",
 		"logging",
+		"advices",
 		"warnings",
 		"warningsInChildren!",
 		"filteredWarningDetailsCount",
@@ -944,6 +946,7 @@ const SIMPLE_ITEMS_JOINER: Record<
 			.join(" "),
 	"compilation.errors": itemsJoinMoreSpacing,
 	"compilation.warnings": itemsJoinMoreSpacing,
+	"compilation.advices": itemsJoinMoreSpacing,
 	"compilation.logging": itemsJoinMoreSpacing,
 	"compilation.children": items => indent(itemsJoinMoreSpacing(items), "  "),
 	"moduleTraceItem.dependencies": itemsJoinOneLine,
@@ -1036,17 +1039,21 @@ const joinExplicitNewLine = (
 };
 
 const joinError: (
-	error: boolean
+	severity: "error" | "warning" | "advice"
 ) => (
 	items: { element: string; content: string }[],
 	context: Required<StatsPrinterContext>
 ) => string =
-	error =>
-	(items, { red, yellow }) =>
-		`${error ? red("ERROR") : yellow("WARNING")} in ${joinExplicitNewLine(
-			items,
-			""
-		)}`;
+	severity =>
+	(items, { red, yellow, cyan }) => {
+		const title =
+			severity === "error"
+				? red("ERROR")
+				: severity === "warning"
+					? yellow("WARNING")
+					: cyan("ADVICE");
+		return `${title} in ${joinExplicitNewLine(items, "")}`;
+	};
 
 const SIMPLE_ELEMENT_JOINERS: Record<
 	string,
@@ -1061,6 +1068,7 @@ const SIMPLE_ELEMENT_JOINERS: Record<
 		for (const item of items) {
 			if (!item.content) continue;
 			const needMoreSpace = [
+				"advices",
 				"warnings",
 				"filteredWarningDetailsCount",
 				"errors",
@@ -1192,8 +1200,9 @@ const SIMPLE_ELEMENT_JOINERS: Record<
 	"module.profile": joinInBrackets,
 	moduleIssuer: joinOneLine,
 	chunkOrigin: items => `> ${joinOneLine(items)}`,
-	"errors[].error": joinError(true),
-	"warnings[].error": joinError(false),
+	"errors[].error": joinError("error"),
+	"warnings[].error": joinError("warning"),
+	"advices[].error": joinError("advice"),
 	loggingGroup: items => joinExplicitNewLine(items, "").trimEnd(),
 	moduleTraceItem: items => ` @ ${joinOneLine(items)}`,
 	moduleTraceDependency: joinOneLine
@@ -161,6 +161,8 @@ export type StatsModuleIssuer = KnownStatsModuleIssuer & Record<string, any>;
 
 export type KnownStatsError = {
 	message: string;
+	/** The stable code of the diagnostic, such as `RSPACK_E0001` */
+	code?: string;
 	chunkName?: string;
 	chunkEntry?: boolean;
 	chunkInitial?: boolean;
@@ -238,6 +240,7 @@ export type KnownStatsCompilation = {
 	errorsCount?: number;
 	warnings?: StatsError[];
 	warningsCount?: number;
+	advices?: StatsError[];
 	filteredModules?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
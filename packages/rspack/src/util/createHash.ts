@@ -157,6 +157,7 @@ export const createHash = (
 	algorithm:
 		| "debug"
 		| "xxhash64"
+		| "xxhash3-128"
 		| "md4"
 		| "native-md4"
 		| (string & {})
@@ -169,6 +170,10 @@ export const createHash = (
 		// TODO add non-cryptographic algorithm here
 		case "debug":
 			return new DebugHash();
+		case "xxhash3-128":
+			throw new Error(
+				"The hash function 'xxhash3-128' is only supported by the Rust side of Rspack, it can't be used to create hashes in JavaScript plugins and loaders"
+			);
 		case "xxhash64":
 			return new BatchedHash(createXXHash64());
 		case "md4":
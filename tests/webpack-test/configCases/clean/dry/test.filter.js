@@ -1 +0,0 @@
-module.exports = () => { return 'https://github.com/web-infra-dev/rspack/issues/8549' }